@@ -0,0 +1,90 @@
+//! Accepts the pending parameters of a [Gaugemeister].
+
+use num_traits::ToPrimitive;
+
+use crate::*;
+
+/// Accounts for [gauge::accept_gaugemeister_params].
+#[derive(Accounts)]
+pub struct AcceptGaugemeisterParams<'info> {
+    /// The [Gaugemeister] to modify.
+    #[account(mut)]
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [Gaugemeister::pending_foreman].
+    pub pending_foreman: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptGaugemeisterParams>) -> Result<()> {
+    let now = unwrap_int!(Clock::get()?.unix_timestamp.to_u64());
+    let gaugemeister = &mut ctx.accounts.gaugemeister;
+    invariant!(
+        now >= gaugemeister.pending_params_executable_at,
+        ParamsChangeNotExecutable
+    );
+
+    let prev_foreman = gaugemeister.foreman;
+    let prev_epoch_duration_seconds = gaugemeister.epoch_duration_seconds;
+    let prev_params_change_delay_seconds = gaugemeister.params_change_delay_seconds;
+    let prev_fixed_epoch_schedule = gaugemeister.fixed_epoch_schedule;
+
+    gaugemeister.foreman = gaugemeister.pending_foreman;
+    gaugemeister.epoch_duration_seconds = gaugemeister.pending_epoch_duration_seconds;
+    gaugemeister.params_change_delay_seconds = gaugemeister.pending_params_change_delay_seconds;
+    gaugemeister.fixed_epoch_schedule = gaugemeister.pending_fixed_epoch_schedule;
+
+    gaugemeister.pending_foreman = Pubkey::default();
+    gaugemeister.pending_epoch_duration_seconds = 0;
+    gaugemeister.pending_params_change_delay_seconds = 0;
+    gaugemeister.pending_fixed_epoch_schedule = false;
+    gaugemeister.pending_params_executable_at = 0;
+
+    emit!(AcceptGaugemeisterParamsEvent {
+        gaugemeister: gaugemeister.key(),
+        prev_foreman,
+        new_foreman: gaugemeister.foreman,
+        prev_epoch_duration_seconds,
+        new_epoch_duration_seconds: gaugemeister.epoch_duration_seconds,
+        prev_params_change_delay_seconds,
+        new_params_change_delay_seconds: gaugemeister.params_change_delay_seconds,
+        prev_fixed_epoch_schedule,
+        new_fixed_epoch_schedule: gaugemeister.fixed_epoch_schedule,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for AcceptGaugemeisterParams<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(self.gaugemeister.has_pending_params(), NoPendingParams);
+        assert_keys_eq!(
+            self.pending_foreman,
+            self.gaugemeister.pending_foreman,
+            UnauthorizedNotPendingForeman
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::accept_gaugemeister_params].
+#[event]
+pub struct AcceptGaugemeisterParamsEvent {
+    /// The [Gaugemeister].
+    #[index]
+    pub gaugemeister: Pubkey,
+    /// The previous [Gaugemeister::foreman].
+    pub prev_foreman: Pubkey,
+    /// The new [Gaugemeister::foreman].
+    pub new_foreman: Pubkey,
+    /// The previous [Gaugemeister::epoch_duration_seconds].
+    pub prev_epoch_duration_seconds: u32,
+    /// The new [Gaugemeister::epoch_duration_seconds].
+    pub new_epoch_duration_seconds: u32,
+    /// The previous [Gaugemeister::params_change_delay_seconds].
+    pub prev_params_change_delay_seconds: u32,
+    /// The new [Gaugemeister::params_change_delay_seconds].
+    pub new_params_change_delay_seconds: u32,
+    /// The previous [Gaugemeister::fixed_epoch_schedule].
+    pub prev_fixed_epoch_schedule: bool,
+    /// The new [Gaugemeister::fixed_epoch_schedule].
+    pub new_fixed_epoch_schedule: bool,
+}
@@ -0,0 +1,56 @@
+//! Cancels the pending parameters of a [Gaugemeister].
+
+use crate::*;
+
+/// Accounts for [gauge::cancel_gaugemeister_params].
+#[derive(Accounts)]
+pub struct CancelGaugemeisterParams<'info> {
+    /// The [Gaugemeister] to modify.
+    #[account(mut)]
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [Gaugemeister::foreman].
+    pub foreman: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CancelGaugemeisterParams>) -> Result<()> {
+    let gaugemeister = &mut ctx.accounts.gaugemeister;
+    let pending_foreman = gaugemeister.pending_foreman;
+
+    gaugemeister.pending_foreman = Pubkey::default();
+    gaugemeister.pending_epoch_duration_seconds = 0;
+    gaugemeister.pending_params_change_delay_seconds = 0;
+    gaugemeister.pending_fixed_epoch_schedule = false;
+    gaugemeister.pending_params_executable_at = 0;
+
+    emit!(CancelGaugemeisterParamsEvent {
+        gaugemeister: gaugemeister.key(),
+        foreman: ctx.accounts.foreman.key(),
+        pending_foreman,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for CancelGaugemeisterParams<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.foreman,
+            self.gaugemeister.foreman,
+            UnauthorizedNotForeman
+        );
+        invariant!(self.gaugemeister.has_pending_params(), NoPendingParams);
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::cancel_gaugemeister_params].
+#[event]
+pub struct CancelGaugemeisterParamsEvent {
+    /// The [Gaugemeister].
+    #[index]
+    pub gaugemeister: Pubkey,
+    /// The [Gaugemeister::foreman] that cancelled the change.
+    pub foreman: Pubkey,
+    /// The [Gaugemeister::pending_foreman] of the cancelled change.
+    pub pending_foreman: Pubkey,
+}
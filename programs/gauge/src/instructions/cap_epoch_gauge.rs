@@ -0,0 +1,89 @@
+//! Caps an [EpochGauge] which received more than its maximum share of votes.
+
+use crate::*;
+
+/// Accounts for [gauge::cap_epoch_gauge].
+#[derive(Accounts)]
+pub struct CapEpochGauge<'info> {
+    /// The [Gaugemeister].
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [Gauge].
+    #[account(has_one = gaugemeister)]
+    pub gauge: Account<'info, Gauge>,
+    /// The [EpochGauge] of the current rewards epoch.
+    #[account(mut, has_one = gauge)]
+    pub epoch_gauge: Account<'info, EpochGauge>,
+    /// The [EpochGaugemeister] of the current rewards epoch.
+    #[account(mut, has_one = gaugemeister)]
+    pub epoch_gaugemeister: Account<'info, EpochGaugemeister>,
+}
+
+pub fn handler(ctx: Context<CapEpochGauge>) -> Result<()> {
+    let cap_bps = ctx.accounts.gaugemeister.max_share_bps(&ctx.accounts.gauge);
+
+    let epoch_gauge = &mut ctx.accounts.epoch_gauge;
+    epoch_gauge.cap_bps = cap_bps;
+
+    let epoch_gaugemeister = &mut ctx.accounts.epoch_gaugemeister;
+    epoch_gaugemeister.capped_power = unwrap_int!(epoch_gaugemeister
+        .capped_power
+        .checked_add(epoch_gauge.total_power));
+    epoch_gaugemeister.capped_bps =
+        unwrap_int!(epoch_gaugemeister.capped_bps.checked_add(cap_bps.into()));
+
+    emit!(CapEpochGaugeEvent {
+        gaugemeister: ctx.accounts.gaugemeister.key(),
+        gauge: ctx.accounts.gauge.key(),
+        epoch: epoch_gauge.voting_epoch,
+        cap_bps,
+        capped_power: epoch_gaugemeister.capped_power,
+        capped_bps: epoch_gaugemeister.capped_bps,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for CapEpochGauge<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(!self.gaugemeister.is_paused, GaugemeisterPaused);
+        let epoch = self.gaugemeister.current_rewards_epoch;
+        invariant!(epoch != 0, GaugeEpochCannotBeZero);
+        invariant!(self.epoch_gauge.voting_epoch == epoch, GaugeWrongEpoch);
+        invariant!(
+            self.epoch_gaugemeister.voting_epoch == epoch,
+            GaugeWrongEpoch
+        );
+
+        invariant!(self.epoch_gauge.cap_bps == 0, EpochGaugeAlreadyCapped);
+        invariant!(!self.epoch_gauge.is_dropped, EpochGaugeAlreadyDropped);
+        invariant!(
+            self.gaugemeister.is_over_cap(
+                &self.gauge,
+                self.epoch_gauge.total_power,
+                unwrap_int!(self.epoch_gaugemeister.distributed_power())
+            ),
+            EpochGaugeNotOverCap
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::cap_epoch_gauge].
+#[event]
+pub struct CapEpochGaugeEvent {
+    /// The [Gaugemeister].
+    #[index]
+    pub gaugemeister: Pubkey,
+    /// The [Gauge].
+    #[index]
+    pub gauge: Pubkey,
+    /// The epoch capped.
+    #[index]
+    pub epoch: u32,
+    /// The [EpochGauge::cap_bps].
+    pub cap_bps: u16,
+    /// The updated [EpochGaugemeister::capped_power].
+    pub capped_power: u64,
+    /// The updated [EpochGaugemeister::capped_bps].
+    pub capped_bps: u32,
+}
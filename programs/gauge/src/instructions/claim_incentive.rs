@@ -0,0 +1,154 @@
+//! Claims a voter's share of an [EpochGaugeIncentive].
+
+use anchor_spl::token::{self, Token, TokenAccount};
+
+use crate::*;
+
+/// Accounts for [gauge::claim_incentive].
+#[derive(Accounts)]
+pub struct ClaimIncentive<'info> {
+    /// The [Gaugemeister].
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [Gauge].
+    #[account(has_one = gaugemeister)]
+    pub gauge: Account<'info, Gauge>,
+    /// The [EpochGauge] of the [EpochGaugeIncentive::voting_epoch].
+    #[account(has_one = gauge)]
+    pub epoch_gauge: Account<'info, EpochGauge>,
+
+    /// The [EpochGaugeIncentive] to claim from.
+    #[account(mut, has_one = gauge, has_one = token_account)]
+    pub epoch_gauge_incentive: Account<'info, EpochGaugeIncentive>,
+    /// The [EpochGaugeIncentive::token_account].
+    #[account(mut)]
+    pub token_account: Account<'info, TokenAccount>,
+
+    /// The [GaugeVoter] claiming.
+    #[account(has_one = gaugemeister, has_one = owner)]
+    pub gauge_voter: Account<'info, GaugeVoter>,
+    /// The [GaugeVote] of the [GaugeVoter] for the [Gauge].
+    #[account(has_one = gauge_voter, has_one = gauge)]
+    pub gauge_vote: Account<'info, GaugeVote>,
+    /// The [EpochGaugeVote] containing the power the [GaugeVoter] committed.
+    #[account(
+        seeds = [
+            b"EpochGaugeVote".as_ref(),
+            gauge_vote.key().as_ref(),
+            epoch_gauge_incentive.voting_epoch.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub epoch_gauge_vote: Account<'info, EpochGaugeVote>,
+
+    /// The [IncentiveClaim] to create, which prevents claiming twice.
+    #[account(
+        init,
+        seeds = [
+            b"IncentiveClaim".as_ref(),
+            epoch_gauge_incentive.key().as_ref(),
+            gauge_voter.key().as_ref()
+        ],
+        bump,
+        space = 8 + IncentiveClaim::LEN,
+        payer = payer
+    )]
+    pub incentive_claim: Account<'info, IncentiveClaim>,
+
+    /// The [GaugeVoter::owner].
+    pub owner: Signer<'info>,
+    /// The token account receiving the claimed tokens.
+    #[account(mut)]
+    pub destination: Account<'info, TokenAccount>,
+
+    /// Funder of the [IncentiveClaim].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// The SPL token program.
+    pub token_program: Program<'info, Token>,
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimIncentive>) -> Result<()> {
+    let incentive = &ctx.accounts.epoch_gauge_incentive;
+    let amount = unwrap_int!(incentive.claim_amount(
+        ctx.accounts.epoch_gauge_vote.allocated_power,
+        ctx.accounts.epoch_gauge.total_power
+    ));
+
+    let seeds: &[&[&[u8]]] = epoch_gauge_incentive_seeds!(incentive);
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.token_account.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: incentive.to_account_info(),
+            },
+        )
+        .with_signer(seeds),
+        amount,
+    )?;
+
+    let incentive = &mut ctx.accounts.epoch_gauge_incentive;
+    incentive.claimed_amount = unwrap_int!(incentive.claimed_amount.checked_add(amount));
+
+    let incentive_claim = &mut ctx.accounts.incentive_claim;
+    incentive_claim.epoch_gauge_incentive = incentive.key();
+    incentive_claim.gauge_voter = ctx.accounts.gauge_voter.key();
+    incentive_claim.gauge = incentive.gauge;
+    incentive_claim.voting_epoch = incentive.voting_epoch;
+    incentive_claim.amount = amount;
+    incentive_claim.rent_payer = ctx.accounts.payer.key();
+
+    emit!(ClaimIncentiveEvent {
+        gaugemeister: ctx.accounts.gaugemeister.key(),
+        gauge: incentive.gauge,
+        gauge_voter_owner: ctx.accounts.owner.key(),
+        epoch_gauge_incentive: incentive.key(),
+        voting_epoch: incentive.voting_epoch,
+        amount,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for ClaimIncentive<'info> {
+    fn validate(&self) -> Result<()> {
+        let voting_epoch = self.epoch_gauge_incentive.voting_epoch;
+        invariant!(
+            self.gaugemeister.incentive_claims_open(voting_epoch),
+            IncentiveClaimsNotOpen
+        );
+        invariant!(
+            self.epoch_gauge.voting_epoch == voting_epoch,
+            GaugeWrongEpoch
+        );
+        assert_keys_eq!(
+            self.destination.mint,
+            self.epoch_gauge_incentive.mint,
+            InvalidIncentiveTokenAccount
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::claim_incentive].
+#[event]
+pub struct ClaimIncentiveEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    #[index]
+    /// The [Gauge].
+    pub gauge: Pubkey,
+    #[index]
+    /// Owner of the Escrow of the [GaugeVoter].
+    pub gauge_voter_owner: Pubkey,
+    /// The [EpochGaugeIncentive].
+    pub epoch_gauge_incentive: Pubkey,
+    /// The voting epoch whose votes are rewarded.
+    pub voting_epoch: u32,
+    /// The amount claimed.
+    pub amount: u64,
+}
@@ -0,0 +1,65 @@
+//! Closes an [EpochGauge], freeing lamports.
+
+use crate::*;
+
+/// Accounts for [gauge::close_epoch_gauge].
+#[derive(Accounts)]
+pub struct CloseEpochGauge<'info> {
+    /// The [Gaugemeister].
+    pub gaugemeister: Account<'info, Gaugemeister>,
+
+    /// The [Gauge].
+    #[account(has_one = gaugemeister)]
+    pub gauge: Account<'info, Gauge>,
+
+    /// The [EpochGauge] to close.
+    /// Lamports go to the [EpochGauge::rent_payer].
+    #[account(mut, has_one = gauge, has_one = rent_payer, close = rent_payer)]
+    pub epoch_gauge: Account<'info, EpochGauge>,
+
+    /// The account that paid the rent of the [EpochGauge].
+    /// CHECK: validated by key against [EpochGauge::rent_payer].
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CloseEpochGauge>) -> Result<()> {
+    emit!(CloseEpochGaugeEvent {
+        gaugemeister: ctx.accounts.gaugemeister.key(),
+        gauge: ctx.accounts.gauge.key(),
+        voting_epoch: ctx.accounts.epoch_gauge.voting_epoch,
+        rent_payer: ctx.accounts.rent_payer.key(),
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for CloseEpochGauge<'info> {
+    fn validate(&self) -> Result<()> {
+        // The [EpochGauge] of the current rewards epoch is needed by [gauge::sync_gauge].
+        invariant!(
+            self.epoch_gauge.voting_epoch < self.gaugemeister.current_rewards_epoch,
+            CloseEpochNotElapsed
+        );
+        // Voters need the [EpochGauge::total_power] to claim incentives.
+        invariant!(
+            !self.epoch_gauge.has_open_incentives(&self.gaugemeister),
+            IncentiveClaimsOpen
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::close_epoch_gauge].
+#[event]
+pub struct CloseEpochGaugeEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    #[index]
+    /// The [Gauge].
+    pub gauge: Pubkey,
+    /// The epoch of the closed [EpochGauge].
+    pub voting_epoch: u32,
+    /// The account that received the rent.
+    pub rent_payer: Pubkey,
+}
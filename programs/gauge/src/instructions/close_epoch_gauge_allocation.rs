@@ -0,0 +1,60 @@
+//! Closes an [EpochGaugeAllocation], freeing lamports.
+
+use crate::*;
+
+/// Accounts for [gauge::close_epoch_gauge_allocation].
+#[derive(Accounts)]
+pub struct CloseEpochGaugeAllocation<'info> {
+    /// The [Gaugemeister].
+    pub gaugemeister: Account<'info, Gaugemeister>,
+
+    /// The [Gauge].
+    #[account(has_one = gaugemeister)]
+    pub gauge: Account<'info, Gauge>,
+
+    /// The [EpochGaugeAllocation] to close.
+    /// Lamports go to the [EpochGaugeAllocation::rent_payer].
+    #[account(mut, has_one = gauge, has_one = rent_payer, close = rent_payer)]
+    pub epoch_gauge_allocation: Account<'info, EpochGaugeAllocation>,
+
+    /// The account that paid the rent of the [EpochGaugeAllocation].
+    /// CHECK: validated by key against [EpochGaugeAllocation::rent_payer].
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CloseEpochGaugeAllocation>) -> Result<()> {
+    emit!(CloseEpochGaugeAllocationEvent {
+        gaugemeister: ctx.accounts.gaugemeister.key(),
+        gauge: ctx.accounts.gauge.key(),
+        voting_epoch: ctx.accounts.epoch_gauge_allocation.voting_epoch,
+        rent_payer: ctx.accounts.rent_payer.key(),
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for CloseEpochGaugeAllocation<'info> {
+    fn validate(&self) -> Result<()> {
+        // The [EpochGaugeAllocation] of the current rewards epoch is needed by [gauge::sync_gauge].
+        invariant!(
+            self.epoch_gauge_allocation.voting_epoch < self.gaugemeister.current_rewards_epoch,
+            CloseEpochNotElapsed
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::close_epoch_gauge_allocation].
+#[event]
+pub struct CloseEpochGaugeAllocationEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    #[index]
+    /// The [Gauge].
+    pub gauge: Pubkey,
+    /// The epoch of the closed [EpochGaugeAllocation].
+    pub voting_epoch: u32,
+    /// The account that received the rent.
+    pub rent_payer: Pubkey,
+}
@@ -1,14 +1,13 @@
 //! Closes an [EpochGaugeVote], freeing lamports.
 
 use crate::*;
-use locked_voter::Escrow;
 
-/// Accounts for [gauge::create_epoch_gauge].
+/// Accounts for [gauge::close_epoch_gauge_vote].
 #[derive(Accounts)]
 #[instruction(voting_epoch: u32)]
 pub struct CloseEpochGaugeVote<'info> {
     /// The [EpochGaugeVote] to close.
-    /// Lamports go to the recipient.
+    /// Lamports go to the [EpochGaugeVote::rent_payer].
     #[account(
         mut,
         seeds = [
@@ -17,7 +16,8 @@ pub struct CloseEpochGaugeVote<'info> {
             voting_epoch.to_le_bytes().as_ref(),
         ],
         bump,
-        close = recipient
+        has_one = rent_payer,
+        close = rent_payer
     )]
     pub epoch_gauge_vote: Account<'info, EpochGaugeVote>,
 
@@ -29,23 +29,28 @@ pub struct CloseEpochGaugeVote<'info> {
     pub gauge: Account<'info, Gauge>,
 
     /// The [GaugeVoter].
-    #[account(has_one = gaugemeister, has_one = escrow)]
+    #[account(mut, has_one = gaugemeister)]
     pub gauge_voter: Account<'info, GaugeVoter>,
 
     /// The [GaugeVote].
-    #[account(has_one = gauge_voter, has_one = gauge)]
+    #[account(mut, has_one = gauge_voter, has_one = gauge)]
     pub gauge_vote: Account<'info, GaugeVote>,
+    /// The [EpochGauge] of the voting epoch.
+    /// CHECK: validated by seeds, and may already be closed.
+    #[account(
+        seeds = [
+            b"EpochGauge".as_ref(),
+            gauge.key().as_ref(),
+            voting_epoch.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub epoch_gauge: UncheckedAccount<'info>,
 
-    /// The [Escrow] which owns this [EpochGaugeVote].
-    #[account(has_one = vote_delegate @ crate::ErrorCode::UnauthorizedNotDelegate)]
-    pub escrow: Account<'info, Escrow>,
-
-    /// The [Escrow::vote_delegate].
-    pub vote_delegate: Signer<'info>,
-
-    /// Recipient of the freed lamports.
+    /// The account that paid the rent of the [EpochGaugeVote].
+    /// CHECK: validated by key against [EpochGaugeVote::rent_payer].
     #[account(mut)]
-    pub recipient: SystemAccount<'info>,
+    pub rent_payer: UncheckedAccount<'info>,
 }
 
 pub fn handler(ctx: Context<CloseEpochGaugeVote>, voting_epoch: u32) -> Result<()> {
@@ -55,6 +60,20 @@ pub fn handler(ctx: Context<CloseEpochGaugeVote>, voting_epoch: u32) -> Result<(
     let (epoch_gauge_vote_key, _) =
         EpochGaugeVote::find_program_address(ctx.accounts.gauge_vote.as_key_ref(), voting_epoch);
     assert_keys_eq!(epoch_gauge_vote_key, ctx.accounts.epoch_gauge_vote);
+
+    // Voters need their [EpochGaugeVote] to claim incentives.
+    if !ctx.accounts.epoch_gauge.data_is_empty() {
+        let epoch_gauge: Account<EpochGauge> = Account::try_from(&ctx.accounts.epoch_gauge)?;
+        invariant!(
+            !epoch_gauge.has_open_incentives(&ctx.accounts.gaugemeister),
+            IncentiveClaimsOpen
+        );
+    }
+
+    unwrap_int!(ctx
+        .accounts
+        .gauge_vote
+        .close_epoch_vote(&mut ctx.accounts.gauge_voter));
     Ok(())
 }
 
@@ -0,0 +1,62 @@
+//! Closes an [EpochGaugeVoter], freeing lamports.
+
+use crate::*;
+
+/// Accounts for [gauge::close_epoch_gauge_voter].
+#[derive(Accounts)]
+pub struct CloseEpochGaugeVoter<'info> {
+    /// The [Gaugemeister].
+    pub gaugemeister: Account<'info, Gaugemeister>,
+
+    /// The [GaugeVoter].
+    #[account(mut, has_one = gaugemeister)]
+    pub gauge_voter: Account<'info, GaugeVoter>,
+
+    /// The [EpochGaugeVoter] to close.
+    /// Lamports go to the [EpochGaugeVoter::rent_payer].
+    #[account(mut, has_one = gauge_voter, has_one = rent_payer, close = rent_payer)]
+    pub epoch_gauge_voter: Account<'info, EpochGaugeVoter>,
+
+    /// The account that paid the rent of the [EpochGaugeVoter].
+    /// CHECK: validated by key against [EpochGaugeVoter::rent_payer].
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CloseEpochGaugeVoter>) -> Result<()> {
+    let gauge_voter = &mut ctx.accounts.gauge_voter;
+    gauge_voter.open_epoch_accounts = unwrap_int!(gauge_voter.open_epoch_accounts.checked_sub(1));
+
+    emit!(CloseEpochGaugeVoterEvent {
+        gaugemeister: ctx.accounts.gaugemeister.key(),
+        gauge_voter_owner: ctx.accounts.gauge_voter.owner,
+        voting_epoch: ctx.accounts.epoch_gauge_voter.voting_epoch,
+        rent_payer: ctx.accounts.rent_payer.key(),
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for CloseEpochGaugeVoter<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(
+            self.epoch_gauge_voter.voting_epoch < self.gaugemeister.current_rewards_epoch,
+            CloseEpochNotElapsed
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::close_epoch_gauge_voter].
+#[event]
+pub struct CloseEpochGaugeVoterEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    #[index]
+    /// Owner of the Escrow of the [GaugeVoter].
+    pub gauge_voter_owner: Pubkey,
+    /// The epoch of the closed [EpochGaugeVoter].
+    pub voting_epoch: u32,
+    /// The account that received the rent.
+    pub rent_payer: Pubkey,
+}
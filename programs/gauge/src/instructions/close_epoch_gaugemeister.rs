@@ -0,0 +1,57 @@
+//! Closes an [EpochGaugemeister], freeing lamports.
+
+use crate::*;
+
+/// Accounts for [gauge::close_epoch_gaugemeister].
+#[derive(Accounts)]
+pub struct CloseEpochGaugemeister<'info> {
+    /// The [Gaugemeister].
+    pub gaugemeister: Account<'info, Gaugemeister>,
+
+    /// The [EpochGaugemeister] to close.
+    /// Lamports go to the [EpochGaugemeister::rent_payer].
+    #[account(
+        mut,
+        has_one = gaugemeister,
+        has_one = rent_payer,
+        close = rent_payer
+    )]
+    pub epoch_gaugemeister: Account<'info, EpochGaugemeister>,
+
+    /// The account that paid the rent of the [EpochGaugemeister].
+    /// CHECK: validated by key against [EpochGaugemeister::rent_payer].
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CloseEpochGaugemeister>) -> Result<()> {
+    emit!(CloseEpochGaugemeisterEvent {
+        gaugemeister: ctx.accounts.gaugemeister.key(),
+        voting_epoch: ctx.accounts.epoch_gaugemeister.voting_epoch,
+        rent_payer: ctx.accounts.rent_payer.key(),
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for CloseEpochGaugemeister<'info> {
+    fn validate(&self) -> Result<()> {
+        // The [EpochGaugemeister] of the current rewards epoch is needed by [gauge::sync_gauge].
+        invariant!(
+            self.epoch_gaugemeister.voting_epoch < self.gaugemeister.current_rewards_epoch,
+            CloseEpochNotElapsed
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::close_epoch_gaugemeister].
+#[event]
+pub struct CloseEpochGaugemeisterEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    /// The epoch of the closed [EpochGaugemeister].
+    pub voting_epoch: u32,
+    /// The account that received the rent.
+    pub rent_payer: Pubkey,
+}
@@ -0,0 +1,72 @@
+//! Closes a [GaugeVote], freeing lamports.
+
+use crate::*;
+
+/// Accounts for [gauge::close_gauge_vote].
+#[derive(Accounts)]
+pub struct CloseGaugeVote<'info> {
+    /// The [Gaugemeister].
+    pub gaugemeister: Account<'info, Gaugemeister>,
+
+    /// The [GaugeVoter].
+    pub gauge_voter: Account<'info, GaugeVoter>,
+
+    /// The [GaugeVote] to close.
+    /// Lamports go to the recipient.
+    #[account(mut, has_one = gauge_voter, close = recipient)]
+    pub gauge_vote: Account<'info, GaugeVote>,
+
+    /// The [locked_voter::Escrow] of the [GaugeVoter]. This may have already been closed.
+    /// CHECK: validated by key in `assert_is_owner_or_escrow_delegate`.
+    pub escrow: UncheckedAccount<'info>,
+
+    /// The owner or vote delegate of the [locked_voter::Escrow].
+    pub authority: Signer<'info>,
+
+    /// Recipient of the freed lamports.
+    #[account(mut)]
+    pub recipient: SystemAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CloseGaugeVote>) -> Result<()> {
+    emit!(CloseGaugeVoteEvent {
+        gaugemeister: ctx.accounts.gauge_voter.gaugemeister,
+        gauge: ctx.accounts.gauge_vote.gauge,
+        gauge_voter_owner: ctx.accounts.gauge_voter.owner,
+        recipient: ctx.accounts.recipient.key(),
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for CloseGaugeVote<'info> {
+    fn validate(&self) -> Result<()> {
+        utils::assert_is_owner_or_escrow_delegate(
+            &self.gaugemeister,
+            &self.gauge_voter,
+            &self.escrow,
+            self.authority.key,
+        )?;
+        invariant!(self.gauge_vote.weight == 0, GaugeVoteWeightMustBeZero);
+        invariant!(
+            self.gauge_vote.open_epoch_votes == 0,
+            EpochAccountsMustBeClosed
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::close_gauge_vote].
+#[event]
+pub struct CloseGaugeVoteEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    #[index]
+    /// The [Gauge].
+    pub gauge: Pubkey,
+    #[index]
+    /// Owner of the Escrow of the [GaugeVoter].
+    pub gauge_voter_owner: Pubkey,
+    /// Recipient of the freed lamports.
+    pub recipient: Pubkey,
+}
@@ -0,0 +1,93 @@
+//! Closes a [GaugeVoter], freeing lamports.
+
+use crate::*;
+
+/// Accounts for [gauge::close_gauge_voter].
+#[derive(Accounts)]
+pub struct CloseGaugeVoter<'info> {
+    /// The [Gaugemeister].
+    pub gaugemeister: Account<'info, Gaugemeister>,
+
+    /// The [GaugeVoter] to close.
+    /// Lamports go to the recipient.
+    #[account(mut, has_one = gaugemeister, close = recipient)]
+    pub gauge_voter: Account<'info, GaugeVoter>,
+
+    /// The [EpochGaugeVoter] of the current voting epoch. This may not exist.
+    /// CHECK: validated by seeds and deserialized if it exists.
+    #[account(
+        seeds = [
+            b"EpochGaugeVoter".as_ref(),
+            gauge_voter.key().as_ref(),
+            gaugemeister.voting_epoch()?.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub epoch_gauge_voter: UncheckedAccount<'info>,
+
+    /// The [locked_voter::Escrow] of the [GaugeVoter]. This may have already been closed.
+    /// CHECK: validated by key in `assert_is_owner_or_escrow_delegate`.
+    pub escrow: UncheckedAccount<'info>,
+
+    /// The owner or vote delegate of the [locked_voter::Escrow].
+    pub authority: Signer<'info>,
+
+    /// Recipient of the freed lamports.
+    #[account(mut)]
+    pub recipient: SystemAccount<'info>,
+}
+
+impl<'info> CloseGaugeVoter<'info> {
+    /// Power committed in the current voting epoch.
+    fn allocated_power(&self) -> Result<u64> {
+        if *self.epoch_gauge_voter.owner != crate::ID {
+            return Ok(0);
+        }
+        let epoch_gauge_voter: Account<EpochGaugeVoter> =
+            Account::try_from(&self.epoch_gauge_voter)?;
+        Ok(epoch_gauge_voter.allocated_power)
+    }
+}
+
+pub fn handler(ctx: Context<CloseGaugeVoter>) -> Result<()> {
+    emit!(CloseGaugeVoterEvent {
+        gaugemeister: ctx.accounts.gaugemeister.key(),
+        gauge_voter_owner: ctx.accounts.gauge_voter.owner,
+        recipient: ctx.accounts.recipient.key(),
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for CloseGaugeVoter<'info> {
+    fn validate(&self) -> Result<()> {
+        utils::assert_is_owner_or_escrow_delegate(
+            &self.gaugemeister,
+            &self.gauge_voter,
+            &self.escrow,
+            self.authority.key,
+        )?;
+        invariant!(
+            self.gauge_voter.total_weight == 0,
+            GaugeVoterTotalWeightMustBeZero
+        );
+        invariant!(self.allocated_power()? == 0, AllocatedPowerMustBeZero);
+        invariant!(
+            self.gauge_voter.open_epoch_accounts == 0,
+            EpochAccountsMustBeClosed
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::close_gauge_voter].
+#[event]
+pub struct CloseGaugeVoterEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    #[index]
+    /// Owner of the Escrow of the [GaugeVoter].
+    pub gauge_voter_owner: Pubkey,
+    /// Recipient of the freed lamports.
+    pub recipient: Pubkey,
+}
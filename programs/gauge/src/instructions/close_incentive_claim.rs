@@ -0,0 +1,64 @@
+//! Closes an [IncentiveClaim], freeing lamports.
+
+use crate::*;
+
+/// Accounts for [gauge::close_incentive_claim].
+#[derive(Accounts)]
+pub struct CloseIncentiveClaim<'info> {
+    /// The [Gaugemeister].
+    pub gaugemeister: Account<'info, Gaugemeister>,
+
+    /// The [Gauge].
+    #[account(has_one = gaugemeister)]
+    pub gauge: Account<'info, Gauge>,
+
+    /// The [IncentiveClaim] to close.
+    /// Lamports go to the [IncentiveClaim::rent_payer].
+    #[account(mut, has_one = gauge, has_one = rent_payer, close = rent_payer)]
+    pub incentive_claim: Account<'info, IncentiveClaim>,
+
+    /// The account that paid the rent of the [IncentiveClaim].
+    /// CHECK: validated by key against [IncentiveClaim::rent_payer].
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CloseIncentiveClaim>) -> Result<()> {
+    emit!(CloseIncentiveClaimEvent {
+        gaugemeister: ctx.accounts.gaugemeister.key(),
+        gauge: ctx.accounts.gauge.key(),
+        epoch_gauge_incentive: ctx.accounts.incentive_claim.epoch_gauge_incentive,
+        gauge_voter: ctx.accounts.incentive_claim.gauge_voter,
+        rent_payer: ctx.accounts.rent_payer.key(),
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for CloseIncentiveClaim<'info> {
+    fn validate(&self) -> Result<()> {
+        // The [IncentiveClaim] prevents double claims until the claim window has ended.
+        invariant!(
+            self.gaugemeister
+                .incentive_claims_ended(self.incentive_claim.voting_epoch),
+            IncentiveClaimsOpen
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::close_incentive_claim].
+#[event]
+pub struct CloseIncentiveClaimEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    #[index]
+    /// The [Gauge].
+    pub gauge: Pubkey,
+    /// The [EpochGaugeIncentive] that was claimed.
+    pub epoch_gauge_incentive: Pubkey,
+    /// The [GaugeVoter] that claimed.
+    pub gauge_voter: Pubkey,
+    /// The account that received the rent.
+    pub rent_payer: Pubkey,
+}
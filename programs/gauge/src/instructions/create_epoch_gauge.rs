@@ -3,6 +3,10 @@
 use crate::*;
 
 /// Accounts for [gauge::create_epoch_gauge].
+///
+/// To receive a tip, the remaining accounts must be the [Gaugemeister] of the [Gauge],
+/// followed by the accounts of the [TipVault] described in `pay_crank_tip`.
+/// Tips are only paid for [EpochGauge]s of enabled [Gauge]s in the current voting epoch.
 #[derive(Accounts)]
 #[instruction(_bump: u8, voting_epoch: u32)]
 pub struct CreateEpochGauge<'info> {
@@ -31,11 +35,41 @@ pub struct CreateEpochGauge<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<CreateEpochGauge>, voting_epoch: u32) -> Result<()> {
+/// Pays a tip for creating the [EpochGauge] if it is for the current voting epoch.
+fn pay_tip<'info>(
+    gauge: &Gauge,
+    voting_epoch: u32,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let (gaugemeister_info, tip_accounts) = match remaining_accounts.split_first() {
+        Some(accounts) => accounts,
+        None => return Ok(()),
+    };
+    let gaugemeister: Account<Gaugemeister> = Account::try_from(gaugemeister_info)?;
+    assert_keys_eq!(gaugemeister, gauge.gaugemeister);
+    if gauge.is_disabled || voting_epoch != gaugemeister.voting_epoch()? {
+        return Ok(());
+    }
+    pay_crank_tip(
+        &gauge.gaugemeister,
+        gaugemeister.current_rewards_epoch,
+        CrankKind::CreateEpochGauge,
+        tip_accounts,
+    )
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateEpochGauge<'info>>,
+    voting_epoch: u32,
+) -> Result<()> {
     let epoch_gauge = &mut ctx.accounts.epoch_gauge;
     epoch_gauge.gauge = ctx.accounts.gauge.key();
     epoch_gauge.voting_epoch = voting_epoch;
     epoch_gauge.total_power = 0;
+    epoch_gauge.rent_payer = ctx.accounts.payer.key();
+    epoch_gauge.cap_bps = 0;
+    epoch_gauge.group = ctx.accounts.gauge.group;
+    epoch_gauge.num_incentives = 0;
 
     emit!(EpochGaugeCreateEvent {
         gaugemeister: ctx.accounts.gauge.gaugemeister,
@@ -43,7 +77,7 @@ pub fn handler(ctx: Context<CreateEpochGauge>, voting_epoch: u32) -> Result<()>
         voting_epoch,
     });
 
-    Ok(())
+    pay_tip(&ctx.accounts.gauge, voting_epoch, ctx.remaining_accounts)
 }
 
 impl<'info> Validate<'info> for CreateEpochGauge<'info> {
@@ -0,0 +1,76 @@
+//! Creates an [EpochGaugeAllocation].
+
+use crate::*;
+
+/// Accounts for [gauge::create_epoch_gauge_allocation].
+#[derive(Accounts)]
+#[instruction(voting_epoch: u32)]
+pub struct CreateEpochGaugeAllocation<'info> {
+    /// The [Gaugemeister].
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [Gauge] to create an [EpochGaugeAllocation] of.
+    #[account(has_one = gaugemeister)]
+    pub gauge: Account<'info, Gauge>,
+
+    /// The [EpochGaugeAllocation] to be created.
+    #[account(
+        init,
+        seeds = [
+            b"EpochGaugeAllocation".as_ref(),
+            gauge.key().as_ref(),
+            voting_epoch.to_le_bytes().as_ref()
+        ],
+        bump,
+        space = 8 + EpochGaugeAllocation::LEN,
+        payer = payer
+    )]
+    pub epoch_gauge_allocation: Account<'info, EpochGaugeAllocation>,
+
+    /// Payer.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateEpochGaugeAllocation>, voting_epoch: u32) -> Result<()> {
+    // allocations can only be made while the epoch is being voted on.
+    invariant!(
+        voting_epoch == ctx.accounts.gaugemeister.voting_epoch()?,
+        EpochGaugeNotVoting
+    );
+
+    let epoch_gauge_allocation = &mut ctx.accounts.epoch_gauge_allocation;
+    epoch_gauge_allocation.gauge = ctx.accounts.gauge.key();
+    epoch_gauge_allocation.voting_epoch = voting_epoch;
+    epoch_gauge_allocation.weight = 0;
+    epoch_gauge_allocation.rent_payer = ctx.accounts.payer.key();
+
+    emit!(EpochGaugeAllocationCreateEvent {
+        gaugemeister: ctx.accounts.gaugemeister.key(),
+        gauge: ctx.accounts.gauge.key(),
+        voting_epoch,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for CreateEpochGaugeAllocation<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::create_epoch_gauge_allocation].
+#[event]
+pub struct EpochGaugeAllocationCreateEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    #[index]
+    /// The [Gauge].
+    pub gauge: Pubkey,
+    /// The epoch associated with this [EpochGaugeAllocation].
+    pub voting_epoch: u32,
+}
@@ -0,0 +1,111 @@
+//! Creates an [EpochGaugeIncentive].
+
+use anchor_spl::token::{Mint, TokenAccount};
+
+use crate::*;
+
+/// Accounts for [gauge::create_epoch_gauge_incentive].
+#[derive(Accounts)]
+pub struct CreateEpochGaugeIncentive<'info> {
+    /// The [Gaugemeister].
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [Gauge] whose voters are rewarded.
+    #[account(has_one = gaugemeister)]
+    pub gauge: Account<'info, Gauge>,
+    /// The [EpochGauge] of the voting epoch whose votes are rewarded.
+    #[account(mut, has_one = gauge)]
+    pub epoch_gauge: Account<'info, EpochGauge>,
+
+    /// The [EpochGaugeIncentive] to be created.
+    #[account(
+        init,
+        seeds = [
+            b"EpochGaugeIncentive".as_ref(),
+            gauge.key().as_ref(),
+            epoch_gauge.voting_epoch.to_le_bytes().as_ref(),
+            depositor.key().as_ref(),
+            mint.key().as_ref()
+        ],
+        bump,
+        space = 8 + EpochGaugeIncentive::LEN,
+        payer = payer
+    )]
+    pub epoch_gauge_incentive: Account<'info, EpochGaugeIncentive>,
+
+    /// The depositor of the incentive.
+    pub depositor: Signer<'info>,
+    /// The mint of the incentive.
+    pub mint: Account<'info, Mint>,
+    /// The [EpochGaugeIncentive::token_account].
+    pub token_account: Account<'info, TokenAccount>,
+
+    /// Payer.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateEpochGaugeIncentive>) -> Result<()> {
+    let epoch_gauge = &mut ctx.accounts.epoch_gauge;
+    epoch_gauge.num_incentives = unwrap_int!(epoch_gauge.num_incentives.checked_add(1));
+
+    let incentive = &mut ctx.accounts.epoch_gauge_incentive;
+    incentive.gauge = ctx.accounts.gauge.key();
+    incentive.voting_epoch = epoch_gauge.voting_epoch;
+    incentive.depositor = ctx.accounts.depositor.key();
+    incentive.bump = *unwrap_int!(ctx.bumps.get("epoch_gauge_incentive"));
+    incentive.mint = ctx.accounts.mint.key();
+    incentive.token_account = ctx.accounts.token_account.key();
+    incentive.amount = 0;
+    incentive.claimed_amount = 0;
+
+    emit!(EpochGaugeIncentiveCreateEvent {
+        gaugemeister: ctx.accounts.gaugemeister.key(),
+        gauge: incentive.gauge,
+        depositor: incentive.depositor,
+        mint: incentive.mint,
+        voting_epoch: incentive.voting_epoch,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for CreateEpochGaugeIncentive<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(
+            self.epoch_gauge.voting_epoch > self.gaugemeister.current_rewards_epoch,
+            IncentiveDepositsClosed
+        );
+        assert_keys_eq!(
+            self.token_account.owner,
+            self.epoch_gauge_incentive,
+            InvalidIncentiveTokenAccount
+        );
+        assert_keys_eq!(
+            self.token_account.mint,
+            self.mint,
+            InvalidIncentiveTokenAccount
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::create_epoch_gauge_incentive].
+#[event]
+pub struct EpochGaugeIncentiveCreateEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    #[index]
+    /// The [Gauge].
+    pub gauge: Pubkey,
+    #[index]
+    /// The depositor of the incentive.
+    pub depositor: Pubkey,
+    /// The mint of the incentive.
+    pub mint: Pubkey,
+    /// The voting epoch whose votes are rewarded.
+    pub voting_epoch: u32,
+}
@@ -0,0 +1,77 @@
+//! Creates an [EpochGaugemeister].
+
+use crate::*;
+
+/// Accounts for [gauge::create_epoch_gaugemeister].
+#[derive(Accounts)]
+#[instruction(voting_epoch: u32)]
+pub struct CreateEpochGaugemeister<'info> {
+    /// The [Gaugemeister] to create an [EpochGaugemeister] of.
+    pub gaugemeister: Account<'info, Gaugemeister>,
+
+    /// The [EpochGaugemeister] to be created.
+    #[account(
+        init,
+        seeds = [
+            b"EpochGaugemeister".as_ref(),
+            gaugemeister.key().as_ref(),
+            voting_epoch.to_le_bytes().as_ref()
+        ],
+        bump,
+        space = 8 + EpochGaugemeister::LEN,
+        payer = payer
+    )]
+    pub epoch_gaugemeister: Account<'info, EpochGaugemeister>,
+
+    /// Payer.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateEpochGaugemeister>, voting_epoch: u32) -> Result<()> {
+    // totals can only be counted from the start of voting on the epoch.
+    invariant!(
+        voting_epoch == ctx.accounts.gaugemeister.voting_epoch()?,
+        EpochGaugeNotVoting
+    );
+
+    let epoch_gaugemeister = &mut ctx.accounts.epoch_gaugemeister;
+    epoch_gaugemeister.gaugemeister = ctx.accounts.gaugemeister.key();
+    epoch_gaugemeister.voting_epoch = voting_epoch;
+    epoch_gaugemeister.total_power = 0;
+    epoch_gaugemeister.num_gauges_voted = 0;
+    epoch_gaugemeister.num_voters = 0;
+    epoch_gaugemeister.capped_power = 0;
+    epoch_gaugemeister.capped_bps = 0;
+    epoch_gaugemeister.total_allocation_weight = 0;
+    epoch_gaugemeister.group_power = [0; MAX_GAUGE_GROUPS];
+    epoch_gaugemeister.rent_payer = ctx.accounts.payer.key();
+    // budget changes made during the epoch only apply from the next voting epoch.
+    epoch_gaugemeister.group_budget_weights = ctx.accounts.gaugemeister.group_budget_weights;
+
+    emit!(EpochGaugemeisterCreateEvent {
+        gaugemeister: ctx.accounts.gaugemeister.key(),
+        voting_epoch,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for CreateEpochGaugemeister<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::create_epoch_gaugemeister].
+#[event]
+pub struct EpochGaugemeisterCreateEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    /// The epoch associated with this [EpochGaugemeister].
+    pub voting_epoch: u32,
+}
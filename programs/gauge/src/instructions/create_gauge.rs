@@ -41,6 +41,11 @@ pub fn handler(ctx: Context<CreateGauge>) -> Result<()> {
     gauge.quarry = ctx.accounts.quarry.key();
     // Since this is permissionless, gauges are disabled when they are created.
     gauge.is_disabled = true;
+    gauge.max_share_bps = 0;
+    gauge.floor_bps = 0;
+    gauge.group = 0;
+    gauge.activation_epoch = 0;
+    gauge.sunset_epoch = 0;
     Ok(())
 }
 
@@ -41,6 +41,7 @@ pub fn handler(ctx: Context<CreateGaugeVote>) -> Result<()> {
     gauge_vote.gauge = ctx.accounts.gauge.key();
 
     gauge_vote.weight = 0;
+    gauge_vote.open_epoch_votes = 0;
 
     emit!(GaugeVoteCreateEvent {
         gaugemeister: ctx.accounts.gauge.gaugemeister,
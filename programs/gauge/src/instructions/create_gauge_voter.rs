@@ -43,6 +43,10 @@ pub fn handler(ctx: Context<CreateGaugeVoter>) -> Result<()> {
     gauge_voter.owner = ctx.accounts.escrow.owner;
     gauge_voter.total_weight = 0;
     gauge_voter.weight_change_seqno = 0;
+    gauge_voter.is_persistent = false;
+    gauge_voter.gauge_delegate = Pubkey::default();
+    gauge_voter.gauge_delegate_expires_epoch = 0;
+    gauge_voter.open_epoch_accounts = 0;
 
     emit!(GaugeVoterCreateEvent {
         gaugemeister: gauge_voter.gaugemeister,
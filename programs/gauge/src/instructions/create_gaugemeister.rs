@@ -43,6 +43,7 @@ pub fn handler(
     foreman: Pubkey,
     epoch_duration_seconds: u32,
     first_epoch_starts_at: u64,
+    params_change_delay_seconds: u32,
 ) -> Result<()> {
     let now = unwrap_int!(Clock::get()?.unix_timestamp.to_u64());
     invariant!(
@@ -68,6 +69,26 @@ pub fn handler(
     gaugemeister.locker_token_mint = ctx.accounts.locker.token_mint;
     gaugemeister.locker_governor = ctx.accounts.locker.governor;
 
+    gaugemeister.fixed_epoch_schedule = false;
+
+    gaugemeister.params_change_delay_seconds = params_change_delay_seconds;
+    gaugemeister.pending_foreman = Pubkey::default();
+    gaugemeister.pending_epoch_duration_seconds = 0;
+    gaugemeister.pending_params_change_delay_seconds = 0;
+    gaugemeister.pending_params_executable_at = 0;
+
+    gaugemeister.curator = foreman;
+    gaugemeister.guardian = foreman;
+    gaugemeister.is_paused = false;
+    gaugemeister.normalize_rewards_shares = false;
+    gaugemeister.max_gauge_share_bps = 0;
+    gaugemeister.min_gauge_power = 0;
+    gaugemeister.min_gauge_share_bps = 0;
+    gaugemeister.total_floor_bps = 0;
+    gaugemeister.foreman_slice_bps = 0;
+    gaugemeister.group_budget_weights = [0; MAX_GAUGE_GROUPS];
+    gaugemeister.pending_fixed_epoch_schedule = false;
+
     emit!(GaugemeisterCreateEvent {
         gaugemeister: gaugemeister.key(),
         rewarder: gaugemeister.rewarder,
@@ -0,0 +1,109 @@
+//! Creates a [TipVault].
+
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::*;
+
+/// Accounts for [gauge::create_tip_vault].
+#[derive(Accounts)]
+pub struct CreateTipVault<'info> {
+    /// The [Gaugemeister].
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [Gaugemeister::foreman].
+    pub foreman: Signer<'info>,
+
+    /// The [TipVault] to be created.
+    #[account(
+        init,
+        seeds = [
+            b"TipVault".as_ref(),
+            gaugemeister.key().as_ref()
+        ],
+        bump,
+        space = 8 + TipVault::LEN,
+        payer = payer
+    )]
+    pub tip_vault: Account<'info, TipVault>,
+
+    /// The [TipVault::token_account].
+    /// CHECK: must be the default address if tips are paid in SOL, otherwise checked in the handler.
+    pub token_account: UncheckedAccount<'info>,
+
+    /// Payer.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateTipVault>, mint: Pubkey) -> Result<()> {
+    let tip_vault_key = ctx.accounts.tip_vault.key();
+    let token_account_info = &ctx.accounts.token_account;
+    if mint == Pubkey::default() {
+        assert_keys_eq!(
+            token_account_info.key(),
+            Pubkey::default(),
+            InvalidTipVaultTokenAccount
+        );
+    } else {
+        invariant!(
+            *token_account_info.owner == Token::id(),
+            InvalidTipVaultTokenAccount
+        );
+        let token_account: Account<TokenAccount> =
+            Account::try_from(&token_account_info.to_account_info())?;
+        assert_keys_eq!(
+            token_account.owner,
+            tip_vault_key,
+            InvalidTipVaultTokenAccount
+        );
+        assert_keys_eq!(token_account.mint, mint, InvalidTipVaultTokenAccount);
+    }
+
+    let tip_vault = &mut ctx.accounts.tip_vault;
+    tip_vault.gaugemeister = ctx.accounts.gaugemeister.key();
+    tip_vault.bump = *unwrap_int!(ctx.bumps.get("tip_vault"));
+    tip_vault.mint = mint;
+    tip_vault.token_account = token_account_info.key();
+    tip_vault.tip_amount = 0;
+    tip_vault.max_tips_per_epoch = 0;
+    tip_vault.tips_epoch = 0;
+    tip_vault.tips_paid_in_epoch = [0; NUM_CRANK_KINDS];
+    tip_vault.total_tips_paid = 0;
+    tip_vault.commit_vote_tips_paid_in_epoch = 0;
+
+    emit!(TipVaultCreateEvent {
+        gaugemeister: tip_vault.gaugemeister,
+        tip_vault: tip_vault_key,
+        mint,
+        token_account: tip_vault.token_account,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for CreateTipVault<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.foreman,
+            self.gaugemeister.foreman,
+            UnauthorizedNotForeman
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::create_tip_vault].
+#[event]
+pub struct TipVaultCreateEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    /// The [TipVault].
+    pub tip_vault: Pubkey,
+    /// The [TipVault::mint].
+    pub mint: Pubkey,
+    /// The [TipVault::token_account].
+    pub token_account: Pubkey,
+}
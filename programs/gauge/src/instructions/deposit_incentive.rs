@@ -0,0 +1,92 @@
+//! Deposits tokens into an [EpochGaugeIncentive].
+
+use anchor_spl::token::{self, Token, TokenAccount};
+
+use crate::*;
+
+/// Accounts for [gauge::deposit_incentive].
+#[derive(Accounts)]
+pub struct DepositIncentive<'info> {
+    /// The [Gaugemeister].
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [Gauge].
+    #[account(has_one = gaugemeister)]
+    pub gauge: Account<'info, Gauge>,
+    /// The [EpochGaugeIncentive] to deposit into.
+    #[account(mut, has_one = gauge, has_one = depositor, has_one = token_account)]
+    pub epoch_gauge_incentive: Account<'info, EpochGaugeIncentive>,
+    /// The [EpochGaugeIncentive::token_account].
+    #[account(mut)]
+    pub token_account: Account<'info, TokenAccount>,
+
+    /// The [EpochGaugeIncentive::depositor].
+    pub depositor: Signer<'info>,
+    /// The token account of the depositor to transfer tokens from.
+    #[account(mut)]
+    pub source: Account<'info, TokenAccount>,
+
+    /// The SPL token program.
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<DepositIncentive>, amount: u64) -> Result<()> {
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.source.to_account_info(),
+                to: ctx.accounts.token_account.to_account_info(),
+                authority: ctx.accounts.depositor.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let incentive = &mut ctx.accounts.epoch_gauge_incentive;
+    incentive.amount = unwrap_int!(incentive.amount.checked_add(amount));
+
+    emit!(DepositIncentiveEvent {
+        gaugemeister: ctx.accounts.gaugemeister.key(),
+        gauge: incentive.gauge,
+        depositor: incentive.depositor,
+        mint: incentive.mint,
+        voting_epoch: incentive.voting_epoch,
+        amount,
+        total_amount: incentive.amount,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for DepositIncentive<'info> {
+    fn validate(&self) -> Result<()> {
+        // Deposits must be final before claims open, so every claim sees the same total.
+        invariant!(
+            self.epoch_gauge_incentive.voting_epoch > self.gaugemeister.current_rewards_epoch,
+            IncentiveDepositsClosed
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::deposit_incentive].
+#[event]
+pub struct DepositIncentiveEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    #[index]
+    /// The [Gauge].
+    pub gauge: Pubkey,
+    #[index]
+    /// The depositor of the incentive.
+    pub depositor: Pubkey,
+    /// The mint of the incentive.
+    pub mint: Pubkey,
+    /// The voting epoch whose votes are rewarded.
+    pub voting_epoch: u32,
+    /// The amount deposited.
+    pub amount: u64,
+    /// The new [EpochGaugeIncentive::amount].
+    pub total_amount: u64,
+}
@@ -0,0 +1,83 @@
+//! Drops an [EpochGauge] which received less than the minimum power.
+
+use crate::*;
+
+/// Accounts for [gauge::drop_epoch_gauge].
+#[derive(Accounts)]
+pub struct DropEpochGauge<'info> {
+    /// The [Gaugemeister].
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [Gauge].
+    #[account(has_one = gaugemeister)]
+    pub gauge: Account<'info, Gauge>,
+    /// The [EpochGauge] of the current rewards epoch.
+    #[account(mut, has_one = gauge)]
+    pub epoch_gauge: Account<'info, EpochGauge>,
+    /// The [EpochGaugemeister] of the current rewards epoch.
+    #[account(mut, has_one = gaugemeister)]
+    pub epoch_gaugemeister: Account<'info, EpochGaugemeister>,
+}
+
+pub fn handler(ctx: Context<DropEpochGauge>) -> Result<()> {
+    let epoch_gauge = &mut ctx.accounts.epoch_gauge;
+    epoch_gauge.is_dropped = true;
+
+    let epoch_gaugemeister = &mut ctx.accounts.epoch_gaugemeister;
+    unwrap_int!(epoch_gaugemeister.drop_power(epoch_gauge));
+
+    emit!(DropEpochGaugeEvent {
+        gaugemeister: ctx.accounts.gaugemeister.key(),
+        gauge: ctx.accounts.gauge.key(),
+        epoch: epoch_gauge.voting_epoch,
+        dropped_power: epoch_gauge.total_power,
+        capped_power: epoch_gaugemeister.capped_power,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for DropEpochGauge<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(!self.gaugemeister.is_paused, GaugemeisterPaused);
+        let epoch = self.gaugemeister.current_rewards_epoch;
+        invariant!(epoch != 0, GaugeEpochCannotBeZero);
+        invariant!(self.epoch_gauge.voting_epoch == epoch, GaugeWrongEpoch);
+        invariant!(
+            self.epoch_gaugemeister.voting_epoch == epoch,
+            GaugeWrongEpoch
+        );
+
+        invariant!(!self.epoch_gauge.is_dropped, EpochGaugeAlreadyDropped);
+        invariant!(self.epoch_gauge.cap_bps == 0, EpochGaugeAlreadyCapped);
+        invariant!(
+            self.epoch_gauge.total_power != 0,
+            EpochGaugeNotBelowThreshold
+        );
+        invariant!(
+            self.gaugemeister.is_below_threshold(
+                self.epoch_gauge.total_power,
+                self.epoch_gaugemeister.total_power
+            ),
+            EpochGaugeNotBelowThreshold
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::drop_epoch_gauge].
+#[event]
+pub struct DropEpochGaugeEvent {
+    /// The [Gaugemeister].
+    #[index]
+    pub gaugemeister: Pubkey,
+    /// The [Gauge].
+    #[index]
+    pub gauge: Pubkey,
+    /// The epoch dropped.
+    #[index]
+    pub epoch: u32,
+    /// The [EpochGauge::total_power] which does not earn rewards.
+    pub dropped_power: u64,
+    /// The updated [EpochGaugemeister::capped_power].
+    pub capped_power: u64,
+}
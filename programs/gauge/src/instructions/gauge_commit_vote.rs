@@ -3,6 +3,9 @@
 use crate::*;
 
 /// Accounts for [gauge::gauge_commit_vote].
+///
+/// The accounts of the [TipVault] described in `pay_crank_tip` may be passed as remaining
+/// accounts to receive a tip if the vote commits any power.
 #[derive(Accounts)]
 pub struct GaugeCommitVote<'info> {
     /// The [Gaugemeister].
@@ -10,8 +13,10 @@ pub struct GaugeCommitVote<'info> {
     /// The [Gauge].
     pub gauge: Account<'info, Gauge>,
     /// The [GaugeVoter].
+    #[account(mut)]
     pub gauge_voter: Account<'info, GaugeVoter>,
     /// The [GaugeVote] containing the vote weights.
+    #[account(mut)]
     pub gauge_vote: Account<'info, GaugeVote>,
 
     /// The [EpochGauge].
@@ -20,6 +25,9 @@ pub struct GaugeCommitVote<'info> {
     /// The [EpochGaugeVoter].
     #[account(mut)]
     pub epoch_gauge_voter: Account<'info, EpochGaugeVoter>,
+    /// The [EpochGaugemeister].
+    #[account(mut)]
+    pub epoch_gaugemeister: Account<'info, EpochGaugemeister>,
 
     /// The [EpochGaugeVote] to create.
     #[account(
@@ -58,7 +66,13 @@ impl<'info> GaugeCommitVote<'info> {
     }
 }
 
-pub fn handler(ctx: Context<GaugeCommitVote>) -> Result<()> {
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, GaugeCommitVote<'info>>) -> Result<()> {
+    ctx.accounts.epoch_gauge_vote.rent_payer = ctx.accounts.payer.key();
+    unwrap_int!(ctx
+        .accounts
+        .gauge_vote
+        .open_epoch_vote(&mut ctx.accounts.gauge_voter));
+
     let next_vote_shares = unwrap_int!(ctx.accounts.vote_shares_for_next_epoch());
     // if zero vote shares, don't do anything
     if next_vote_shares == 0 {
@@ -69,12 +83,13 @@ pub fn handler(ctx: Context<GaugeCommitVote>) -> Result<()> {
     let epoch_voter = &mut ctx.accounts.epoch_gauge_voter;
     let epoch_vote = &mut ctx.accounts.epoch_gauge_vote;
 
-    epoch_voter.allocated_power =
-        unwrap_int!(epoch_voter.allocated_power.checked_add(next_vote_shares));
+    unwrap_int!(ctx.accounts.epoch_gaugemeister.commit_power(
+        epoch_gauge,
+        epoch_voter,
+        next_vote_shares
+    ));
     epoch_vote.allocated_power = next_vote_shares;
 
-    epoch_gauge.total_power = unwrap_int!(epoch_gauge.total_power.checked_add(next_vote_shares));
-
     emit!(CommitGaugeVoteEvent {
         gaugemeister: ctx.accounts.gauge.gaugemeister,
         gauge: ctx.accounts.gauge.key(),
@@ -86,19 +101,30 @@ pub fn handler(ctx: Context<GaugeCommitVote>) -> Result<()> {
         updated_total_power: epoch_gauge.total_power,
     });
 
-    Ok(())
+    pay_crank_tip(
+        &ctx.accounts.gaugemeister.key(),
+        ctx.accounts.gaugemeister.current_rewards_epoch,
+        CrankKind::CommitVote,
+        ctx.remaining_accounts,
+    )
 }
 
 impl<'info> Validate<'info> for GaugeCommitVote<'info> {
     fn validate(&self) -> Result<()> {
+        invariant!(!self.gaugemeister.is_paused, GaugemeisterPaused);
         assert_keys_eq!(self.gaugemeister, self.gauge.gaugemeister);
         assert_keys_eq!(self.gauge, self.gauge_vote.gauge);
         assert_keys_eq!(self.gauge_voter, self.gauge_vote.gauge_voter);
 
         assert_keys_eq!(self.epoch_gauge.gauge, self.gauge);
         assert_keys_eq!(self.epoch_gauge_voter.gauge_voter, self.gauge_voter);
+        assert_keys_eq!(self.epoch_gaugemeister.gaugemeister, self.gaugemeister);
 
         invariant!(!self.gauge.is_disabled, CannotCommitGaugeDisabled);
+        invariant!(
+            self.gauge.is_active_in(self.gaugemeister.voting_epoch()?),
+            GaugeNotActive
+        );
         invariant!(
             self.epoch_gauge_voter.weight_change_seqno == self.gauge_voter.weight_change_seqno,
             WeightSeqnoChanged
@@ -109,6 +135,10 @@ impl<'info> Validate<'info> for GaugeCommitVote<'info> {
             self.epoch_gauge_voter.voting_epoch == voting_epoch,
             EpochGaugeNotVoting
         );
+        invariant!(
+            self.epoch_gaugemeister.voting_epoch == voting_epoch,
+            EpochGaugeNotVoting
+        );
 
         Ok(())
     }
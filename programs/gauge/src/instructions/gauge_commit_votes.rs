@@ -0,0 +1,241 @@
+//! Commits all of a [GaugeVoter]'s votes at once.
+
+use crate::utils::{create_program_account, write_account};
+use crate::*;
+
+/// Accounts for [gauge::gauge_commit_votes].
+///
+/// The remaining accounts must be groups of four accounts, one for each [GaugeVote] of the [GaugeVoter]:
+/// 1. The [Gauge].
+/// 2. The [GaugeVote] containing the vote weights, which must be writable.
+/// 3. The [EpochGauge] of the voting epoch, which must be writable.
+/// 4. The [EpochGaugeVote] to create, which must be writable.
+///
+/// Groups must be sorted by [GaugeVote] address, and the weights of all
+/// [GaugeVote]s must add up to [GaugeVoter::total_weight].
+#[derive(Accounts)]
+pub struct GaugeCommitVotes<'info> {
+    /// The [Gaugemeister].
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [GaugeVoter].
+    #[account(mut)]
+    pub gauge_voter: Account<'info, GaugeVoter>,
+    /// The [EpochGaugeVoter].
+    #[account(mut)]
+    pub epoch_gauge_voter: Account<'info, EpochGaugeVoter>,
+    /// The [EpochGaugemeister].
+    #[account(mut)]
+    pub epoch_gaugemeister: Account<'info, EpochGaugemeister>,
+
+    /// Funder of the [EpochGaugeVote]s to create.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Number of remaining accounts per [GaugeVote] in [gauge::gauge_commit_votes].
+const ACCOUNTS_PER_VOTE: usize = 4;
+
+/// Commits the [GaugeVote]s of a [GaugeVoter] passed as remaining accounts.
+///
+/// This is shared by [gauge::gauge_commit_votes] and [gauge::roll_forward_votes].
+pub(crate) struct VoteCommitter<'a, 'info> {
+    /// The [Gaugemeister].
+    pub(crate) gaugemeister: Pubkey,
+    /// The [GaugeVoter].
+    pub(crate) gauge_voter: &'a mut Account<'info, GaugeVoter>,
+    /// The [EpochGaugeVoter].
+    pub(crate) epoch_gauge_voter: &'a mut Account<'info, EpochGaugeVoter>,
+    /// The [EpochGaugemeister].
+    pub(crate) epoch_gaugemeister: &'a mut Account<'info, EpochGaugemeister>,
+    /// Funder of the [EpochGaugeVote]s to create.
+    pub(crate) payer: AccountInfo<'info>,
+    /// The [System] program.
+    pub(crate) system_program: AccountInfo<'info>,
+    /// If true, [GaugeVote]s for disabled or inactive [Gauge]s are skipped rather
+    /// than failing the whole commit. Their weight still counts as committed.
+    pub(crate) skip_inactive_gauges: bool,
+}
+
+impl<'a, 'info> VoteCommitter<'a, 'info> {
+    fn vote_shares_for_next_epoch(&self, weight: u32) -> Option<u64> {
+        if weight == 0 {
+            return Some(0);
+        }
+        ::u128::mul_div_u64(
+            self.epoch_gauge_voter.voting_power,
+            weight.into(),
+            self.gauge_voter.total_weight.into(),
+        )
+    }
+
+    /// Validates and commits a single [GaugeVote], returning its weight.
+    fn commit_vote(
+        &mut self,
+        gauge_info: &AccountInfo<'info>,
+        gauge_vote_info: &AccountInfo<'info>,
+        epoch_gauge_info: &AccountInfo<'info>,
+        epoch_gauge_vote_info: &AccountInfo<'info>,
+    ) -> Result<u32> {
+        let gauge: Account<Gauge> = Account::try_from(gauge_info)?;
+        let mut gauge_vote: Account<GaugeVote> = Account::try_from(gauge_vote_info)?;
+        assert_keys_eq!(self.gaugemeister, gauge.gaugemeister);
+        assert_keys_eq!(gauge, gauge_vote.gauge);
+        assert_keys_eq!(self.gauge_voter.key(), gauge_vote.gauge_voter);
+
+        let voting_epoch = self.epoch_gauge_voter.voting_epoch;
+        let (epoch_gauge_vote_key, epoch_gauge_vote_bump) =
+            EpochGaugeVote::find_program_address(&gauge_vote.key(), voting_epoch);
+        assert_keys_eq!(epoch_gauge_vote_key, epoch_gauge_vote_info.key());
+
+        // Votes which have already been committed count towards the total,
+        // so a partially failed commit may be retried.
+        if *epoch_gauge_vote_info.owner == crate::ID {
+            return Ok(gauge_vote.weight);
+        }
+
+        let next_vote_shares = unwrap_int!(self.vote_shares_for_next_epoch(gauge_vote.weight));
+        // if zero vote shares, don't do anything
+        if next_vote_shares == 0 {
+            return Ok(gauge_vote.weight);
+        }
+
+        let is_active = !gauge.is_disabled && gauge.is_active_in(voting_epoch);
+        if self.skip_inactive_gauges && !is_active {
+            emit!(SkipGaugeVoteEvent {
+                gaugemeister: gauge.gaugemeister,
+                gauge: gauge.key(),
+                gauge_voter_owner: self.gauge_voter.owner,
+                voting_epoch,
+                weight: gauge_vote.weight,
+            });
+            return Ok(gauge_vote.weight);
+        }
+        invariant!(!gauge.is_disabled, CannotCommitGaugeDisabled);
+        invariant!(gauge.is_active_in(voting_epoch), GaugeNotActive);
+        let mut epoch_gauge: Account<EpochGauge> = Account::try_from(epoch_gauge_info)?;
+        assert_keys_eq!(epoch_gauge.gauge, gauge);
+        invariant!(
+            epoch_gauge.voting_epoch == voting_epoch,
+            EpochGaugeNotVoting
+        );
+
+        let epoch_bytes = voting_epoch.to_le_bytes();
+        let gauge_vote_key = gauge_vote.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"EpochGaugeVote",
+            gauge_vote_key.as_ref(),
+            epoch_bytes.as_ref(),
+            &[epoch_gauge_vote_bump],
+        ]];
+        create_program_account(
+            self.payer.clone(),
+            epoch_gauge_vote_info.clone(),
+            self.system_program.clone(),
+            8 + EpochGaugeVote::LEN,
+            signer_seeds,
+        )?;
+        write_account(
+            epoch_gauge_vote_info,
+            &EpochGaugeVote {
+                allocated_power: next_vote_shares,
+                rent_payer: self.payer.key(),
+            },
+        )?;
+        unwrap_int!(gauge_vote.open_epoch_vote(self.gauge_voter));
+        gauge_vote.exit(&crate::ID)?;
+
+        let epoch_voter: &mut EpochGaugeVoter = self.epoch_gauge_voter;
+        unwrap_int!(self.epoch_gaugemeister.commit_power(
+            &mut epoch_gauge,
+            epoch_voter,
+            next_vote_shares
+        ));
+        epoch_gauge.exit(&crate::ID)?;
+
+        emit!(CommitGaugeVoteEvent {
+            gaugemeister: gauge.gaugemeister,
+            gauge: gauge.key(),
+            quarry: gauge.quarry,
+            gauge_voter_owner: self.gauge_voter.owner,
+            vote_shares_for_next_epoch: next_vote_shares,
+            voting_epoch,
+            updated_allocated_power: epoch_voter.allocated_power,
+            updated_total_power: epoch_gauge.total_power,
+        });
+
+        Ok(gauge_vote.weight)
+    }
+
+    /// Commits all of the [GaugeVote]s of the [GaugeVoter].
+    ///
+    /// The `remaining_accounts` must be groups of four accounts, sorted by [GaugeVote],
+    /// as described in [GaugeCommitVotes].
+    pub(crate) fn commit_all(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let votes_accounts = remaining_accounts.chunks_exact(ACCOUNTS_PER_VOTE);
+        invariant!(
+            votes_accounts.remainder().is_empty(),
+            InvalidRemainingAccounts
+        );
+
+        let mut committed_weight: u32 = 0;
+        let mut prev_gauge_vote = Pubkey::default();
+        for accounts in votes_accounts {
+            // Sorting prevents the same vote from being counted twice.
+            let gauge_vote = accounts[1].key();
+            invariant!(prev_gauge_vote < gauge_vote, GaugeVotesNotSorted);
+            prev_gauge_vote = gauge_vote;
+
+            let weight =
+                self.commit_vote(&accounts[0], &accounts[1], &accounts[2], &accounts[3])?;
+            committed_weight = unwrap_int!(committed_weight.checked_add(weight));
+        }
+        invariant!(
+            committed_weight == self.gauge_voter.total_weight,
+            GaugeVotesIncomplete
+        );
+
+        Ok(())
+    }
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, GaugeCommitVotes<'info>>) -> Result<()> {
+    let accounts = ctx.accounts;
+    VoteCommitter {
+        gaugemeister: accounts.gaugemeister.key(),
+        gauge_voter: &mut accounts.gauge_voter,
+        epoch_gauge_voter: &mut accounts.epoch_gauge_voter,
+        epoch_gaugemeister: &mut accounts.epoch_gaugemeister,
+        payer: accounts.payer.to_account_info(),
+        system_program: accounts.system_program.to_account_info(),
+        skip_inactive_gauges: false,
+    }
+    .commit_all(ctx.remaining_accounts)
+}
+
+impl<'info> Validate<'info> for GaugeCommitVotes<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(!self.gaugemeister.is_paused, GaugemeisterPaused);
+        assert_keys_eq!(self.gaugemeister, self.gauge_voter.gaugemeister);
+        assert_keys_eq!(self.epoch_gauge_voter.gauge_voter, self.gauge_voter);
+        assert_keys_eq!(self.epoch_gaugemeister.gaugemeister, self.gaugemeister);
+
+        invariant!(
+            self.epoch_gauge_voter.weight_change_seqno == self.gauge_voter.weight_change_seqno,
+            WeightSeqnoChanged
+        );
+
+        let voting_epoch = self.gaugemeister.voting_epoch()?;
+        invariant!(
+            self.epoch_gauge_voter.voting_epoch == voting_epoch,
+            EpochGaugeNotVoting
+        );
+        invariant!(
+            self.epoch_gaugemeister.voting_epoch == voting_epoch,
+            EpochGaugeNotVoting
+        );
+
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 //! Disables a [Gauge].
 
-use vipers::assert_keys_eq;
+use vipers::{assert_keys_eq, invariant};
 
 use crate::*;
 
@@ -8,12 +8,13 @@ use crate::*;
 #[derive(Accounts)]
 pub struct GaugeDisable<'info> {
     /// The [Gaugemeister].
+    #[account(mut)]
     pub gaugemeister: Account<'info, Gaugemeister>,
     /// The [Gauge] to disable.
     #[account(mut)]
     pub gauge: Account<'info, Gauge>,
-    /// The [Gaugemeister::foreman].
-    pub foreman: Signer<'info>,
+    /// The [Gaugemeister::curator] or [Gaugemeister::guardian].
+    pub authority: Signer<'info>,
 }
 
 /// Emitted on [gauge::gauge_disable].
@@ -25,27 +26,33 @@ pub struct GaugeDisableEvent {
     /// The [Gaugemeister].
     #[index]
     pub gaugemeister: Pubkey,
-    /// The [Gaugemeister::foreman] that disabled the gauge.
-    pub foreman: Pubkey,
+    /// The [Gaugemeister::curator] or [Gaugemeister::guardian] that disabled the gauge.
+    pub authority: Pubkey,
 }
 
 pub fn handler(ctx: Context<GaugeDisable>) -> Result<()> {
+    let gaugemeister = &mut ctx.accounts.gaugemeister;
     let gauge = &mut ctx.accounts.gauge;
+    if !gauge.is_disabled {
+        // Disabled gauges do not receive their floor.
+        gaugemeister.total_floor_bps =
+            unwrap_int!(gaugemeister.total_floor_bps.checked_sub(gauge.floor_bps));
+    }
     gauge.is_disabled = true;
     emit!(GaugeDisableEvent {
-        gaugemeister: ctx.accounts.gaugemeister.key(),
+        gaugemeister: gaugemeister.key(),
         gauge: gauge.key(),
-        foreman: ctx.accounts.foreman.key(),
+        authority: ctx.accounts.authority.key(),
     });
     Ok(())
 }
 
 impl<'info> Validate<'info> for GaugeDisable<'info> {
     fn validate(&self) -> Result<()> {
-        assert_keys_eq!(
-            self.foreman,
-            self.gaugemeister.foreman,
-            UnauthorizedNotForeman
+        invariant!(
+            self.authority.key() == self.gaugemeister.curator
+                || self.authority.key() == self.gaugemeister.guardian,
+            UnauthorizedNotCuratorOrGuardian
         );
         assert_keys_eq!(self.gauge.gaugemeister, self.gaugemeister);
         Ok(())
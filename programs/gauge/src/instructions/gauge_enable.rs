@@ -1,6 +1,6 @@
 //! Enables a [Gauge].
 
-use vipers::assert_keys_eq;
+use vipers::{assert_keys_eq, invariant};
 
 use crate::*;
 
@@ -8,12 +8,13 @@ use crate::*;
 #[derive(Accounts)]
 pub struct GaugeEnable<'info> {
     /// The [Gaugemeister].
+    #[account(mut)]
     pub gaugemeister: Account<'info, Gaugemeister>,
     /// The [Gauge] to enable.
     #[account(mut)]
     pub gauge: Account<'info, Gauge>,
-    /// The [Gaugemeister::foreman].
-    pub foreman: Signer<'info>,
+    /// The [Gaugemeister::curator].
+    pub curator: Signer<'info>,
 }
 
 /// Emitted on [gauge::gauge_enable].
@@ -25,17 +26,25 @@ pub struct GaugeEnableEvent {
     /// The [Gaugemeister].
     #[index]
     pub gaugemeister: Pubkey,
-    /// The [Gaugemeister::foreman] that enabled the gauge.
-    pub foreman: Pubkey,
+    /// The [Gaugemeister::curator] that enabled the gauge.
+    pub curator: Pubkey,
 }
 
 pub fn handler(ctx: Context<GaugeEnable>) -> Result<()> {
+    let gaugemeister = &mut ctx.accounts.gaugemeister;
     let gauge = &mut ctx.accounts.gauge;
+    if gauge.is_disabled {
+        let total_floor_bps =
+            unwrap_int!(gaugemeister.total_floor_bps.checked_add(gauge.floor_bps));
+        let reserved_bps = unwrap_int!(total_floor_bps.checked_add(gaugemeister.foreman_slice_bps));
+        invariant!(reserved_bps <= BPS_DENOMINATOR, InvalidBps);
+        gaugemeister.total_floor_bps = total_floor_bps;
+    }
     gauge.is_disabled = false;
     emit!(GaugeEnableEvent {
-        gaugemeister: ctx.accounts.gaugemeister.key(),
+        gaugemeister: gaugemeister.key(),
         gauge: gauge.key(),
-        foreman: ctx.accounts.foreman.key(),
+        curator: ctx.accounts.curator.key(),
     });
     Ok(())
 }
@@ -43,9 +52,9 @@ pub fn handler(ctx: Context<GaugeEnable>) -> Result<()> {
 impl<'info> Validate<'info> for GaugeEnable<'info> {
     fn validate(&self) -> Result<()> {
         assert_keys_eq!(
-            self.foreman,
-            self.gaugemeister.foreman,
-            UnauthorizedNotForeman
+            self.curator,
+            self.gaugemeister.curator,
+            UnauthorizedNotCurator
         );
         assert_keys_eq!(self.gauge.gaugemeister, self.gaugemeister);
         Ok(())
@@ -7,29 +7,35 @@ use crate::*;
 pub struct GaugeRevertVote<'info> {
     pub gaugemeister: Account<'info, Gaugemeister>,
     pub gauge: Account<'info, Gauge>,
+    #[account(mut)]
     pub gauge_voter: Account<'info, GaugeVoter>,
+    #[account(mut)]
     pub gauge_vote: Account<'info, GaugeVote>,
 
     #[account(mut)]
     pub epoch_gauge: Account<'info, EpochGauge>,
     #[account(mut)]
     pub epoch_gauge_voter: Account<'info, EpochGaugeVoter>,
+    #[account(mut)]
+    pub epoch_gaugemeister: Account<'info, EpochGaugemeister>,
 
     /// The escrow.
-    #[account(has_one = vote_delegate @ crate::ErrorCode::UnauthorizedNotDelegate)]
     pub escrow: Account<'info, locked_voter::Escrow>,
-    /// The vote delegate.
+    /// The vote delegate of the escrow, or the [GaugeVoter::gauge_delegate].
     pub vote_delegate: Signer<'info>,
 
     /// The [EpochGaugeVote] to revert.
     #[account(
         mut,
-        close = payer,
+        has_one = rent_payer,
+        close = rent_payer,
     )]
     pub epoch_gauge_vote: Account<'info, EpochGaugeVote>,
 
+    /// The account that paid the rent of the [EpochGaugeVote].
+    /// CHECK: validated by key against [EpochGaugeVote::rent_payer].
     #[account(mut)]
-    pub payer: Signer<'info>,
+    pub rent_payer: UncheckedAccount<'info>,
 }
 
 pub fn handler(ctx: Context<GaugeRevertVote>) -> Result<()> {
@@ -38,9 +44,15 @@ pub fn handler(ctx: Context<GaugeRevertVote>) -> Result<()> {
     let epoch_vote = &mut ctx.accounts.epoch_gauge_vote;
 
     let power_subtract = epoch_vote.allocated_power;
-    epoch_voter.allocated_power =
-        unwrap_int!(epoch_voter.allocated_power.checked_sub(power_subtract));
-    epoch_gauge.total_power = unwrap_int!(epoch_gauge.total_power.checked_sub(power_subtract));
+    unwrap_int!(ctx.accounts.epoch_gaugemeister.revert_power(
+        epoch_gauge,
+        epoch_voter,
+        power_subtract
+    ));
+    unwrap_int!(ctx
+        .accounts
+        .gauge_vote
+        .close_epoch_vote(&mut ctx.accounts.gauge_voter));
 
     emit!(RevertGaugeVoteEvent {
         gaugemeister: ctx.accounts.gaugemeister.key(),
@@ -71,6 +83,11 @@ impl<'info> Validate<'info> for GaugeRevertVote<'info> {
 
         assert_keys_eq!(self.epoch_gauge.gauge, self.gauge);
         assert_keys_eq!(self.epoch_gauge_voter.gauge_voter, self.gauge_voter);
+        assert_keys_eq!(self.epoch_gaugemeister.gaugemeister, self.gaugemeister);
+        invariant!(
+            self.epoch_gaugemeister.voting_epoch == voting_epoch,
+            EpochGaugeNotVoting
+        );
 
         assert_keys_eq!(self.gauge_vote.gauge_voter, self.gauge_voter);
         assert_keys_eq!(self.gauge_vote.gauge, self.gauge);
@@ -80,7 +97,12 @@ impl<'info> Validate<'info> for GaugeRevertVote<'info> {
         assert_keys_eq!(epoch_gauge_vote_key, self.epoch_gauge_vote);
 
         assert_keys_eq!(self.escrow, self.gauge_voter.escrow);
-        assert_keys_eq!(self.vote_delegate, self.escrow.vote_delegate);
+        utils::assert_is_vote_delegate(
+            &self.gaugemeister,
+            &self.gauge_voter,
+            &self.escrow,
+            self.vote_delegate.key,
+        )?;
 
         Ok(())
     }
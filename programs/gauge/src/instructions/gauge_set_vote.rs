@@ -18,10 +18,9 @@ pub struct GaugeSetVote<'info> {
     pub gauge_vote: Account<'info, GaugeVote>,
 
     /// The escrow.
-    #[account(has_one = vote_delegate @ crate::ErrorCode::UnauthorizedNotDelegate)]
     pub escrow: Account<'info, locked_voter::Escrow>,
 
-    /// The vote delegate.
+    /// The vote delegate of the escrow, or the [GaugeVoter::gauge_delegate].
     pub vote_delegate: Signer<'info>,
 }
 
@@ -39,6 +38,10 @@ impl<'info> GaugeSetVote<'info> {
     fn set_vote(&mut self, weight: u32) -> Result<()> {
         if weight != 0 {
             invariant!(!self.gauge.is_disabled, CannotVoteGaugeDisabled);
+            invariant!(
+                self.gauge.is_active_in(self.gaugemeister.voting_epoch()?),
+                GaugeNotActive
+            );
         }
 
         if self.gauge_vote.weight == weight {
@@ -82,12 +85,18 @@ pub fn handler(ctx: Context<GaugeSetVote>, weight: u32) -> Result<()> {
 
 impl<'info> Validate<'info> for GaugeSetVote<'info> {
     fn validate(&self) -> Result<()> {
+        invariant!(!self.gaugemeister.is_paused, GaugemeisterPaused);
         assert_keys_eq!(self.gaugemeister, self.gauge.gaugemeister);
         assert_keys_eq!(self.gauge, self.gauge_vote.gauge);
         assert_keys_eq!(self.gauge_voter, self.gauge_vote.gauge_voter);
 
         assert_keys_eq!(self.escrow, self.gauge_voter.escrow);
-        assert_keys_eq!(self.vote_delegate, self.escrow.vote_delegate);
+        utils::assert_is_vote_delegate(
+            &self.gaugemeister,
+            &self.gauge_voter,
+            &self.escrow,
+            self.vote_delegate.key,
+        )?;
         Ok(())
     }
 }
@@ -0,0 +1,151 @@
+//! Sets all of the votes of a [GaugeVoter] at once.
+
+use crate::*;
+
+/// Accounts for [gauge::gauge_set_votes].
+///
+/// The remaining accounts must be pairs of accounts, one for each weight:
+/// 1. The [Gauge].
+/// 2. The [GaugeVote], which must be writable.
+///
+/// Pairs must be sorted by [GaugeVote] address, and must include every
+/// [GaugeVote] of the [GaugeVoter] that currently has a non-zero weight.
+#[derive(Accounts)]
+pub struct GaugeSetVotes<'info> {
+    /// The [Gaugemeister].
+    pub gaugemeister: Account<'info, Gaugemeister>,
+
+    /// The [GaugeVoter].
+    #[account(mut)]
+    pub gauge_voter: Account<'info, GaugeVoter>,
+
+    /// The escrow.
+    pub escrow: Account<'info, locked_voter::Escrow>,
+
+    /// The vote delegate of the escrow, or the [GaugeVoter::gauge_delegate].
+    pub vote_delegate: Signer<'info>,
+}
+
+/// Number of remaining accounts per weight in [gauge::gauge_set_votes].
+const ACCOUNTS_PER_VOTE: usize = 2;
+
+impl<'info> GaugeSetVotes<'info> {
+    /// Validates and sets a single [GaugeVote], returning its previous weight.
+    fn set_vote(
+        &self,
+        gauge_info: &AccountInfo<'info>,
+        gauge_vote_info: &AccountInfo<'info>,
+        weight: u32,
+    ) -> Result<u32> {
+        let gauge: Account<Gauge> = Account::try_from(gauge_info)?;
+        let mut gauge_vote: Account<GaugeVote> = Account::try_from(gauge_vote_info)?;
+        assert_keys_eq!(self.gaugemeister, gauge.gaugemeister);
+        assert_keys_eq!(gauge, gauge_vote.gauge);
+        assert_keys_eq!(self.gauge_voter, gauge_vote.gauge_voter);
+
+        if weight != 0 {
+            invariant!(!gauge.is_disabled, CannotVoteGaugeDisabled);
+            invariant!(
+                gauge.is_active_in(self.gaugemeister.voting_epoch()?),
+                GaugeNotActive
+            );
+        }
+
+        let prev_weight = gauge_vote.weight;
+        if prev_weight != weight {
+            gauge_vote.weight = weight;
+            gauge_vote.exit(&crate::ID)?;
+        }
+        Ok(prev_weight)
+    }
+}
+
+impl<'info> Validate<'info> for GaugeSetVotes<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(!self.gaugemeister.is_paused, GaugemeisterPaused);
+        assert_keys_eq!(self.gaugemeister, self.gauge_voter.gaugemeister);
+        assert_keys_eq!(self.escrow, self.gauge_voter.escrow);
+        utils::assert_is_vote_delegate(
+            &self.gaugemeister,
+            &self.gauge_voter,
+            &self.escrow,
+            self.vote_delegate.key,
+        )?;
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::gauge_set_votes].
+#[event]
+pub struct SetGaugeVotesEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    #[index]
+    /// Owner of the Escrow of the [GaugeVoter].
+    pub gauge_voter_owner: Pubkey,
+    /// The vote delegate that set the votes.
+    pub vote_delegate: Pubkey,
+    /// Number of [GaugeVote]s that were set.
+    pub num_votes: u32,
+    /// Previous total weight of the [GaugeVoter].
+    pub prev_total_weight: u32,
+    /// New total weight of the [GaugeVoter].
+    pub total_weight: u32,
+    /// The new weight change sequence number.
+    /// This is only incremented if a weight changed.
+    pub weight_change_seqno: u64,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, GaugeSetVotes<'info>>,
+    weights: Vec<u32>,
+) -> Result<()> {
+    let votes_accounts = ctx.remaining_accounts.chunks_exact(ACCOUNTS_PER_VOTE);
+    invariant!(
+        votes_accounts.remainder().is_empty() && votes_accounts.len() == weights.len(),
+        InvalidRemainingAccounts
+    );
+    let num_votes = unwrap_int!(u32::try_from(weights.len()).ok());
+
+    let mut prev_weights_total: u32 = 0;
+    let mut next_total_weight: u32 = 0;
+    let mut weights_changed = false;
+    let mut prev_gauge_vote = Pubkey::default();
+    for (accounts, weight) in votes_accounts.zip(weights) {
+        // Sorting prevents the same vote from being counted twice.
+        let gauge_vote = accounts[1].key();
+        invariant!(prev_gauge_vote < gauge_vote, GaugeVotesNotSorted);
+        prev_gauge_vote = gauge_vote;
+
+        let prev_weight = ctx.accounts.set_vote(&accounts[0], &accounts[1], weight)?;
+        weights_changed |= prev_weight != weight;
+        prev_weights_total = unwrap_int!(prev_weights_total.checked_add(prev_weight));
+        next_total_weight = unwrap_int!(next_total_weight.checked_add(weight));
+    }
+
+    let voter = &mut ctx.accounts.gauge_voter;
+    let prev_total_weight = voter.total_weight;
+    // All votes with weight must be provided, so the new total is exactly the new weights.
+    invariant!(
+        prev_weights_total == prev_total_weight,
+        GaugeVotesIncomplete
+    );
+    voter.total_weight = next_total_weight;
+    if weights_changed {
+        // record that the weights have changed.
+        voter.weight_change_seqno = unwrap_int!(voter.weight_change_seqno.checked_add(1));
+    }
+
+    emit!(SetGaugeVotesEvent {
+        gaugemeister: ctx.accounts.gaugemeister.key(),
+        gauge_voter_owner: voter.owner,
+        vote_delegate: ctx.accounts.vote_delegate.key(),
+        num_votes,
+        prev_total_weight,
+        total_weight: voter.total_weight,
+        weight_change_seqno: voter.weight_change_seqno,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+//! Migrates an [EpochGauge] created with the legacy layout.
+
+use crate::*;
+
+/// Accounts for [gauge::migrate_epoch_gauge].
+#[derive(Accounts)]
+pub struct MigrateEpochGauge<'info> {
+    /// The [Gaugemeister].
+    pub gaugemeister: Account<'info, Gaugemeister>,
+
+    /// The [Gauge], which must have been migrated first.
+    #[account(has_one = gaugemeister)]
+    pub gauge: Account<'info, Gauge>,
+
+    /// The legacy [EpochGauge] to migrate.
+    /// CHECK: validated by owner, discriminator and length in the handler.
+    #[account(mut)]
+    pub epoch_gauge: UncheckedAccount<'info>,
+
+    /// The [EpochGaugemeister] of the epoch of the [EpochGauge], which must exist
+    /// while the epoch is being voted on.
+    /// CHECK: validated by seeds in the handler.
+    #[account(mut)]
+    pub epoch_gaugemeister: UncheckedAccount<'info>,
+
+    /// Payer of the rent of the extended account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateEpochGauge>) -> Result<()> {
+    let info = ctx.accounts.epoch_gauge.to_account_info();
+    utils::migrate_legacy_account::<EpochGauge>(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        EpochGauge::LEGACY_LEN,
+        EpochGauge::LEN,
+    )?;
+
+    let mut epoch_gauge: Account<EpochGauge> = Account::try_from(&info)?;
+    assert_keys_eq!(epoch_gauge.gauge, ctx.accounts.gauge);
+    // the payer of a legacy [EpochGauge] is unknown, so its rent is refunded to the foreman.
+    epoch_gauge.rent_payer = ctx.accounts.gaugemeister.foreman;
+    epoch_gauge.group = ctx.accounts.gauge.group;
+    epoch_gauge.exit(&crate::ID)?;
+
+    // the power of the legacy [EpochGauge] may still be reverted, so it must be in the totals.
+    if let Some(mut epoch_gaugemeister) = utils::load_legacy_epoch_gaugemeister(
+        &ctx.accounts.gaugemeister,
+        &ctx.accounts.epoch_gaugemeister,
+        epoch_gauge.voting_epoch,
+    )? {
+        unwrap_int!(epoch_gaugemeister.count_legacy_epoch_gauge(&epoch_gauge));
+        epoch_gaugemeister.exit(&crate::ID)?;
+    }
+
+    emit!(MigrateEpochGaugeEvent {
+        gaugemeister: ctx.accounts.gaugemeister.key(),
+        gauge: ctx.accounts.gauge.key(),
+        voting_epoch: epoch_gauge.voting_epoch,
+        rent_payer: epoch_gauge.rent_payer,
+        payer: ctx.accounts.payer.key(),
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for MigrateEpochGauge<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::migrate_epoch_gauge].
+#[event]
+pub struct MigrateEpochGaugeEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    #[index]
+    /// The [Gauge].
+    pub gauge: Pubkey,
+    /// The epoch of the [EpochGauge].
+    pub voting_epoch: u32,
+    /// The [EpochGauge::rent_payer] assigned to the account.
+    pub rent_payer: Pubkey,
+    /// Payer of the additional rent.
+    pub payer: Pubkey,
+}
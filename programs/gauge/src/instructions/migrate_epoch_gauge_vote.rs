@@ -0,0 +1,94 @@
+//! Migrates an [EpochGaugeVote] created with the legacy layout.
+
+use crate::*;
+
+/// Accounts for [gauge::migrate_epoch_gauge_vote].
+#[derive(Accounts)]
+#[instruction(voting_epoch: u32)]
+pub struct MigrateEpochGaugeVote<'info> {
+    /// The [GaugeVoter], which must have been migrated first.
+    #[account(mut)]
+    pub gauge_voter: Account<'info, GaugeVoter>,
+
+    /// The [GaugeVote], which must have been migrated first.
+    #[account(mut, has_one = gauge_voter)]
+    pub gauge_vote: Account<'info, GaugeVote>,
+
+    /// The legacy [EpochGaugeVote] to migrate.
+    /// CHECK: validated by seeds, and by owner, discriminator and length in the handler.
+    #[account(
+        mut,
+        seeds = [
+            b"EpochGaugeVote".as_ref(),
+            gauge_vote.key().as_ref(),
+            voting_epoch.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub epoch_gauge_vote: UncheckedAccount<'info>,
+
+    /// Payer of the rent of the extended account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateEpochGaugeVote>, voting_epoch: u32) -> Result<()> {
+    let info = ctx.accounts.epoch_gauge_vote.to_account_info();
+    utils::migrate_legacy_account::<EpochGaugeVote>(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        EpochGaugeVote::LEGACY_LEN,
+        EpochGaugeVote::LEN,
+    )?;
+
+    let mut epoch_gauge_vote: Account<EpochGaugeVote> = Account::try_from(&info)?;
+    // legacy votes were funded by the voter, so rent is refunded to the owner.
+    epoch_gauge_vote.rent_payer = ctx.accounts.gauge_voter.owner;
+    epoch_gauge_vote.exit(&crate::ID)?;
+
+    unwrap_int!(ctx
+        .accounts
+        .gauge_vote
+        .open_epoch_vote(&mut ctx.accounts.gauge_voter));
+
+    emit!(MigrateEpochGaugeVoteEvent {
+        gaugemeister: ctx.accounts.gauge_voter.gaugemeister,
+        gauge_voter: ctx.accounts.gauge_voter.key(),
+        gauge: ctx.accounts.gauge_vote.gauge,
+        voting_epoch,
+        rent_payer: epoch_gauge_vote.rent_payer,
+        payer: ctx.accounts.payer.key(),
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for MigrateEpochGaugeVote<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::migrate_epoch_gauge_vote].
+#[event]
+pub struct MigrateEpochGaugeVoteEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    #[index]
+    /// The [GaugeVoter].
+    pub gauge_voter: Pubkey,
+    #[index]
+    /// The [Gauge].
+    pub gauge: Pubkey,
+    /// The epoch of the [EpochGaugeVote].
+    pub voting_epoch: u32,
+    /// The [EpochGaugeVote::rent_payer] assigned to the account.
+    pub rent_payer: Pubkey,
+    /// Payer of the additional rent.
+    pub payer: Pubkey,
+}
@@ -0,0 +1,95 @@
+//! Migrates an [EpochGaugeVoter] created with the legacy layout.
+
+use crate::*;
+
+/// Accounts for [gauge::migrate_epoch_gauge_voter].
+#[derive(Accounts)]
+pub struct MigrateEpochGaugeVoter<'info> {
+    /// The [Gaugemeister].
+    pub gaugemeister: Account<'info, Gaugemeister>,
+
+    /// The [GaugeVoter], which must have been migrated first.
+    #[account(mut, has_one = gaugemeister)]
+    pub gauge_voter: Account<'info, GaugeVoter>,
+
+    /// The legacy [EpochGaugeVoter] to migrate.
+    /// CHECK: validated by owner, discriminator and length in the handler.
+    #[account(mut)]
+    pub epoch_gauge_voter: UncheckedAccount<'info>,
+
+    /// The [EpochGaugemeister] of the epoch of the [EpochGaugeVoter], which must exist
+    /// while the epoch is being voted on.
+    /// CHECK: validated by seeds in the handler.
+    #[account(mut)]
+    pub epoch_gaugemeister: UncheckedAccount<'info>,
+
+    /// Payer of the rent of the extended account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateEpochGaugeVoter>) -> Result<()> {
+    let info = ctx.accounts.epoch_gauge_voter.to_account_info();
+    utils::migrate_legacy_account::<EpochGaugeVoter>(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        EpochGaugeVoter::LEGACY_LEN,
+        EpochGaugeVoter::LEN,
+    )?;
+
+    let mut epoch_gauge_voter: Account<EpochGaugeVoter> = Account::try_from(&info)?;
+    assert_keys_eq!(epoch_gauge_voter.gauge_voter, ctx.accounts.gauge_voter);
+    // legacy accounts were funded by the voter, so rent is refunded to the owner.
+    epoch_gauge_voter.rent_payer = ctx.accounts.gauge_voter.owner;
+    epoch_gauge_voter.exit(&crate::ID)?;
+
+    // the power of the legacy [EpochGaugeVoter] may still be reverted, so it must be counted.
+    if let Some(mut epoch_gaugemeister) = utils::load_legacy_epoch_gaugemeister(
+        &ctx.accounts.gaugemeister,
+        &ctx.accounts.epoch_gaugemeister,
+        epoch_gauge_voter.voting_epoch,
+    )? {
+        unwrap_int!(epoch_gaugemeister.count_legacy_epoch_gauge_voter(&epoch_gauge_voter));
+        epoch_gaugemeister.exit(&crate::ID)?;
+    }
+
+    let gauge_voter = &mut ctx.accounts.gauge_voter;
+    gauge_voter.open_epoch_accounts = unwrap_int!(gauge_voter.open_epoch_accounts.checked_add(1));
+
+    emit!(MigrateEpochGaugeVoterEvent {
+        gaugemeister: ctx.accounts.gaugemeister.key(),
+        gauge_voter: gauge_voter.key(),
+        voting_epoch: epoch_gauge_voter.voting_epoch,
+        rent_payer: epoch_gauge_voter.rent_payer,
+        payer: ctx.accounts.payer.key(),
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for MigrateEpochGaugeVoter<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::migrate_epoch_gauge_voter].
+#[event]
+pub struct MigrateEpochGaugeVoterEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    #[index]
+    /// The [GaugeVoter].
+    pub gauge_voter: Pubkey,
+    /// The epoch of the [EpochGaugeVoter].
+    pub voting_epoch: u32,
+    /// The [EpochGaugeVoter::rent_payer] assigned to the account.
+    pub rent_payer: Pubkey,
+    /// Payer of the additional rent.
+    pub payer: Pubkey,
+}
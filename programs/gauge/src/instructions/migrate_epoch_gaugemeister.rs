@@ -0,0 +1,65 @@
+//! Migrates an [EpochGaugemeister] created with the legacy layout.
+
+use crate::*;
+
+/// Accounts for [gauge::migrate_epoch_gaugemeister].
+#[derive(Accounts)]
+pub struct MigrateEpochGaugemeister<'info> {
+    /// The [Gaugemeister].
+    pub gaugemeister: Account<'info, Gaugemeister>,
+
+    /// The legacy [EpochGaugemeister] to migrate.
+    /// CHECK: validated by owner, discriminator and length in the handler.
+    #[account(mut)]
+    pub epoch_gaugemeister: UncheckedAccount<'info>,
+
+    /// Payer of the rent of the extended account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateEpochGaugemeister>) -> Result<()> {
+    let info = ctx.accounts.epoch_gaugemeister.to_account_info();
+    utils::migrate_legacy_account::<EpochGaugemeister>(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        EpochGaugemeister::LEGACY_LEN,
+        EpochGaugemeister::LEN,
+    )?;
+
+    // the budgets when the legacy account was created are unknown, so the current ones are used.
+    let mut epoch_gaugemeister: Account<EpochGaugemeister> = Account::try_from(&info)?;
+    assert_keys_eq!(epoch_gaugemeister.gaugemeister, ctx.accounts.gaugemeister);
+    epoch_gaugemeister.group_budget_weights = ctx.accounts.gaugemeister.group_budget_weights;
+    epoch_gaugemeister.exit(&crate::ID)?;
+
+    emit!(MigrateEpochGaugemeisterEvent {
+        gaugemeister: ctx.accounts.gaugemeister.key(),
+        voting_epoch: epoch_gaugemeister.voting_epoch,
+        payer: ctx.accounts.payer.key(),
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for MigrateEpochGaugemeister<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::migrate_epoch_gaugemeister].
+#[event]
+pub struct MigrateEpochGaugemeisterEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    /// The epoch of the [EpochGaugemeister].
+    pub voting_epoch: u32,
+    /// Payer of the additional rent.
+    pub payer: Pubkey,
+}
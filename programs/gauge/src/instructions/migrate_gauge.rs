@@ -0,0 +1,59 @@
+//! Migrates a [Gauge] created with the legacy layout.
+
+use crate::*;
+
+/// Accounts for [gauge::migrate_gauge].
+#[derive(Accounts)]
+pub struct MigrateGauge<'info> {
+    /// The legacy [Gauge] to migrate.
+    /// CHECK: validated by owner, discriminator and length in the handler.
+    #[account(mut)]
+    pub gauge: UncheckedAccount<'info>,
+
+    /// Payer of the rent of the extended account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateGauge>) -> Result<()> {
+    let info = ctx.accounts.gauge.to_account_info();
+    // the appended fields default to zero: no cap, no floor, group 0 and always active.
+    utils::migrate_legacy_account::<Gauge>(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        Gauge::LEGACY_LEN,
+        Gauge::LEN,
+    )?;
+
+    let gauge: Account<Gauge> = Account::try_from(&info)?;
+    emit!(MigrateGaugeEvent {
+        gaugemeister: gauge.gaugemeister,
+        gauge: info.key(),
+        payer: ctx.accounts.payer.key(),
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for MigrateGauge<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::migrate_gauge].
+#[event]
+pub struct MigrateGaugeEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    #[index]
+    /// The [Gauge].
+    pub gauge: Pubkey,
+    /// Payer of the additional rent.
+    pub payer: Pubkey,
+}
@@ -0,0 +1,62 @@
+//! Migrates a [GaugeVote] created with the legacy layout.
+
+use crate::*;
+
+/// Accounts for [gauge::migrate_gauge_vote].
+#[derive(Accounts)]
+pub struct MigrateGaugeVote<'info> {
+    /// The legacy [GaugeVote] to migrate.
+    /// CHECK: validated by owner, discriminator and length in the handler.
+    #[account(mut)]
+    pub gauge_vote: UncheckedAccount<'info>,
+
+    /// Payer of the rent of the extended account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateGaugeVote>) -> Result<()> {
+    let info = ctx.accounts.gauge_vote.to_account_info();
+    // [GaugeVote::open_epoch_votes] is counted up as legacy epoch votes are migrated.
+    utils::migrate_legacy_account::<GaugeVote>(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        GaugeVote::LEGACY_LEN,
+        GaugeVote::LEN,
+    )?;
+
+    let gauge_vote: Account<GaugeVote> = Account::try_from(&info)?;
+    emit!(MigrateGaugeVoteEvent {
+        gauge_voter: gauge_vote.gauge_voter,
+        gauge: gauge_vote.gauge,
+        gauge_vote: info.key(),
+        payer: ctx.accounts.payer.key(),
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for MigrateGaugeVote<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::migrate_gauge_vote].
+#[event]
+pub struct MigrateGaugeVoteEvent {
+    #[index]
+    /// The [GaugeVoter].
+    pub gauge_voter: Pubkey,
+    #[index]
+    /// The [Gauge].
+    pub gauge: Pubkey,
+    /// The [GaugeVote].
+    pub gauge_vote: Pubkey,
+    /// Payer of the additional rent.
+    pub payer: Pubkey,
+}
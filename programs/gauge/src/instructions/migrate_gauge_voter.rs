@@ -0,0 +1,60 @@
+//! Migrates a [GaugeVoter] created with the legacy layout.
+
+use crate::*;
+
+/// Accounts for [gauge::migrate_gauge_voter].
+#[derive(Accounts)]
+pub struct MigrateGaugeVoter<'info> {
+    /// The legacy [GaugeVoter] to migrate.
+    /// CHECK: validated by owner, discriminator and length in the handler.
+    #[account(mut)]
+    pub gauge_voter: UncheckedAccount<'info>,
+
+    /// Payer of the rent of the extended account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateGaugeVoter>) -> Result<()> {
+    let info = ctx.accounts.gauge_voter.to_account_info();
+    // the appended fields default to zero: votes are not persistent and there is no delegate.
+    // [GaugeVoter::open_epoch_accounts] is counted up as legacy epoch accounts are migrated.
+    utils::migrate_legacy_account::<GaugeVoter>(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        GaugeVoter::LEGACY_LEN,
+        GaugeVoter::LEN,
+    )?;
+
+    let gauge_voter: Account<GaugeVoter> = Account::try_from(&info)?;
+    emit!(MigrateGaugeVoterEvent {
+        gaugemeister: gauge_voter.gaugemeister,
+        gauge_voter: info.key(),
+        payer: ctx.accounts.payer.key(),
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for MigrateGaugeVoter<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::migrate_gauge_voter].
+#[event]
+pub struct MigrateGaugeVoterEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    #[index]
+    /// The [GaugeVoter].
+    pub gauge_voter: Pubkey,
+    /// Payer of the additional rent.
+    pub payer: Pubkey,
+}
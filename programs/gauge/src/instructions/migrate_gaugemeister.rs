@@ -0,0 +1,59 @@
+//! Migrates a [Gaugemeister] created with the legacy layout.
+
+use crate::*;
+
+/// Accounts for [gauge::migrate_gaugemeister].
+#[derive(Accounts)]
+pub struct MigrateGaugemeister<'info> {
+    /// The legacy [Gaugemeister] to migrate.
+    /// CHECK: validated by owner, discriminator and length in the handler.
+    #[account(mut)]
+    pub gaugemeister: UncheckedAccount<'info>,
+
+    /// Payer of the rent of the extended account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateGaugemeister>) -> Result<()> {
+    let info = ctx.accounts.gaugemeister.to_account_info();
+    utils::migrate_legacy_account::<Gaugemeister>(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        Gaugemeister::LEGACY_LEN,
+        Gaugemeister::LEN,
+    )?;
+
+    // the curator and guardian default to the foreman, who held both roles before.
+    let mut gaugemeister: Account<Gaugemeister> = Account::try_from(&info)?;
+    gaugemeister.curator = gaugemeister.foreman;
+    gaugemeister.guardian = gaugemeister.foreman;
+    gaugemeister.exit(&crate::ID)?;
+
+    emit!(MigrateGaugemeisterEvent {
+        gaugemeister: info.key(),
+        payer: ctx.accounts.payer.key(),
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for MigrateGaugemeister<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::migrate_gaugemeister].
+#[event]
+pub struct MigrateGaugemeisterEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    /// Payer of the additional rent.
+    pub payer: Pubkey,
+}
@@ -0,0 +1,59 @@
+//! Migrates a [TipVault] created with the legacy layout.
+
+use crate::*;
+
+/// Accounts for [gauge::migrate_tip_vault].
+#[derive(Accounts)]
+pub struct MigrateTipVault<'info> {
+    /// The legacy [TipVault] to migrate.
+    /// CHECK: validated by owner, discriminator and length in the handler.
+    #[account(mut)]
+    pub tip_vault: UncheckedAccount<'info>,
+
+    /// Payer of the rent of the extended account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateTipVault>) -> Result<()> {
+    let info = ctx.accounts.tip_vault.to_account_info();
+    utils::migrate_legacy_account::<TipVault>(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        TipVault::LEGACY_LEN,
+        TipVault::LEN,
+    )?;
+
+    // no vote commits were tipped before, so the appended counter starts at zero.
+    let tip_vault: Account<TipVault> = Account::try_from(&info)?;
+
+    emit!(MigrateTipVaultEvent {
+        gaugemeister: tip_vault.gaugemeister,
+        tip_vault: info.key(),
+        payer: ctx.accounts.payer.key(),
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for MigrateTipVault<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::migrate_tip_vault].
+#[event]
+pub struct MigrateTipVaultEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    /// The [TipVault].
+    pub tip_vault: Pubkey,
+    /// Payer of the additional rent.
+    pub payer: Pubkey,
+}
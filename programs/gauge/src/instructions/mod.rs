@@ -1,37 +1,145 @@
 //! Instructions for the Quarry Gauge system.
 
+pub mod accept_gaugemeister_params;
+pub mod cancel_gaugemeister_params;
+pub mod cap_epoch_gauge;
+pub mod claim_incentive;
+pub mod close_epoch_gauge;
+pub mod close_epoch_gauge_allocation;
 pub mod close_epoch_gauge_vote;
+pub mod close_epoch_gauge_voter;
+pub mod close_epoch_gaugemeister;
+pub mod close_gauge_vote;
+pub mod close_gauge_voter;
+pub mod close_incentive_claim;
 pub mod create_epoch_gauge;
+pub mod create_epoch_gauge_allocation;
+pub mod create_epoch_gauge_incentive;
+pub mod create_epoch_gaugemeister;
 pub mod create_gauge;
 pub mod create_gauge_vote;
 pub mod create_gauge_voter;
 pub mod create_gaugemeister;
+pub mod create_tip_vault;
+pub mod deposit_incentive;
+pub mod drop_epoch_gauge;
 pub mod gauge_commit_vote;
+pub mod gauge_commit_votes;
 pub mod gauge_disable;
 pub mod gauge_enable;
 pub mod gauge_revert_vote;
 pub mod gauge_set_vote;
+pub mod gauge_set_votes;
+pub mod migrate_epoch_gauge;
+pub mod migrate_epoch_gauge_vote;
+pub mod migrate_epoch_gauge_voter;
+pub mod migrate_epoch_gaugemeister;
+pub mod migrate_gauge;
+pub mod migrate_gauge_vote;
+pub mod migrate_gauge_voter;
+pub mod migrate_gaugemeister;
+pub mod migrate_tip_vault;
+pub mod pause_gaugemeister;
 pub mod prepare_epoch_gauge_voter;
+pub mod reclaim_incentive;
+pub mod refresh_epoch_gauge_voter;
+pub mod release_gauge_vote;
 pub mod reset_epoch_gauge_voter;
+pub mod roll_forward_votes;
+pub mod set_curator;
+pub mod set_epoch_gauge_allocation;
+pub mod set_fixed_epoch_schedule;
+pub mod set_foreman_slice_bps;
+pub mod set_gauge_delegate;
+pub mod set_gauge_floor_bps;
+pub mod set_gauge_group;
+pub mod set_gauge_group_budget;
+pub mod set_gauge_max_share_bps;
+pub mod set_gauge_schedule;
 pub mod set_gaugemeister_params;
+pub mod set_guardian;
+pub mod set_max_gauge_share_bps;
+pub mod set_min_gauge_power;
+pub mod set_normalize_rewards_shares;
+pub mod set_persistent_votes;
+pub mod set_tip_params;
+pub mod sunset_gauge;
 pub mod sync_disabled_gauge;
 pub mod sync_gauge;
+pub mod sync_gauges;
+pub mod sync_unvoted_gauge;
 pub mod trigger_next_epoch;
+pub mod unpause_gaugemeister;
+pub mod withdraw_tips;
 
+pub use accept_gaugemeister_params::*;
+pub use cancel_gaugemeister_params::*;
+pub use cap_epoch_gauge::*;
+pub use claim_incentive::*;
+pub use close_epoch_gauge::*;
+pub use close_epoch_gauge_allocation::*;
 pub use close_epoch_gauge_vote::*;
+pub use close_epoch_gauge_voter::*;
+pub use close_epoch_gaugemeister::*;
+pub use close_gauge_vote::*;
+pub use close_gauge_voter::*;
+pub use close_incentive_claim::*;
 pub use create_epoch_gauge::*;
+pub use create_epoch_gauge_allocation::*;
+pub use create_epoch_gauge_incentive::*;
+pub use create_epoch_gaugemeister::*;
 pub use create_gauge::*;
 pub use create_gauge_vote::*;
 pub use create_gauge_voter::*;
 pub use create_gaugemeister::*;
+pub use create_tip_vault::*;
+pub use deposit_incentive::*;
+pub use drop_epoch_gauge::*;
 pub use gauge_commit_vote::*;
+pub use gauge_commit_votes::*;
 pub use gauge_disable::*;
 pub use gauge_enable::*;
 pub use gauge_revert_vote::*;
 pub use gauge_set_vote::*;
+pub use gauge_set_votes::*;
+pub use migrate_epoch_gauge::*;
+pub use migrate_epoch_gauge_vote::*;
+pub use migrate_epoch_gauge_voter::*;
+pub use migrate_epoch_gaugemeister::*;
+pub use migrate_gauge::*;
+pub use migrate_gauge_vote::*;
+pub use migrate_gauge_voter::*;
+pub use migrate_gaugemeister::*;
+pub use migrate_tip_vault::*;
+pub use pause_gaugemeister::*;
 pub use prepare_epoch_gauge_voter::*;
+pub use reclaim_incentive::*;
+pub use refresh_epoch_gauge_voter::*;
+pub use release_gauge_vote::*;
 pub use reset_epoch_gauge_voter::*;
+pub use roll_forward_votes::*;
+pub use set_curator::*;
+pub use set_epoch_gauge_allocation::*;
+pub use set_fixed_epoch_schedule::*;
+pub use set_foreman_slice_bps::*;
+pub use set_gauge_delegate::*;
+pub use set_gauge_floor_bps::*;
+pub use set_gauge_group::*;
+pub use set_gauge_group_budget::*;
+pub use set_gauge_max_share_bps::*;
+pub use set_gauge_schedule::*;
 pub use set_gaugemeister_params::*;
+pub use set_guardian::*;
+pub use set_max_gauge_share_bps::*;
+pub use set_min_gauge_power::*;
+pub use set_normalize_rewards_shares::*;
+pub use set_persistent_votes::*;
+pub use set_tip_params::*;
+pub use sunset_gauge::*;
 pub use sync_disabled_gauge::*;
 pub use sync_gauge::*;
+pub use sync_gauges::*;
+pub use sync_unvoted_gauge::*;
 pub use trigger_next_epoch::*;
+pub use unpause_gaugemeister::*;
+pub use withdraw_tips::*;
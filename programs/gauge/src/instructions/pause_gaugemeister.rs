@@ -0,0 +1,46 @@
+//! Pauses a [Gaugemeister].
+
+use crate::*;
+
+/// Accounts for [gauge::pause_gaugemeister].
+#[derive(Accounts)]
+pub struct PauseGaugemeister<'info> {
+    /// The [Gaugemeister] to pause.
+    #[account(mut)]
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [Gaugemeister::guardian].
+    pub guardian: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<PauseGaugemeister>) -> Result<()> {
+    let gaugemeister = &mut ctx.accounts.gaugemeister;
+    gaugemeister.is_paused = true;
+
+    emit!(PauseGaugemeisterEvent {
+        gaugemeister: gaugemeister.key(),
+        guardian: ctx.accounts.guardian.key(),
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for PauseGaugemeister<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.guardian,
+            self.gaugemeister.guardian,
+            UnauthorizedNotGuardian
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::pause_gaugemeister].
+#[event]
+pub struct PauseGaugemeisterEvent {
+    /// The [Gaugemeister].
+    #[index]
+    pub gaugemeister: Pubkey,
+    /// The [Gaugemeister::guardian] that paused the [Gaugemeister].
+    pub guardian: Pubkey,
+}
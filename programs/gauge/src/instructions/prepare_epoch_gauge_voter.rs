@@ -13,7 +13,7 @@ pub struct PrepareEpochGaugeVoter<'info> {
     pub escrow: Account<'info, locked_voter::Escrow>,
 
     /// [GaugeVoter].
-    #[account(has_one = gaugemeister, has_one = escrow)]
+    #[account(mut, has_one = gaugemeister, has_one = escrow)]
     pub gauge_voter: Account<'info, GaugeVoter>,
 
     /// The [EpochGaugeVoter].
@@ -58,6 +58,10 @@ pub fn handler(ctx: Context<PrepareEpochGaugeVoter>) -> Result<()> {
     epoch_gauge_voter.weight_change_seqno = ctx.accounts.gauge_voter.weight_change_seqno;
     epoch_gauge_voter.voting_power = voting_power;
     epoch_gauge_voter.allocated_power = 0;
+    epoch_gauge_voter.rent_payer = ctx.accounts.payer.key();
+
+    let gauge_voter = &mut ctx.accounts.gauge_voter;
+    gauge_voter.open_epoch_accounts = unwrap_int!(gauge_voter.open_epoch_accounts.checked_add(1));
 
     emit!(PrepareEpochGaugeVoterEvent {
         gaugemeister: ctx.accounts.gaugemeister.key(),
@@ -0,0 +1,110 @@
+//! Returns the unclaimed tokens of an [EpochGaugeIncentive] to its depositor.
+
+use anchor_spl::token::{self, Token, TokenAccount};
+
+use crate::*;
+
+/// Accounts for [gauge::reclaim_incentive].
+#[derive(Accounts)]
+pub struct ReclaimIncentive<'info> {
+    /// The [Gaugemeister].
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [Gauge].
+    #[account(has_one = gaugemeister)]
+    pub gauge: Account<'info, Gauge>,
+
+    /// The [EpochGaugeIncentive] to close.
+    /// Lamports go to the [EpochGaugeIncentive::depositor].
+    #[account(
+        mut,
+        has_one = gauge,
+        has_one = depositor,
+        has_one = token_account,
+        close = depositor
+    )]
+    pub epoch_gauge_incentive: Account<'info, EpochGaugeIncentive>,
+    /// The [EpochGaugeIncentive::token_account], which is closed.
+    #[account(mut)]
+    pub token_account: Account<'info, TokenAccount>,
+
+    /// The [EpochGaugeIncentive::depositor].
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    /// The token account receiving the unclaimed tokens.
+    #[account(mut)]
+    pub destination: Account<'info, TokenAccount>,
+
+    /// The SPL token program.
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<ReclaimIncentive>) -> Result<()> {
+    let incentive = &ctx.accounts.epoch_gauge_incentive;
+    let amount = ctx.accounts.token_account.amount;
+    let seeds: &[&[&[u8]]] = epoch_gauge_incentive_seeds!(incentive);
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.token_account.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: incentive.to_account_info(),
+            },
+        )
+        .with_signer(seeds),
+        amount,
+    )?;
+    token::close_account(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::CloseAccount {
+                account: ctx.accounts.token_account.to_account_info(),
+                destination: ctx.accounts.depositor.to_account_info(),
+                authority: incentive.to_account_info(),
+            },
+        )
+        .with_signer(seeds),
+    )?;
+
+    emit!(ReclaimIncentiveEvent {
+        gaugemeister: ctx.accounts.gaugemeister.key(),
+        gauge: incentive.gauge,
+        depositor: incentive.depositor,
+        mint: incentive.mint,
+        voting_epoch: incentive.voting_epoch,
+        amount,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for ReclaimIncentive<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(
+            self.gaugemeister
+                .incentive_claims_ended(self.epoch_gauge_incentive.voting_epoch),
+            IncentiveClaimsOpen
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::reclaim_incentive].
+#[event]
+pub struct ReclaimIncentiveEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    #[index]
+    /// The [Gauge].
+    pub gauge: Pubkey,
+    #[index]
+    /// The depositor of the incentive.
+    pub depositor: Pubkey,
+    /// The mint of the incentive.
+    pub mint: Pubkey,
+    /// The voting epoch whose votes were rewarded.
+    pub voting_epoch: u32,
+    /// The unclaimed amount returned to the depositor.
+    pub amount: u64,
+}
@@ -0,0 +1,188 @@
+//! Refreshes the voting power of an [EpochGaugeVoter], recomputing its committed votes.
+
+use crate::*;
+use num_traits::ToPrimitive;
+
+/// Accounts for [gauge::refresh_epoch_gauge_voter].
+///
+/// The remaining accounts must be groups of four accounts, one for each committed [EpochGaugeVote]:
+/// 1. The [Gauge].
+/// 2. The [GaugeVote] of the [GaugeVoter] for the [Gauge].
+/// 3. The [EpochGauge] of the voting epoch, which must be writable.
+/// 4. The [EpochGaugeVote], which must be writable.
+///
+/// Groups must be sorted by [GaugeVote] address, and the power of all
+/// [EpochGaugeVote]s must add up to [EpochGaugeVoter::allocated_power].
+#[derive(Accounts)]
+pub struct RefreshEpochGaugeVoter<'info> {
+    /// The [Gaugemeister].
+    #[account(has_one = locker)]
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [Gaugemeister::locker].
+    pub locker: Account<'info, locked_voter::Locker>,
+    /// The [GaugeVoter::escrow].
+    #[account(has_one = locker)]
+    pub escrow: Account<'info, locked_voter::Escrow>,
+
+    /// The [EpochGaugeVoter::gauge_voter].
+    #[account(has_one = gaugemeister, has_one = escrow)]
+    pub gauge_voter: Account<'info, GaugeVoter>,
+    /// The [EpochGaugeVoter] to refresh.
+    #[account(mut, has_one = gauge_voter)]
+    pub epoch_gauge_voter: Account<'info, EpochGaugeVoter>,
+    /// The [EpochGaugemeister] of the voting epoch.
+    #[account(mut, has_one = gaugemeister)]
+    pub epoch_gaugemeister: Account<'info, EpochGaugemeister>,
+}
+
+/// Number of remaining accounts per [EpochGaugeVote] in [gauge::refresh_epoch_gauge_voter].
+const ACCOUNTS_PER_VOTE: usize = 4;
+
+impl<'info> RefreshEpochGaugeVoter<'info> {
+    /// Calculates the voting power.
+    fn power(&self) -> Option<u64> {
+        self.escrow.voting_power_at_time(
+            &self.locker.params,
+            self.gaugemeister.next_epoch_starts_at.to_i64()?,
+        )
+    }
+
+    /// Recomputes a single [EpochGaugeVote] from `voting_power` and its
+    /// [GaugeVote::weight], returning its previous power.
+    fn refresh_vote(&mut self, accounts: &[AccountInfo<'info>], voting_power: u64) -> Result<u64> {
+        let gauge: Account<Gauge> = Account::try_from(&accounts[0])?;
+        let gauge_vote: Account<GaugeVote> = Account::try_from(&accounts[1])?;
+        let mut epoch_gauge: Account<EpochGauge> = Account::try_from(&accounts[2])?;
+        let mut epoch_gauge_vote: Account<EpochGaugeVote> = Account::try_from(&accounts[3])?;
+        assert_keys_eq!(self.gaugemeister, gauge.gaugemeister);
+        assert_keys_eq!(gauge, gauge_vote.gauge);
+        assert_keys_eq!(self.gauge_voter, gauge_vote.gauge_voter);
+        assert_keys_eq!(epoch_gauge.gauge, gauge);
+
+        let voting_epoch = self.epoch_gauge_voter.voting_epoch;
+        invariant!(
+            epoch_gauge.voting_epoch == voting_epoch,
+            EpochGaugeNotVoting
+        );
+        let (epoch_gauge_vote_key, _) =
+            EpochGaugeVote::find_program_address(&gauge_vote.key(), voting_epoch);
+        assert_keys_eq!(epoch_gauge_vote_key, epoch_gauge_vote);
+
+        let prev_power = epoch_gauge_vote.allocated_power;
+        let next_power = unwrap_int!(self
+            .gauge_voter
+            .power_for_weight(voting_power, gauge_vote.weight));
+        if next_power > prev_power {
+            invariant!(!gauge.is_disabled, CannotCommitGaugeDisabled);
+            invariant!(gauge.is_active_in(voting_epoch), GaugeNotActive);
+        }
+
+        let epoch_voter: &mut EpochGaugeVoter = &mut self.epoch_gauge_voter;
+        unwrap_int!(self.epoch_gaugemeister.revert_power(
+            &mut epoch_gauge,
+            epoch_voter,
+            prev_power
+        ));
+        unwrap_int!(self.epoch_gaugemeister.commit_power(
+            &mut epoch_gauge,
+            epoch_voter,
+            next_power
+        ));
+        epoch_gauge_vote.allocated_power = next_power;
+
+        epoch_gauge.exit(&crate::ID)?;
+        epoch_gauge_vote.exit(&crate::ID)?;
+        Ok(prev_power)
+    }
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, RefreshEpochGaugeVoter<'info>>,
+) -> Result<()> {
+    let voting_power = unwrap_int!(ctx.accounts.power());
+    let votes_accounts = ctx.remaining_accounts.chunks_exact(ACCOUNTS_PER_VOTE);
+    invariant!(
+        votes_accounts.remainder().is_empty(),
+        InvalidRemainingAccounts
+    );
+
+    let prev_voting_power = ctx.accounts.epoch_gauge_voter.voting_power;
+    let prev_allocated_power = ctx.accounts.epoch_gauge_voter.allocated_power;
+
+    let mut refreshed_power: u64 = 0;
+    let mut prev_gauge_vote = Pubkey::default();
+    for accounts in votes_accounts {
+        // Sorting prevents the same vote from being refreshed twice.
+        let gauge_vote = accounts[1].key();
+        invariant!(prev_gauge_vote < gauge_vote, GaugeVotesNotSorted);
+        prev_gauge_vote = gauge_vote;
+
+        let prev_power = ctx.accounts.refresh_vote(accounts, voting_power)?;
+        refreshed_power = unwrap_int!(refreshed_power.checked_add(prev_power));
+    }
+    // Every committed vote must be recomputed, otherwise the voter could exceed its power.
+    invariant!(
+        refreshed_power == prev_allocated_power,
+        GaugeVotesIncomplete
+    );
+
+    let epoch_gauge_voter = &mut ctx.accounts.epoch_gauge_voter;
+    epoch_gauge_voter.voting_power = voting_power;
+
+    emit!(RefreshEpochGaugeVoterEvent {
+        gaugemeister: ctx.accounts.gaugemeister.key(),
+        gauge_voter_owner: ctx.accounts.gauge_voter.owner,
+        voting_epoch: epoch_gauge_voter.voting_epoch,
+        prev_voting_power,
+        voting_power,
+        prev_allocated_power,
+        allocated_power: epoch_gauge_voter.allocated_power,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for RefreshEpochGaugeVoter<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(!self.gaugemeister.is_paused, GaugemeisterPaused);
+        assert_keys_eq!(self.escrow.owner, self.gauge_voter.owner);
+        // Votes may only be recomputed from the weights they were committed with.
+        invariant!(
+            self.epoch_gauge_voter.weight_change_seqno == self.gauge_voter.weight_change_seqno,
+            WeightSeqnoChanged
+        );
+
+        let voting_epoch = self.gaugemeister.voting_epoch()?;
+        invariant!(
+            self.epoch_gauge_voter.voting_epoch == voting_epoch,
+            EpochClosed
+        );
+        invariant!(
+            self.epoch_gaugemeister.voting_epoch == voting_epoch,
+            EpochGaugeNotVoting
+        );
+
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::refresh_epoch_gauge_voter].
+#[event]
+pub struct RefreshEpochGaugeVoterEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    #[index]
+    /// Owner of the Escrow of the [GaugeVoter].
+    pub gauge_voter_owner: Pubkey,
+    /// The epoch that the [GaugeVoter] is voting for.
+    pub voting_epoch: u32,
+    /// The previous [EpochGaugeVoter::voting_power].
+    pub prev_voting_power: u64,
+    /// The new [EpochGaugeVoter::voting_power].
+    pub voting_power: u64,
+    /// The previous [EpochGaugeVoter::allocated_power].
+    pub prev_allocated_power: u64,
+    /// The new [EpochGaugeVoter::allocated_power].
+    pub allocated_power: u64,
+}
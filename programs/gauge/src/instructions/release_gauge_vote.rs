@@ -0,0 +1,130 @@
+//! Releases the power committed to a disabled [Gauge] back to the [EpochGaugeVoter].
+
+use crate::*;
+
+/// Accounts for [gauge::release_gauge_vote].
+#[derive(Accounts)]
+pub struct ReleaseGaugeVote<'info> {
+    /// The [Gaugemeister].
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The disabled [Gauge].
+    #[account(has_one = gaugemeister)]
+    pub gauge: Account<'info, Gauge>,
+    /// The [GaugeVoter].
+    #[account(mut)]
+    pub gauge_voter: Account<'info, GaugeVoter>,
+    /// The [GaugeVote].
+    #[account(mut)]
+    pub gauge_vote: Account<'info, GaugeVote>,
+
+    /// The [EpochGauge] of the current voting epoch.
+    #[account(mut)]
+    pub epoch_gauge: Account<'info, EpochGauge>,
+    /// The [EpochGaugeVoter] of the current voting epoch.
+    #[account(mut)]
+    pub epoch_gauge_voter: Account<'info, EpochGaugeVoter>,
+    /// The [EpochGaugemeister] of the current voting epoch.
+    #[account(mut)]
+    pub epoch_gaugemeister: Account<'info, EpochGaugemeister>,
+
+    /// The [EpochGaugeVote] to release.
+    #[account(
+        mut,
+        has_one = rent_payer,
+        close = rent_payer,
+    )]
+    pub epoch_gauge_vote: Account<'info, EpochGaugeVote>,
+
+    /// The account that paid the rent of the [EpochGaugeVote].
+    /// CHECK: validated by key against [EpochGaugeVote::rent_payer].
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<ReleaseGaugeVote>) -> Result<()> {
+    let epoch_gauge = &mut ctx.accounts.epoch_gauge;
+    let epoch_voter = &mut ctx.accounts.epoch_gauge_voter;
+    let epoch_vote = &mut ctx.accounts.epoch_gauge_vote;
+
+    let released_power = epoch_vote.allocated_power;
+    unwrap_int!(ctx.accounts.epoch_gaugemeister.revert_power(
+        epoch_gauge,
+        epoch_voter,
+        released_power
+    ));
+    unwrap_int!(ctx
+        .accounts
+        .gauge_vote
+        .close_epoch_vote(&mut ctx.accounts.gauge_voter));
+
+    emit!(ReleaseGaugeVoteEvent {
+        gaugemeister: ctx.accounts.gaugemeister.key(),
+        gauge: ctx.accounts.gauge.key(),
+        gauge_voter_owner: ctx.accounts.gauge_voter.owner,
+        voting_epoch: epoch_voter.voting_epoch,
+        released_power,
+        updated_allocated_power: epoch_voter.allocated_power,
+        updated_total_power: epoch_gauge.total_power,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for ReleaseGaugeVote<'info> {
+    fn validate(&self) -> Result<()> {
+        let voting_epoch = self.gaugemeister.voting_epoch()?;
+        invariant!(
+            self.gauge.is_disabled || !self.gauge.is_active_in(voting_epoch),
+            GaugeNotDisabled
+        );
+
+        invariant!(
+            self.epoch_gauge.voting_epoch == voting_epoch,
+            EpochGaugeNotVoting
+        );
+        invariant!(
+            self.epoch_gauge_voter.voting_epoch == voting_epoch,
+            EpochGaugeNotVoting
+        );
+        invariant!(
+            self.epoch_gaugemeister.voting_epoch == voting_epoch,
+            EpochGaugeNotVoting
+        );
+
+        assert_keys_eq!(self.epoch_gauge.gauge, self.gauge);
+        assert_keys_eq!(self.epoch_gauge_voter.gauge_voter, self.gauge_voter);
+        assert_keys_eq!(self.epoch_gaugemeister.gaugemeister, self.gaugemeister);
+
+        assert_keys_eq!(self.gauge_vote.gauge_voter, self.gauge_voter);
+        assert_keys_eq!(self.gauge_vote.gauge, self.gauge);
+
+        let (epoch_gauge_vote_key, _) =
+            EpochGaugeVote::find_program_address(&self.gauge_vote.key(), voting_epoch);
+        assert_keys_eq!(epoch_gauge_vote_key, self.epoch_gauge_vote);
+
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::release_gauge_vote].
+#[event]
+pub struct ReleaseGaugeVoteEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    #[index]
+    /// The disabled [Gauge].
+    pub gauge: Pubkey,
+    #[index]
+    /// Owner of the Escrow of the [GaugeVoter].
+    /// Frontends may use this to prompt the owner to recommit their power.
+    pub gauge_voter_owner: Pubkey,
+    /// The epoch that the [GaugeVoter] is voting for.
+    pub voting_epoch: u32,
+    /// Allocated power released back to the [EpochGaugeVoter].
+    pub released_power: u64,
+    /// The total amount of gauge voting power that remains allocated for the epoch voter.
+    pub updated_allocated_power: u64,
+    /// The total power that remains committed to the [EpochGauge].
+    pub updated_total_power: u64,
+}
@@ -60,6 +60,7 @@ pub fn handler(ctx: Context<ResetEpochGaugeVoter>) -> Result<()> {
 
 impl<'info> Validate<'info> for ResetEpochGaugeVoter<'info> {
     fn validate(&self) -> Result<()> {
+        invariant!(!self.gaugemeister.is_paused, GaugemeisterPaused);
         assert_keys_eq!(self.gaugemeister.locker, self.locker);
         assert_keys_eq!(self.escrow, self.gauge_voter.escrow);
         assert_keys_eq!(self.escrow.locker, self.locker);
@@ -0,0 +1,149 @@
+//! Prepares an [EpochGaugeVoter] for a persistent [GaugeVoter] and commits all of its votes.
+
+use crate::*;
+use num_traits::ToPrimitive;
+
+/// Accounts for [gauge::roll_forward_votes].
+///
+/// The remaining accounts are the same as those of [gauge::gauge_commit_votes].
+/// Votes for disabled or inactive [Gauge]s are skipped, emitting a [SkipGaugeVoteEvent].
+#[derive(Accounts)]
+pub struct RollForwardVotes<'info> {
+    /// The [Gaugemeister].
+    #[account(has_one = locker)]
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [locked_voter::Locker].
+    pub locker: Account<'info, locked_voter::Locker>,
+    /// The [locked_voter::Escrow] of the [GaugeVoter].
+    #[account(has_one = locker)]
+    pub escrow: Account<'info, locked_voter::Escrow>,
+
+    /// The persistent [GaugeVoter].
+    #[account(mut, has_one = gaugemeister, has_one = escrow)]
+    pub gauge_voter: Account<'info, GaugeVoter>,
+
+    /// The [EpochGaugeVoter] of the current voting epoch.
+    #[account(
+        init,
+        seeds = [
+            b"EpochGaugeVoter".as_ref(),
+            gauge_voter.key().as_ref(),
+            gaugemeister.voting_epoch()?.to_le_bytes().as_ref()
+        ],
+        bump,
+        space = 8 + EpochGaugeVoter::LEN,
+        payer = payer
+    )]
+    pub epoch_gauge_voter: Account<'info, EpochGaugeVoter>,
+
+    /// The [EpochGaugemeister] of the current voting epoch.
+    #[account(mut, has_one = gaugemeister)]
+    pub epoch_gaugemeister: Account<'info, EpochGaugemeister>,
+
+    /// Funder of the [EpochGaugeVoter] and [EpochGaugeVote]s.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RollForwardVotes<'info> {
+    /// Calculates the voting power.
+    fn power(&self) -> Option<u64> {
+        self.escrow.voting_power_at_time(
+            &self.locker.params,
+            self.gaugemeister.next_epoch_starts_at.to_i64()?,
+        )
+    }
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, RollForwardVotes<'info>>) -> Result<()> {
+    let voting_epoch = ctx.accounts.gaugemeister.voting_epoch()?;
+    let voting_power = unwrap_int!(ctx.accounts.power());
+
+    let accounts = ctx.accounts;
+    let epoch_gauge_voter = &mut accounts.epoch_gauge_voter;
+    epoch_gauge_voter.gauge_voter = accounts.gauge_voter.key();
+    epoch_gauge_voter.voting_epoch = voting_epoch;
+    epoch_gauge_voter.weight_change_seqno = accounts.gauge_voter.weight_change_seqno;
+    epoch_gauge_voter.voting_power = voting_power;
+    epoch_gauge_voter.allocated_power = 0;
+    epoch_gauge_voter.rent_payer = accounts.payer.key();
+
+    let gauge_voter = &mut accounts.gauge_voter;
+    gauge_voter.open_epoch_accounts = unwrap_int!(gauge_voter.open_epoch_accounts.checked_add(1));
+
+    VoteCommitter {
+        gaugemeister: accounts.gaugemeister.key(),
+        gauge_voter: &mut accounts.gauge_voter,
+        epoch_gauge_voter: &mut accounts.epoch_gauge_voter,
+        epoch_gaugemeister: &mut accounts.epoch_gaugemeister,
+        payer: accounts.payer.to_account_info(),
+        system_program: accounts.system_program.to_account_info(),
+        // Disabled gauges must not block the votes of a voter who is not around.
+        skip_inactive_gauges: true,
+    }
+    .commit_all(ctx.remaining_accounts)?;
+
+    emit!(RollForwardVotesEvent {
+        gaugemeister: accounts.gaugemeister.key(),
+        gauge_voter_owner: accounts.gauge_voter.owner,
+        voting_epoch,
+        voting_power,
+        allocated_power: accounts.epoch_gauge_voter.allocated_power,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for RollForwardVotes<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(!self.gaugemeister.is_paused, GaugemeisterPaused);
+        invariant!(self.gauge_voter.is_persistent, GaugeVoterNotPersistent);
+        assert_keys_eq!(self.escrow.owner, self.gauge_voter.owner);
+
+        let voting_epoch = self.gaugemeister.voting_epoch()?;
+        invariant!(
+            self.epoch_gaugemeister.voting_epoch == voting_epoch,
+            EpochGaugeNotVoting
+        );
+
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::roll_forward_votes].
+#[event]
+pub struct RollForwardVotesEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    #[index]
+    /// Owner of the Escrow of the [GaugeVoter].
+    pub gauge_voter_owner: Pubkey,
+    /// The epoch that the [GaugeVoter] is voting for.
+    pub voting_epoch: u32,
+    /// The total amount of voting power.
+    pub voting_power: u64,
+    /// The total amount of gauge voting power that has been committed.
+    pub allocated_power: u64,
+}
+
+/// Event called in [gauge::roll_forward_votes] for each skipped [GaugeVote].
+#[event]
+pub struct SkipGaugeVoteEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    #[index]
+    /// The [Gauge] which is disabled or inactive.
+    pub gauge: Pubkey,
+    #[index]
+    /// Owner of the Escrow of the [GaugeVoter].
+    pub gauge_voter_owner: Pubkey,
+    /// The epoch that the [GaugeVoter] is voting for.
+    pub voting_epoch: u32,
+    /// The [GaugeVote::weight] which was not committed.
+    pub weight: u32,
+}
@@ -0,0 +1,53 @@
+//! Sets the [Gaugemeister::curator].
+
+use crate::*;
+
+/// Accounts for [gauge::set_curator].
+#[derive(Accounts)]
+pub struct SetCurator<'info> {
+    /// The [Gaugemeister] to modify.
+    #[account(mut)]
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [Gaugemeister::foreman].
+    pub foreman: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetCurator>, new_curator: Pubkey) -> Result<()> {
+    let gaugemeister = &mut ctx.accounts.gaugemeister;
+    let prev_curator = gaugemeister.curator;
+    gaugemeister.curator = new_curator;
+
+    emit!(SetCuratorEvent {
+        gaugemeister: gaugemeister.key(),
+        foreman: ctx.accounts.foreman.key(),
+        prev_curator,
+        new_curator,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for SetCurator<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.foreman,
+            self.gaugemeister.foreman,
+            UnauthorizedNotForeman
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::set_curator].
+#[event]
+pub struct SetCuratorEvent {
+    /// The [Gaugemeister].
+    #[index]
+    pub gaugemeister: Pubkey,
+    /// The [Gaugemeister::foreman] that rotated the role.
+    pub foreman: Pubkey,
+    /// The previous [Gaugemeister::curator].
+    pub prev_curator: Pubkey,
+    /// The new [Gaugemeister::curator].
+    pub new_curator: Pubkey,
+}
@@ -0,0 +1,99 @@
+//! Sets the [EpochGaugeAllocation::weight] of a [Gauge] for the current voting epoch.
+
+use crate::*;
+
+/// Accounts for [gauge::set_epoch_gauge_allocation].
+#[derive(Accounts)]
+pub struct SetEpochGaugeAllocation<'info> {
+    /// The [Gaugemeister].
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [Gauge].
+    #[account(has_one = gaugemeister)]
+    pub gauge: Account<'info, Gauge>,
+
+    /// The [EpochGaugeAllocation] of the current voting epoch.
+    #[account(mut, has_one = gauge)]
+    pub epoch_gauge_allocation: Account<'info, EpochGaugeAllocation>,
+    /// The [EpochGaugemeister] of the current voting epoch.
+    #[account(mut, has_one = gaugemeister)]
+    pub epoch_gaugemeister: Account<'info, EpochGaugemeister>,
+
+    /// The [Gaugemeister::locker_governor].
+    pub locker_governor: Account<'info, govern::Governor>,
+
+    /// The [Gaugemeister::foreman] or the smart wallet of the [Gaugemeister::locker_governor].
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetEpochGaugeAllocation>, weight: u32) -> Result<()> {
+    invariant!(
+        weight == 0 || !ctx.accounts.gauge.is_disabled,
+        CannotVoteGaugeDisabled
+    );
+
+    let epoch_gauge_allocation = &mut ctx.accounts.epoch_gauge_allocation;
+    let epoch_gaugemeister = &mut ctx.accounts.epoch_gaugemeister;
+
+    let prev_weight = epoch_gauge_allocation.weight;
+    epoch_gaugemeister.total_allocation_weight = unwrap_int!(unwrap_int!(epoch_gaugemeister
+        .total_allocation_weight
+        .checked_sub(prev_weight.into()))
+    .checked_add(weight.into()));
+    epoch_gauge_allocation.weight = weight;
+
+    emit!(SetEpochGaugeAllocationEvent {
+        gaugemeister: ctx.accounts.gaugemeister.key(),
+        gauge: ctx.accounts.gauge.key(),
+        authority: ctx.accounts.authority.key(),
+        voting_epoch: epoch_gauge_allocation.voting_epoch,
+        prev_weight,
+        weight,
+        total_allocation_weight: epoch_gaugemeister.total_allocation_weight,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for SetEpochGaugeAllocation<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(!self.gaugemeister.is_paused, GaugemeisterPaused);
+        assert_keys_eq!(self.locker_governor, self.gaugemeister.locker_governor);
+        invariant!(
+            self.authority.key() == self.gaugemeister.foreman
+                || self.authority.key() == self.locker_governor.smart_wallet,
+            UnauthorizedNotForemanOrGovernor
+        );
+
+        let voting_epoch = self.gaugemeister.voting_epoch()?;
+        invariant!(
+            self.epoch_gauge_allocation.voting_epoch == voting_epoch,
+            EpochGaugeNotVoting
+        );
+        invariant!(
+            self.epoch_gaugemeister.voting_epoch == voting_epoch,
+            EpochGaugeNotVoting
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::set_epoch_gauge_allocation].
+#[event]
+pub struct SetEpochGaugeAllocationEvent {
+    /// The [Gaugemeister].
+    #[index]
+    pub gaugemeister: Pubkey,
+    /// The [Gauge].
+    #[index]
+    pub gauge: Pubkey,
+    /// The foreman or governor smart wallet that changed the allocation.
+    pub authority: Pubkey,
+    /// The epoch of the [EpochGaugeAllocation].
+    pub voting_epoch: u32,
+    /// The previous [EpochGaugeAllocation::weight].
+    pub prev_weight: u32,
+    /// The new [EpochGaugeAllocation::weight].
+    pub weight: u32,
+    /// The new [EpochGaugemeister::total_allocation_weight].
+    pub total_allocation_weight: u64,
+}
@@ -0,0 +1,65 @@
+//! Proposes a new epoch scheduling mode for a [Gaugemeister].
+
+use num_traits::ToPrimitive;
+
+use crate::*;
+
+/// Accounts for [gauge::set_fixed_epoch_schedule].
+#[derive(Accounts)]
+pub struct SetFixedEpochSchedule<'info> {
+    /// The [Gaugemeister] to modify.
+    #[account(mut)]
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [Gaugemeister::foreman].
+    pub foreman: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetFixedEpochSchedule>, fixed_epoch_schedule: bool) -> Result<()> {
+    let now = unwrap_int!(Clock::get()?.unix_timestamp.to_u64());
+    let gaugemeister = &mut ctx.accounts.gaugemeister;
+    if !gaugemeister.has_pending_params() {
+        // Propose keeping all other parameters unchanged.
+        gaugemeister.pending_foreman = gaugemeister.foreman;
+        gaugemeister.pending_epoch_duration_seconds = gaugemeister.epoch_duration_seconds;
+        gaugemeister.pending_params_change_delay_seconds = gaugemeister.params_change_delay_seconds;
+    }
+    gaugemeister.pending_fixed_epoch_schedule = fixed_epoch_schedule;
+    // The delay restarts whenever the pending parameters change.
+    let executable_at =
+        unwrap_int!(now.checked_add(gaugemeister.params_change_delay_seconds.into()));
+    gaugemeister.pending_params_executable_at = executable_at;
+
+    emit!(SetFixedEpochScheduleEvent {
+        gaugemeister: gaugemeister.key(),
+        foreman: ctx.accounts.foreman.key(),
+        fixed_epoch_schedule,
+        executable_at,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for SetFixedEpochSchedule<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.foreman,
+            self.gaugemeister.foreman,
+            UnauthorizedNotForeman
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::set_fixed_epoch_schedule].
+#[event]
+pub struct SetFixedEpochScheduleEvent {
+    /// The [Gaugemeister].
+    #[index]
+    pub gaugemeister: Pubkey,
+    /// The [Gaugemeister::foreman] that proposed the schedule.
+    pub foreman: Pubkey,
+    /// The proposed [Gaugemeister::fixed_epoch_schedule].
+    pub fixed_epoch_schedule: bool,
+    /// When the proposed schedule may be accepted.
+    pub executable_at: u64,
+}
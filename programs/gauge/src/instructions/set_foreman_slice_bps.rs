@@ -0,0 +1,56 @@
+//! Sets the [Gaugemeister::foreman_slice_bps].
+
+use crate::*;
+
+/// Accounts for [gauge::set_foreman_slice_bps].
+#[derive(Accounts)]
+pub struct SetForemanSliceBps<'info> {
+    /// The [Gaugemeister] to modify.
+    #[account(mut)]
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [Gaugemeister::foreman].
+    pub foreman: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetForemanSliceBps>, foreman_slice_bps: u16) -> Result<()> {
+    let gaugemeister = &mut ctx.accounts.gaugemeister;
+    let reserved_bps = unwrap_int!(gaugemeister.total_floor_bps.checked_add(foreman_slice_bps));
+    invariant!(reserved_bps <= BPS_DENOMINATOR, InvalidBps);
+
+    let prev_foreman_slice_bps = gaugemeister.foreman_slice_bps;
+    gaugemeister.foreman_slice_bps = foreman_slice_bps;
+
+    emit!(SetForemanSliceBpsEvent {
+        gaugemeister: gaugemeister.key(),
+        foreman: ctx.accounts.foreman.key(),
+        prev_foreman_slice_bps,
+        foreman_slice_bps,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for SetForemanSliceBps<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.foreman,
+            self.gaugemeister.foreman,
+            UnauthorizedNotForeman
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::set_foreman_slice_bps].
+#[event]
+pub struct SetForemanSliceBpsEvent {
+    /// The [Gaugemeister].
+    #[index]
+    pub gaugemeister: Pubkey,
+    /// The [Gaugemeister::foreman] that changed the slice.
+    pub foreman: Pubkey,
+    /// The previous [Gaugemeister::foreman_slice_bps].
+    pub prev_foreman_slice_bps: u16,
+    /// The new [Gaugemeister::foreman_slice_bps].
+    pub foreman_slice_bps: u16,
+}
@@ -0,0 +1,62 @@
+//! Sets the [GaugeVoter::gauge_delegate].
+
+use crate::*;
+
+/// Accounts for [gauge::set_gauge_delegate].
+#[derive(Accounts)]
+pub struct SetGaugeDelegate<'info> {
+    /// The [GaugeVoter].
+    #[account(mut)]
+    pub gauge_voter: Account<'info, GaugeVoter>,
+    /// The [GaugeVoter::owner].
+    pub owner: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetGaugeDelegate>,
+    gauge_delegate: Pubkey,
+    expires_epoch: u32,
+) -> Result<()> {
+    let gauge_voter = &mut ctx.accounts.gauge_voter;
+    let prev_gauge_delegate = gauge_voter.gauge_delegate;
+    gauge_voter.gauge_delegate = gauge_delegate;
+    gauge_voter.gauge_delegate_expires_epoch = expires_epoch;
+
+    emit!(SetGaugeDelegateEvent {
+        gaugemeister: gauge_voter.gaugemeister,
+        gauge_voter_owner: gauge_voter.owner,
+        prev_gauge_delegate,
+        gauge_delegate,
+        expires_epoch,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for SetGaugeDelegate<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.owner,
+            self.gauge_voter.owner,
+            UnauthorizedNotGaugeVoterOwner
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::set_gauge_delegate].
+#[event]
+pub struct SetGaugeDelegateEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    #[index]
+    /// Owner of the Escrow of the [GaugeVoter].
+    pub gauge_voter_owner: Pubkey,
+    /// The previous [GaugeVoter::gauge_delegate].
+    pub prev_gauge_delegate: Pubkey,
+    /// The new [GaugeVoter::gauge_delegate].
+    pub gauge_delegate: Pubkey,
+    /// The new [GaugeVoter::gauge_delegate_expires_epoch].
+    pub expires_epoch: u32,
+}
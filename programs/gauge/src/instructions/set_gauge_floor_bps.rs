@@ -0,0 +1,78 @@
+//! Sets the [Gauge::floor_bps].
+
+use crate::*;
+
+/// Accounts for [gauge::set_gauge_floor_bps].
+#[derive(Accounts)]
+pub struct SetGaugeFloorBps<'info> {
+    /// The [Gaugemeister].
+    #[account(mut)]
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [Gauge] to modify.
+    #[account(mut, has_one = gaugemeister)]
+    pub gauge: Account<'info, Gauge>,
+    /// The [Gaugemeister::foreman].
+    pub foreman: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetGaugeFloorBps>, floor_bps: u16) -> Result<()> {
+    let gaugemeister = &mut ctx.accounts.gaugemeister;
+    let gauge = &mut ctx.accounts.gauge;
+
+    let prev_floor_bps = gauge.floor_bps;
+    // The floors of disabled gauges are only counted once they are enabled.
+    let total_floor_bps = if gauge.is_disabled {
+        gaugemeister.total_floor_bps
+    } else {
+        unwrap_int!(
+            unwrap_int!(gaugemeister.total_floor_bps.checked_sub(prev_floor_bps))
+                .checked_add(floor_bps)
+        )
+    };
+    let reserved_bps = unwrap_int!(total_floor_bps.checked_add(gaugemeister.foreman_slice_bps));
+    invariant!(reserved_bps <= BPS_DENOMINATOR, InvalidBps);
+
+    gaugemeister.total_floor_bps = total_floor_bps;
+    gauge.floor_bps = floor_bps;
+
+    emit!(SetGaugeFloorBpsEvent {
+        gaugemeister: gaugemeister.key(),
+        gauge: gauge.key(),
+        foreman: ctx.accounts.foreman.key(),
+        prev_floor_bps,
+        floor_bps,
+        total_floor_bps,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for SetGaugeFloorBps<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.foreman,
+            self.gaugemeister.foreman,
+            UnauthorizedNotForeman
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::set_gauge_floor_bps].
+#[event]
+pub struct SetGaugeFloorBpsEvent {
+    /// The [Gaugemeister].
+    #[index]
+    pub gaugemeister: Pubkey,
+    /// The [Gauge].
+    #[index]
+    pub gauge: Pubkey,
+    /// The [Gaugemeister::foreman] that changed the floor.
+    pub foreman: Pubkey,
+    /// The previous [Gauge::floor_bps].
+    pub prev_floor_bps: u16,
+    /// The new [Gauge::floor_bps].
+    pub floor_bps: u16,
+    /// The new [Gaugemeister::total_floor_bps].
+    pub total_floor_bps: u16,
+}
@@ -0,0 +1,61 @@
+//! Sets the [Gauge::group].
+
+use crate::*;
+
+/// Accounts for [gauge::set_gauge_group].
+#[derive(Accounts)]
+pub struct SetGaugeGroup<'info> {
+    /// The [Gaugemeister].
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [Gauge] to modify.
+    #[account(mut, has_one = gaugemeister)]
+    pub gauge: Account<'info, Gauge>,
+    /// The [Gaugemeister::foreman].
+    pub foreman: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetGaugeGroup>, group: u8) -> Result<()> {
+    invariant!(usize::from(group) < MAX_GAUGE_GROUPS, InvalidGaugeGroup);
+
+    let gauge = &mut ctx.accounts.gauge;
+    let prev_group = gauge.group;
+    gauge.group = group;
+
+    emit!(SetGaugeGroupEvent {
+        gaugemeister: gauge.gaugemeister,
+        gauge: gauge.key(),
+        foreman: ctx.accounts.foreman.key(),
+        prev_group,
+        group,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for SetGaugeGroup<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.foreman,
+            self.gaugemeister.foreman,
+            UnauthorizedNotForeman
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::set_gauge_group].
+#[event]
+pub struct SetGaugeGroupEvent {
+    /// The [Gaugemeister].
+    #[index]
+    pub gaugemeister: Pubkey,
+    /// The [Gauge].
+    #[index]
+    pub gauge: Pubkey,
+    /// The [Gaugemeister::foreman] that changed the group.
+    pub foreman: Pubkey,
+    /// The previous [Gauge::group].
+    pub prev_group: u8,
+    /// The new [Gauge::group].
+    pub group: u8,
+}
@@ -0,0 +1,60 @@
+//! Sets the budget weight of a [Gauge] group in [Gaugemeister::group_budget_weights].
+
+use crate::*;
+
+/// Accounts for [gauge::set_gauge_group_budget].
+#[derive(Accounts)]
+pub struct SetGaugeGroupBudget<'info> {
+    /// The [Gaugemeister] to modify.
+    #[account(mut)]
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [Gaugemeister::foreman].
+    pub foreman: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetGaugeGroupBudget>, group: u8, budget_weight: u32) -> Result<()> {
+    let gaugemeister = &mut ctx.accounts.gaugemeister;
+    let group_budget_weight = gaugemeister
+        .group_budget_weights
+        .get_mut(usize::from(group));
+    let group_budget_weight = unwrap_opt!(group_budget_weight, InvalidGaugeGroup);
+    let prev_budget_weight = *group_budget_weight;
+    *group_budget_weight = budget_weight;
+
+    emit!(SetGaugeGroupBudgetEvent {
+        gaugemeister: gaugemeister.key(),
+        foreman: ctx.accounts.foreman.key(),
+        group,
+        prev_budget_weight,
+        budget_weight,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for SetGaugeGroupBudget<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.foreman,
+            self.gaugemeister.foreman,
+            UnauthorizedNotForeman
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::set_gauge_group_budget].
+#[event]
+pub struct SetGaugeGroupBudgetEvent {
+    /// The [Gaugemeister].
+    #[index]
+    pub gaugemeister: Pubkey,
+    /// The [Gaugemeister::foreman] that changed the budget.
+    pub foreman: Pubkey,
+    /// The [Gauge::group].
+    pub group: u8,
+    /// The previous budget weight of the group.
+    pub prev_budget_weight: u32,
+    /// The new budget weight of the group.
+    pub budget_weight: u32,
+}
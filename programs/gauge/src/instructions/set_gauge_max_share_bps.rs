@@ -0,0 +1,61 @@
+//! Sets the [Gauge::max_share_bps].
+
+use crate::*;
+
+/// Accounts for [gauge::set_gauge_max_share_bps].
+#[derive(Accounts)]
+pub struct SetGaugeMaxShareBps<'info> {
+    /// The [Gaugemeister].
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [Gauge] to modify.
+    #[account(mut, has_one = gaugemeister)]
+    pub gauge: Account<'info, Gauge>,
+    /// The [Gaugemeister::foreman].
+    pub foreman: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetGaugeMaxShareBps>, max_share_bps: u16) -> Result<()> {
+    invariant!(max_share_bps <= BPS_DENOMINATOR, InvalidBps);
+
+    let gauge = &mut ctx.accounts.gauge;
+    let prev_max_share_bps = gauge.max_share_bps;
+    gauge.max_share_bps = max_share_bps;
+
+    emit!(SetGaugeMaxShareBpsEvent {
+        gaugemeister: gauge.gaugemeister,
+        gauge: gauge.key(),
+        foreman: ctx.accounts.foreman.key(),
+        prev_max_share_bps,
+        max_share_bps,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for SetGaugeMaxShareBps<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.foreman,
+            self.gaugemeister.foreman,
+            UnauthorizedNotForeman
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::set_gauge_max_share_bps].
+#[event]
+pub struct SetGaugeMaxShareBpsEvent {
+    /// The [Gaugemeister].
+    #[index]
+    pub gaugemeister: Pubkey,
+    /// The [Gauge].
+    #[index]
+    pub gauge: Pubkey,
+    /// The [Gaugemeister::foreman] that changed the cap.
+    pub foreman: Pubkey,
+    /// The previous [Gauge::max_share_bps].
+    pub prev_max_share_bps: u16,
+    /// The new [Gauge::max_share_bps].
+    pub max_share_bps: u16,
+}
@@ -0,0 +1,68 @@
+//! Sets the [Gauge::activation_epoch] and [Gauge::sunset_epoch].
+
+use crate::*;
+
+/// Accounts for [gauge::set_gauge_schedule].
+#[derive(Accounts)]
+pub struct SetGaugeSchedule<'info> {
+    /// The [Gaugemeister].
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [Gauge] to modify.
+    #[account(mut, has_one = gaugemeister)]
+    pub gauge: Account<'info, Gauge>,
+    /// The [Gaugemeister::curator].
+    pub curator: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetGaugeSchedule>,
+    activation_epoch: u32,
+    sunset_epoch: u32,
+) -> Result<()> {
+    invariant!(
+        sunset_epoch == 0 || sunset_epoch > activation_epoch,
+        InvalidGaugeSchedule
+    );
+
+    let gauge = &mut ctx.accounts.gauge;
+    gauge.activation_epoch = activation_epoch;
+    gauge.sunset_epoch = sunset_epoch;
+
+    emit!(SetGaugeScheduleEvent {
+        gaugemeister: gauge.gaugemeister,
+        gauge: gauge.key(),
+        curator: ctx.accounts.curator.key(),
+        activation_epoch,
+        sunset_epoch,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for SetGaugeSchedule<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.curator,
+            self.gaugemeister.curator,
+            UnauthorizedNotCurator
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::set_gauge_schedule].
+#[event]
+pub struct SetGaugeScheduleEvent {
+    /// The [Gaugemeister].
+    #[index]
+    pub gaugemeister: Pubkey,
+    /// The [Gauge].
+    #[index]
+    pub gauge: Pubkey,
+    /// The [Gaugemeister::curator] that changed the schedule.
+    pub curator: Pubkey,
+    /// The new [Gauge::activation_epoch].
+    pub activation_epoch: u32,
+    /// The new [Gauge::sunset_epoch].
+    pub sunset_epoch: u32,
+}
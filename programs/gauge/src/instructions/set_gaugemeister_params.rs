@@ -1,6 +1,10 @@
+//! Proposes new parameters for a [Gaugemeister].
+
+use num_traits::ToPrimitive;
+
 use crate::*;
 
-/// Accounts for [gauge::reset_epoch_gauge_voter].
+/// Accounts for [gauge::propose_gaugemeister_params].
 #[derive(Accounts)]
 pub struct SetGaugemeisterParams<'info> {
     #[account(mut)]
@@ -12,7 +16,11 @@ pub struct SetGaugemeisterParams<'info> {
 
 impl<'info> Validate<'info> for SetGaugemeisterParams<'info> {
     fn validate(&self) -> Result<()> {
-        assert_keys_eq!(self.foreman, self.gaugemeister.foreman);
+        assert_keys_eq!(
+            self.foreman,
+            self.gaugemeister.foreman,
+            UnauthorizedNotForeman
+        );
 
         Ok(())
     }
@@ -22,29 +30,48 @@ pub fn handler(
     ctx: Context<SetGaugemeisterParams>,
     new_epoch_duration_seconds: u32,
     new_foreman: Pubkey,
+    new_params_change_delay_seconds: u32,
 ) -> Result<()> {
+    let now = unwrap_int!(Clock::get()?.unix_timestamp.to_u64());
     let gaugemeister = &mut ctx.accounts.gaugemeister;
-    let prev_foreman = gaugemeister.foreman;
-    let prev_epoch_duration_seconds = gaugemeister.epoch_duration_seconds;
+    let executable_at =
+        unwrap_int!(now.checked_add(gaugemeister.params_change_delay_seconds.into()));
 
-    gaugemeister.foreman = new_foreman;
-    gaugemeister.epoch_duration_seconds = new_epoch_duration_seconds;
+    gaugemeister.pending_foreman = new_foreman;
+    gaugemeister.pending_epoch_duration_seconds = new_epoch_duration_seconds;
+    gaugemeister.pending_params_change_delay_seconds = new_params_change_delay_seconds;
+    if !gaugemeister.has_pending_params() {
+        // Keep any schedule change proposed by set_fixed_epoch_schedule.
+        gaugemeister.pending_fixed_epoch_schedule = gaugemeister.fixed_epoch_schedule;
+    }
+    gaugemeister.pending_params_executable_at = executable_at;
 
-    emit!(SetGaugemeisterParamsEvent {
-        prev_foreman,
-        new_foreman: gaugemeister.foreman,
-        prev_epoch_duration_seconds,
-        new_epoch_duration_seconds: gaugemeister.epoch_duration_seconds
+    emit!(ProposeGaugemeisterParamsEvent {
+        gaugemeister: gaugemeister.key(),
+        foreman: gaugemeister.foreman,
+        new_foreman,
+        new_epoch_duration_seconds,
+        new_params_change_delay_seconds,
+        executable_at,
     });
 
     Ok(())
 }
 
-/// Event called in [gauge::set_gaugemeister_params].
+/// Event called in [gauge::propose_gaugemeister_params].
 #[event]
-struct SetGaugemeisterParamsEvent {
-    prev_foreman: Pubkey,
-    new_foreman: Pubkey,
-    prev_epoch_duration_seconds: u32,
-    new_epoch_duration_seconds: u32,
+pub struct ProposeGaugemeisterParamsEvent {
+    /// The [Gaugemeister].
+    #[index]
+    pub gaugemeister: Pubkey,
+    /// The [Gaugemeister::foreman] that proposed the change.
+    pub foreman: Pubkey,
+    /// The proposed [Gaugemeister::foreman].
+    pub new_foreman: Pubkey,
+    /// The proposed [Gaugemeister::epoch_duration_seconds].
+    pub new_epoch_duration_seconds: u32,
+    /// The proposed [Gaugemeister::params_change_delay_seconds].
+    pub new_params_change_delay_seconds: u32,
+    /// When the proposed parameters may be accepted.
+    pub executable_at: u64,
 }
@@ -0,0 +1,53 @@
+//! Sets the [Gaugemeister::guardian].
+
+use crate::*;
+
+/// Accounts for [gauge::set_guardian].
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    /// The [Gaugemeister] to modify.
+    #[account(mut)]
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [Gaugemeister::foreman].
+    pub foreman: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetGuardian>, new_guardian: Pubkey) -> Result<()> {
+    let gaugemeister = &mut ctx.accounts.gaugemeister;
+    let prev_guardian = gaugemeister.guardian;
+    gaugemeister.guardian = new_guardian;
+
+    emit!(SetGuardianEvent {
+        gaugemeister: gaugemeister.key(),
+        foreman: ctx.accounts.foreman.key(),
+        prev_guardian,
+        new_guardian,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for SetGuardian<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.foreman,
+            self.gaugemeister.foreman,
+            UnauthorizedNotForeman
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::set_guardian].
+#[event]
+pub struct SetGuardianEvent {
+    /// The [Gaugemeister].
+    #[index]
+    pub gaugemeister: Pubkey,
+    /// The [Gaugemeister::foreman] that rotated the role.
+    pub foreman: Pubkey,
+    /// The previous [Gaugemeister::guardian].
+    pub prev_guardian: Pubkey,
+    /// The new [Gaugemeister::guardian].
+    pub new_guardian: Pubkey,
+}
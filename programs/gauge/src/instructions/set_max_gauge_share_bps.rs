@@ -0,0 +1,55 @@
+//! Sets the [Gaugemeister::max_gauge_share_bps].
+
+use crate::*;
+
+/// Accounts for [gauge::set_max_gauge_share_bps].
+#[derive(Accounts)]
+pub struct SetMaxGaugeShareBps<'info> {
+    /// The [Gaugemeister] to modify.
+    #[account(mut)]
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [Gaugemeister::foreman].
+    pub foreman: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetMaxGaugeShareBps>, max_gauge_share_bps: u16) -> Result<()> {
+    invariant!(max_gauge_share_bps <= BPS_DENOMINATOR, InvalidBps);
+
+    let gaugemeister = &mut ctx.accounts.gaugemeister;
+    let prev_max_gauge_share_bps = gaugemeister.max_gauge_share_bps;
+    gaugemeister.max_gauge_share_bps = max_gauge_share_bps;
+
+    emit!(SetMaxGaugeShareBpsEvent {
+        gaugemeister: gaugemeister.key(),
+        foreman: ctx.accounts.foreman.key(),
+        prev_max_gauge_share_bps,
+        max_gauge_share_bps,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for SetMaxGaugeShareBps<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.foreman,
+            self.gaugemeister.foreman,
+            UnauthorizedNotForeman
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::set_max_gauge_share_bps].
+#[event]
+pub struct SetMaxGaugeShareBpsEvent {
+    /// The [Gaugemeister].
+    #[index]
+    pub gaugemeister: Pubkey,
+    /// The [Gaugemeister::foreman] that changed the cap.
+    pub foreman: Pubkey,
+    /// The previous [Gaugemeister::max_gauge_share_bps].
+    pub prev_max_gauge_share_bps: u16,
+    /// The new [Gaugemeister::max_gauge_share_bps].
+    pub max_gauge_share_bps: u16,
+}
@@ -0,0 +1,59 @@
+//! Sets the minimum power a [Gauge] needs to receive rewards.
+
+use crate::*;
+
+/// Accounts for [gauge::set_min_gauge_power].
+#[derive(Accounts)]
+pub struct SetMinGaugePower<'info> {
+    /// The [Gaugemeister] to modify.
+    #[account(mut)]
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [Gaugemeister::foreman].
+    pub foreman: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetMinGaugePower>,
+    min_gauge_power: u64,
+    min_gauge_share_bps: u16,
+) -> Result<()> {
+    invariant!(min_gauge_share_bps <= BPS_DENOMINATOR, InvalidBps);
+
+    let gaugemeister = &mut ctx.accounts.gaugemeister;
+    gaugemeister.min_gauge_power = min_gauge_power;
+    gaugemeister.min_gauge_share_bps = min_gauge_share_bps;
+
+    emit!(SetMinGaugePowerEvent {
+        gaugemeister: gaugemeister.key(),
+        foreman: ctx.accounts.foreman.key(),
+        min_gauge_power,
+        min_gauge_share_bps,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for SetMinGaugePower<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.foreman,
+            self.gaugemeister.foreman,
+            UnauthorizedNotForeman
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::set_min_gauge_power].
+#[event]
+pub struct SetMinGaugePowerEvent {
+    /// The [Gaugemeister].
+    #[index]
+    pub gaugemeister: Pubkey,
+    /// The [Gaugemeister::foreman] that changed the threshold.
+    pub foreman: Pubkey,
+    /// The new [Gaugemeister::min_gauge_power].
+    pub min_gauge_power: u64,
+    /// The new [Gaugemeister::min_gauge_share_bps].
+    pub min_gauge_share_bps: u16,
+}
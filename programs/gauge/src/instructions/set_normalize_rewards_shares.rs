@@ -0,0 +1,52 @@
+//! Sets whether a [Gaugemeister] normalizes rewards shares.
+
+use crate::*;
+
+/// Accounts for [gauge::set_normalize_rewards_shares].
+#[derive(Accounts)]
+pub struct SetNormalizeRewardsShares<'info> {
+    /// The [Gaugemeister] to modify.
+    #[account(mut)]
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [Gaugemeister::foreman].
+    pub foreman: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetNormalizeRewardsShares>,
+    normalize_rewards_shares: bool,
+) -> Result<()> {
+    let gaugemeister = &mut ctx.accounts.gaugemeister;
+    gaugemeister.normalize_rewards_shares = normalize_rewards_shares;
+
+    emit!(SetNormalizeRewardsSharesEvent {
+        gaugemeister: gaugemeister.key(),
+        foreman: ctx.accounts.foreman.key(),
+        normalize_rewards_shares,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for SetNormalizeRewardsShares<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.foreman,
+            self.gaugemeister.foreman,
+            UnauthorizedNotForeman
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::set_normalize_rewards_shares].
+#[event]
+pub struct SetNormalizeRewardsSharesEvent {
+    /// The [Gaugemeister].
+    #[index]
+    pub gaugemeister: Pubkey,
+    /// The [Gaugemeister::foreman] that changed the mode.
+    pub foreman: Pubkey,
+    /// The new [Gaugemeister::normalize_rewards_shares].
+    pub normalize_rewards_shares: bool,
+}
@@ -0,0 +1,62 @@
+//! Sets [GaugeVoter::is_persistent].
+
+use crate::*;
+
+/// Accounts for [gauge::set_persistent_votes].
+#[derive(Accounts)]
+pub struct SetPersistentVotes<'info> {
+    /// The [Gaugemeister].
+    pub gaugemeister: Account<'info, Gaugemeister>,
+
+    /// The [GaugeVoter].
+    #[account(mut)]
+    pub gauge_voter: Account<'info, GaugeVoter>,
+
+    /// The [locked_voter::Escrow] of the [GaugeVoter].
+    /// CHECK: validated by key in `assert_is_voter_authority`.
+    pub escrow: UncheckedAccount<'info>,
+
+    /// The owner or vote delegate of the [locked_voter::Escrow], or the [GaugeVoter::gauge_delegate].
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetPersistentVotes>, is_persistent: bool) -> Result<()> {
+    let gauge_voter = &mut ctx.accounts.gauge_voter;
+    gauge_voter.is_persistent = is_persistent;
+
+    emit!(SetPersistentVotesEvent {
+        gaugemeister: gauge_voter.gaugemeister,
+        gauge_voter_owner: gauge_voter.owner,
+        authority: ctx.accounts.authority.key(),
+        is_persistent,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for SetPersistentVotes<'info> {
+    fn validate(&self) -> Result<()> {
+        utils::assert_is_voter_authority(
+            &self.gaugemeister,
+            &self.gauge_voter,
+            &self.escrow,
+            self.authority.key,
+        )?;
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::set_persistent_votes].
+#[event]
+pub struct SetPersistentVotesEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    #[index]
+    /// Owner of the Escrow of the [GaugeVoter].
+    pub gauge_voter_owner: Pubkey,
+    /// The owner or vote delegate that changed the setting.
+    pub authority: Pubkey,
+    /// The new [GaugeVoter::is_persistent].
+    pub is_persistent: bool,
+}
@@ -0,0 +1,63 @@
+//! Sets the parameters of a [TipVault].
+
+use crate::*;
+
+/// Accounts for [gauge::set_tip_params].
+#[derive(Accounts)]
+pub struct SetTipParams<'info> {
+    /// The [Gaugemeister].
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [TipVault] to modify.
+    #[account(mut, has_one = gaugemeister)]
+    pub tip_vault: Account<'info, TipVault>,
+    /// The [Gaugemeister::foreman].
+    pub foreman: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetTipParams>, tip_amount: u64, max_tips_per_epoch: u32) -> Result<()> {
+    let tip_vault = &mut ctx.accounts.tip_vault;
+    let prev_tip_amount = tip_vault.tip_amount;
+    let prev_max_tips_per_epoch = tip_vault.max_tips_per_epoch;
+    tip_vault.tip_amount = tip_amount;
+    tip_vault.max_tips_per_epoch = max_tips_per_epoch;
+
+    emit!(SetTipParamsEvent {
+        gaugemeister: tip_vault.gaugemeister,
+        foreman: ctx.accounts.foreman.key(),
+        prev_tip_amount,
+        tip_amount,
+        prev_max_tips_per_epoch,
+        max_tips_per_epoch,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for SetTipParams<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.foreman,
+            self.gaugemeister.foreman,
+            UnauthorizedNotForeman
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::set_tip_params].
+#[event]
+pub struct SetTipParamsEvent {
+    /// The [Gaugemeister].
+    #[index]
+    pub gaugemeister: Pubkey,
+    /// The [Gaugemeister::foreman] that changed the parameters.
+    pub foreman: Pubkey,
+    /// The previous [TipVault::tip_amount].
+    pub prev_tip_amount: u64,
+    /// The new [TipVault::tip_amount].
+    pub tip_amount: u64,
+    /// The previous [TipVault::max_tips_per_epoch].
+    pub prev_max_tips_per_epoch: u32,
+    /// The new [TipVault::max_tips_per_epoch].
+    pub max_tips_per_epoch: u32,
+}
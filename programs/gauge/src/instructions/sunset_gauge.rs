@@ -0,0 +1,107 @@
+//! Disables a [Gauge] which has reached its [Gauge::sunset_epoch].
+
+use quarry_operator::Operator;
+
+use crate::*;
+
+/// Accounts for [gauge::sunset_gauge].
+#[derive(Accounts)]
+pub struct SunsetGauge<'info> {
+    /// The [Gaugemeister].
+    #[account(mut, has_one = rewarder)]
+    pub gaugemeister: Account<'info, Gaugemeister>,
+
+    /// The [Gauge] to disable.
+    #[account(mut, has_one = gaugemeister)]
+    pub gauge: Account<'info, Gauge>,
+
+    /// [Gauge::quarry].
+    #[account(mut)]
+    pub quarry: Account<'info, quarry_mine::Quarry>,
+
+    /// [Gaugemeister::operator].
+    #[account(mut)]
+    pub operator: Account<'info, Operator>,
+
+    /// [Gaugemeister::rewarder].
+    /// CHECK: validated by key, not deserialized to save CU's.
+    #[account(mut)]
+    pub rewarder: UncheckedAccount<'info>,
+
+    /// [quarry_mine] program.
+    pub quarry_mine_program: Program<'info, quarry_mine::program::QuarryMine>,
+    /// [quarry_operator] program.
+    pub quarry_operator_program: Program<'info, quarry_operator::program::QuarryOperator>,
+}
+
+impl<'info> SunsetGauge<'info> {
+    fn sunset(&mut self) -> Result<()> {
+        if !self.gauge.is_disabled {
+            // Disabled gauges do not receive their floor.
+            self.gaugemeister.total_floor_bps = unwrap_int!(self
+                .gaugemeister
+                .total_floor_bps
+                .checked_sub(self.gauge.floor_bps));
+        }
+        self.gauge.is_disabled = true;
+
+        // Only call CPI if the rewards share actually changed.
+        if self.quarry.rewards_share != 0 {
+            delegate_set_rewards_share(
+                &self.gaugemeister,
+                self.operator.to_account_info(),
+                self.rewarder.to_account_info(),
+                self.quarry_mine_program.to_account_info(),
+                self.quarry_operator_program.to_account_info(),
+                self.quarry.to_account_info(),
+                0,
+            )?;
+        }
+
+        emit!(GaugeSunsetEvent {
+            gaugemeister: self.gaugemeister.key(),
+            gauge: self.gauge.key(),
+            sunset_epoch: self.gauge.sunset_epoch,
+            previous_share: self.quarry.rewards_share,
+        });
+
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<SunsetGauge>) -> Result<()> {
+    ctx.accounts.sunset()
+}
+
+impl<'info> Validate<'info> for SunsetGauge<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(!self.gaugemeister.is_paused, GaugemeisterPaused);
+        assert_keys_eq!(self.gaugemeister.operator, self.operator);
+        invariant!(
+            self.gauge
+                .is_sunset(self.gaugemeister.current_rewards_epoch),
+            GaugeNotSunset
+        );
+
+        assert_keys_eq!(self.quarry, self.gauge.quarry);
+        assert_keys_eq!(self.quarry.rewarder, self.rewarder);
+        assert_keys_eq!(self.operator.rewarder, self.rewarder);
+
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::sunset_gauge].
+#[event]
+pub struct GaugeSunsetEvent {
+    /// The [Gaugemeister].
+    #[index]
+    pub gaugemeister: Pubkey,
+    /// The [Gauge].
+    #[index]
+    pub gauge: Pubkey,
+    /// The [Gauge::sunset_epoch].
+    pub sunset_epoch: u32,
+    /// The [quarry_mine::Quarry::rewards_share] before it was set to zero.
+    pub previous_share: u64,
+}
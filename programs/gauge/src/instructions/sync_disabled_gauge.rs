@@ -37,21 +37,13 @@ impl<'info> SyncDisabledGauge<'info> {
     fn disable_rewards(&self) -> Result<()> {
         // Only call CPI if the rewards share actually changed.
         if self.quarry.rewards_share != 0 {
-            let gm_seeds: &[&[&[u8]]] = gaugemeister_seeds!(self.gaugemeister);
-            quarry_operator::cpi::delegate_set_rewards_share(
-                CpiContext::new(
-                    self.quarry_operator_program.to_account_info(),
-                    quarry_operator::cpi::accounts::DelegateSetRewardsShare {
-                        with_delegate: quarry_operator::cpi::accounts::WithDelegate {
-                            operator: self.operator.to_account_info(),
-                            delegate: self.gaugemeister.to_account_info(),
-                            rewarder: self.rewarder.to_account_info(),
-                            quarry_mine_program: self.quarry_mine_program.to_account_info(),
-                        },
-                        quarry: self.quarry.to_account_info(),
-                    },
-                )
-                .with_signer(gm_seeds),
+            delegate_set_rewards_share(
+                &self.gaugemeister,
+                self.operator.to_account_info(),
+                self.rewarder.to_account_info(),
+                self.quarry_mine_program.to_account_info(),
+                self.quarry_operator_program.to_account_info(),
+                self.quarry.to_account_info(),
                 0,
             )?;
         }
@@ -66,6 +58,7 @@ pub fn handler(ctx: Context<SyncDisabledGauge>) -> Result<()> {
 
 impl<'info> Validate<'info> for SyncDisabledGauge<'info> {
     fn validate(&self) -> Result<()> {
+        invariant!(!self.gaugemeister.is_paused, GaugemeisterPaused);
         assert_keys_eq!(self.gaugemeister, self.gauge.gaugemeister);
         assert_keys_eq!(self.gaugemeister.rewarder, self.rewarder);
         assert_keys_eq!(self.gaugemeister.operator, self.operator);
@@ -5,6 +5,9 @@ use quarry_operator::Operator;
 use crate::*;
 
 /// Accounts for [gauge::sync_gauge].
+///
+/// The accounts of the [TipVault] described in `pay_crank_tip` may be passed as remaining
+/// accounts to receive a tip if the rewards share changes.
 #[derive(Accounts)]
 pub struct SyncGauge<'info> {
     /// The [Gaugemeister].
@@ -17,6 +20,30 @@ pub struct SyncGauge<'info> {
     /// The [EpochGauge].
     pub epoch_gauge: Account<'info, EpochGauge>,
 
+    /// The [EpochGaugemeister] of the current rewards epoch.
+    /// CHECK: validated by seeds, and does not exist if no votes were committed in the epoch.
+    #[account(
+        seeds = [
+            b"EpochGaugemeister".as_ref(),
+            gaugemeister.key().as_ref(),
+            gaugemeister.current_rewards_epoch.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub epoch_gaugemeister: UncheckedAccount<'info>,
+
+    /// The [EpochGaugeAllocation] of the current rewards epoch.
+    /// CHECK: validated by seeds, and does not exist if the foreman made no allocation.
+    #[account(
+        seeds = [
+            b"EpochGaugeAllocation".as_ref(),
+            gauge.key().as_ref(),
+            gaugemeister.current_rewards_epoch.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub epoch_gauge_allocation: UncheckedAccount<'info>,
+
     /// [Gauge::quarry].
     #[account(mut)]
     pub quarry: Account<'info, quarry_mine::Quarry>,
@@ -52,28 +79,57 @@ pub struct SyncGaugeEvent {
     pub previous_share: u64,
     /// The new [quarry_mine::Quarry::rewards_share].
     pub new_share: u64,
+    /// Power committed to the [Gauge] which did not earn rewards
+    /// because it was below the minimum threshold.
+    pub dropped_power: u64,
+    /// The part of the new share which comes from the [Gauge::floor_bps].
+    pub floor_share: u64,
+    /// The part of the new share which comes from the [Gaugemeister::foreman_slice_bps].
+    pub foreman_share: u64,
+}
+
+/// Event called in [gauge::sync_gauge], [gauge::sync_gauges] and [gauge::sync_unvoted_gauge]
+/// when the rewards epoch has no [EpochGaugemeister] but its totals are needed.
+/// The vote-driven shares of the epoch are zero.
+#[event]
+pub struct MissingEpochGaugemeisterEvent {
+    /// The [Gaugemeister].
+    #[index]
+    pub gaugemeister: Pubkey,
+    /// The rewards epoch without an [EpochGaugemeister].
+    pub epoch: u32,
 }
 
 impl<'info> SyncGauge<'info> {
-    fn set_rewards_share(&self) -> Result<()> {
+    /// Sets the rewards share of the [quarry_mine::Quarry], returning true if it changed.
+    fn set_rewards_share(&self) -> Result<bool> {
+        let epoch_gaugemeister =
+            load_epoch_gaugemeister(&self.gaugemeister, &self.epoch_gaugemeister)?;
+        let allocation_weight = load_allocation_weight(
+            &self.gauge.key(),
+            self.gaugemeister.current_rewards_epoch,
+            &self.epoch_gauge_allocation,
+        )?;
+        let share = compute_rewards_share(
+            &self.gaugemeister,
+            &self.gauge,
+            &self.epoch_gauge,
+            &epoch_gaugemeister,
+            allocation_weight,
+        )?;
+        let new_share = share.new_share;
+
         // Only call CPI if the rewards share actually changed.
-        if self.quarry.rewards_share != self.epoch_gauge.total_power {
-            let gm_seeds: &[&[&[u8]]] = gaugemeister_seeds!(self.gaugemeister);
-            quarry_operator::cpi::delegate_set_rewards_share(
-                CpiContext::new(
-                    self.quarry_operator_program.to_account_info(),
-                    quarry_operator::cpi::accounts::DelegateSetRewardsShare {
-                        with_delegate: quarry_operator::cpi::accounts::WithDelegate {
-                            operator: self.operator.to_account_info(),
-                            delegate: self.gaugemeister.to_account_info(),
-                            rewarder: self.rewarder.to_account_info(),
-                            quarry_mine_program: self.quarry_mine_program.to_account_info(),
-                        },
-                        quarry: self.quarry.to_account_info(),
-                    },
-                )
-                .with_signer(gm_seeds),
-                self.epoch_gauge.total_power,
+        let changed = self.quarry.rewards_share != new_share;
+        if changed {
+            delegate_set_rewards_share(
+                &self.gaugemeister,
+                self.operator.to_account_info(),
+                self.rewarder.to_account_info(),
+                self.quarry_mine_program.to_account_info(),
+                self.quarry_operator_program.to_account_info(),
+                self.quarry.to_account_info(),
+                new_share,
             )?;
         }
 
@@ -83,19 +139,193 @@ impl<'info> SyncGauge<'info> {
             gauge: self.epoch_gauge.gauge,
             epoch: self.epoch_gauge.voting_epoch,
             previous_share: self.quarry.rewards_share,
-            new_share: self.epoch_gauge.total_power
+            new_share,
+            dropped_power: share.dropped_power,
+            floor_share: share.floor_share,
+            foreman_share: share.foreman_share,
         });
 
-        Ok(())
+        Ok(changed)
+    }
+}
+
+/// Loads the [EpochGaugemeister] of the current rewards epoch.
+///
+/// The `epoch_gaugemeister` must already be validated as the [EpochGaugemeister]
+/// of the current rewards epoch. If it does not exist, nobody committed votes while
+/// the epoch was being voted on, so an empty [EpochGaugemeister] is used and the epoch
+/// is synced as one without committed power. Normalized and group shares of an
+/// [EpochGauge] then come out as zero, which emits a [MissingEpochGaugemeisterEvent].
+pub(crate) fn load_epoch_gaugemeister(
+    gaugemeister: &Account<Gaugemeister>,
+    epoch_gaugemeister: &AccountInfo,
+) -> Result<EpochGaugemeister> {
+    if epoch_gaugemeister.data_is_empty() {
+        if gaugemeister.normalize_rewards_shares || gaugemeister.has_gauge_groups() {
+            emit!(MissingEpochGaugemeisterEvent {
+                gaugemeister: gaugemeister.key(),
+                epoch: gaugemeister.current_rewards_epoch,
+            });
+        }
+        return Ok(EpochGaugemeister::default());
     }
+    let epoch_gaugemeister: Account<EpochGaugemeister> = Account::try_from(epoch_gaugemeister)?;
+    Ok(*epoch_gaugemeister)
 }
 
-pub fn handler(ctx: Context<SyncGauge>) -> Result<()> {
-    ctx.accounts.set_rewards_share()
+/// Loads the [EpochGaugeAllocation::weight] of a [Gauge] for an epoch.
+///
+/// If the `epoch_gauge_allocation` does not exist, the [Gauge] has no allocation.
+/// The caller must ensure that an empty account is at the expected address.
+pub(crate) fn load_allocation_weight(
+    gauge: &Pubkey,
+    epoch: u32,
+    epoch_gauge_allocation: &AccountInfo,
+) -> Result<u32> {
+    if epoch_gauge_allocation.data_is_empty() {
+        return Ok(0);
+    }
+    let epoch_gauge_allocation: Account<EpochGaugeAllocation> =
+        Account::try_from(epoch_gauge_allocation)?;
+    assert_keys_eq!(epoch_gauge_allocation.gauge, *gauge);
+    invariant!(
+        epoch_gauge_allocation.voting_epoch == epoch,
+        GaugeWrongEpoch
+    );
+    Ok(epoch_gauge_allocation.weight)
+}
+
+/// A rewards share computed by [compute_rewards_share].
+#[derive(Copy, Clone, Debug, Default)]
+pub(crate) struct GaugeRewardsShare {
+    /// The new [quarry_mine::Quarry::rewards_share].
+    pub(crate) new_share: u64,
+    /// Power which did not earn rewards because it was below the minimum threshold.
+    pub(crate) dropped_power: u64,
+    /// The part of [Self::new_share] which comes from the [Gauge::floor_bps].
+    pub(crate) floor_share: u64,
+    /// The part of [Self::new_share] which comes from the [Gaugemeister::foreman_slice_bps].
+    pub(crate) foreman_share: u64,
+}
+
+/// Computes the [quarry_mine::Quarry::rewards_share] of an enabled [Gauge]
+/// for the current rewards epoch, combining its votes, its floor, and
+/// its [EpochGaugeAllocation::weight] of the foreman slice.
+///
+/// A [Gauge] outside of its activation window receives nothing.
+pub(crate) fn compute_rewards_share(
+    gaugemeister: &Gaugemeister,
+    gauge: &Gauge,
+    epoch_gauge: &EpochGauge,
+    epoch_gaugemeister: &EpochGaugemeister,
+    allocation_weight: u32,
+) -> Result<GaugeRewardsShare> {
+    if !gauge.is_active_in(epoch_gauge.voting_epoch) {
+        return Ok(GaugeRewardsShare::default());
+    }
+    let epoch_total_power = epoch_gaugemeister.total_power;
+    let is_below_threshold =
+        gaugemeister.is_below_threshold(epoch_gauge.total_power, epoch_total_power);
+    // Epochs without an [EpochGaugemeister] cannot record dropped gauges.
+    invariant!(
+        !is_below_threshold
+            || epoch_gauge.is_dropped
+            || epoch_gauge.total_power == 0
+            || epoch_total_power == 0,
+        GaugeThresholdNotApplied
+    );
+    let (vote_share, dropped_power) = if epoch_gauge.is_dropped || is_below_threshold {
+        (0, epoch_gauge.total_power)
+    } else {
+        (
+            vote_share(gaugemeister, gauge, epoch_gauge, epoch_gaugemeister)?,
+            0,
+        )
+    };
+    let total_vote_shares = unwrap_int!(gaugemeister.total_vote_shares(epoch_gaugemeister));
+    let (share, floor_share) =
+        unwrap_int!(gaugemeister.apply_floor(gauge, vote_share, total_vote_shares));
+    let foreman_share = unwrap_int!(gaugemeister.foreman_share(
+        allocation_weight,
+        epoch_gaugemeister,
+        total_vote_shares
+    ));
+    Ok(GaugeRewardsShare {
+        new_share: unwrap_int!(share.checked_add(foreman_share)),
+        dropped_power,
+        floor_share,
+        foreman_share,
+    })
+}
+
+/// Computes the part of the rewards share of a [Gauge] which is driven by votes.
+fn vote_share(
+    gaugemeister: &Gaugemeister,
+    gauge: &Gauge,
+    epoch_gauge: &EpochGauge,
+    epoch_gaugemeister: &EpochGaugemeister,
+) -> Result<u64> {
+    invariant!(
+        epoch_gauge.cap_bps != 0
+            || !gaugemeister.is_over_cap(
+                gauge,
+                epoch_gauge.total_power,
+                unwrap_int!(epoch_gaugemeister.distributed_power())
+            ),
+        GaugeCapNotApplied
+    );
+    let vote_share = unwrap_int!(gaugemeister.rewards_share(epoch_gauge, epoch_gaugemeister));
+    Ok(unwrap_int!(
+        epoch_gaugemeister.apply_group_budget(epoch_gauge, vote_share)
+    ))
+}
+
+/// Sets the [quarry_mine::Quarry::rewards_share] of a quarry,
+/// signing as the [Gaugemeister], which is the delegate of the [Operator].
+pub(crate) fn delegate_set_rewards_share<'info>(
+    gaugemeister: &Account<'info, Gaugemeister>,
+    operator: AccountInfo<'info>,
+    rewarder: AccountInfo<'info>,
+    quarry_mine_program: AccountInfo<'info>,
+    quarry_operator_program: AccountInfo<'info>,
+    quarry: AccountInfo<'info>,
+    new_share: u64,
+) -> Result<()> {
+    let gm_seeds: &[&[&[u8]]] = gaugemeister_seeds!(gaugemeister);
+    quarry_operator::cpi::delegate_set_rewards_share(
+        CpiContext::new(
+            quarry_operator_program,
+            quarry_operator::cpi::accounts::DelegateSetRewardsShare {
+                with_delegate: quarry_operator::cpi::accounts::WithDelegate {
+                    operator,
+                    delegate: gaugemeister.to_account_info(),
+                    rewarder,
+                    quarry_mine_program,
+                },
+                quarry,
+            },
+        )
+        .with_signer(gm_seeds),
+        new_share,
+    )
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, SyncGauge<'info>>) -> Result<()> {
+    // Syncs which do not change the share are not tipped, so they cannot be farmed.
+    if !ctx.accounts.set_rewards_share()? {
+        return Ok(());
+    }
+    pay_crank_tip(
+        &ctx.accounts.gaugemeister.key(),
+        ctx.accounts.gaugemeister.current_rewards_epoch,
+        CrankKind::SyncGauge,
+        ctx.remaining_accounts,
+    )
 }
 
 impl<'info> Validate<'info> for SyncGauge<'info> {
     fn validate(&self) -> Result<()> {
+        invariant!(!self.gaugemeister.is_paused, GaugemeisterPaused);
         assert_keys_eq!(self.gaugemeister, self.gauge.gaugemeister);
         assert_keys_eq!(self.gaugemeister.rewarder, self.rewarder);
         assert_keys_eq!(self.gaugemeister.operator, self.operator);
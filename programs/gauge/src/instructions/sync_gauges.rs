@@ -0,0 +1,157 @@
+//! Syncs many [Gauge]s at once.
+
+use quarry_operator::Operator;
+
+use crate::*;
+
+/// Accounts for [gauge::sync_gauges].
+///
+/// The remaining accounts must be groups of four accounts, one for each [Gauge] being synced:
+/// 1. The [Gauge].
+/// 2. The [EpochGauge] of the current rewards epoch. This is not read if the [Gauge] is disabled.
+/// 3. The [EpochGaugeAllocation] of the current rewards epoch, which may not exist.
+///    This is not read if the [Gauge] is disabled.
+/// 4. The [Gauge::quarry], which must be writable.
+#[derive(Accounts)]
+pub struct SyncGauges<'info> {
+    /// The [Gaugemeister].
+    #[account(has_one = rewarder)]
+    pub gaugemeister: Account<'info, Gaugemeister>,
+
+    /// The [EpochGaugemeister] of the current rewards epoch.
+    /// CHECK: validated by seeds, and does not exist if no votes were committed in the epoch.
+    #[account(
+        seeds = [
+            b"EpochGaugemeister".as_ref(),
+            gaugemeister.key().as_ref(),
+            gaugemeister.current_rewards_epoch.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub epoch_gaugemeister: UncheckedAccount<'info>,
+
+    /// [Gaugemeister::operator].
+    #[account(mut)]
+    pub operator: Account<'info, Operator>,
+
+    /// [Gaugemeister::rewarder].
+    /// CHECK: validated by key, not deserialized to save CU's.
+    #[account(mut)]
+    pub rewarder: UncheckedAccount<'info>,
+
+    /// [quarry_mine] program.
+    pub quarry_mine_program: Program<'info, quarry_mine::program::QuarryMine>,
+    /// [quarry_operator] program.
+    pub quarry_operator_program: Program<'info, quarry_operator::program::QuarryOperator>,
+}
+
+/// Number of remaining accounts per [Gauge] in [gauge::sync_gauges].
+const ACCOUNTS_PER_GAUGE: usize = 4;
+
+impl<'info> SyncGauges<'info> {
+    /// Validates and syncs a single [Gauge].
+    fn sync_gauge(
+        &self,
+        epoch_gaugemeister: &EpochGaugemeister,
+        gauge_info: &AccountInfo<'info>,
+        epoch_gauge_info: &AccountInfo<'info>,
+        epoch_gauge_allocation_info: &AccountInfo<'info>,
+        quarry_info: &AccountInfo<'info>,
+    ) -> Result<()> {
+        let gauge: Account<Gauge> = Account::try_from(gauge_info)?;
+        let quarry: Account<quarry_mine::Quarry> = Account::try_from(quarry_info)?;
+
+        assert_keys_eq!(self.gaugemeister, gauge.gaugemeister);
+        assert_keys_eq!(quarry, gauge.quarry);
+        assert_keys_eq!(quarry.rewarder, self.rewarder);
+
+        let epoch = self.gaugemeister.current_rewards_epoch;
+        let share = if gauge.is_disabled {
+            GaugeRewardsShare::default()
+        } else {
+            let epoch_gauge: Account<EpochGauge> = Account::try_from(epoch_gauge_info)?;
+            assert_keys_eq!(epoch_gauge.gauge, gauge);
+            invariant!(epoch_gauge.voting_epoch == epoch, GaugeWrongEpoch);
+            if epoch_gauge_allocation_info.data_is_empty() {
+                let (epoch_gauge_allocation_key, _) =
+                    EpochGaugeAllocation::find_program_address(&gauge.key(), epoch);
+                assert_keys_eq!(
+                    epoch_gauge_allocation_key,
+                    epoch_gauge_allocation_info.key()
+                );
+            }
+            let allocation_weight =
+                load_allocation_weight(&gauge.key(), epoch, epoch_gauge_allocation_info)?;
+            compute_rewards_share(
+                &self.gaugemeister,
+                &gauge,
+                &epoch_gauge,
+                epoch_gaugemeister,
+                allocation_weight,
+            )?
+        };
+        let new_share = share.new_share;
+
+        // Only call CPI if the rewards share actually changed.
+        if quarry.rewards_share != new_share {
+            delegate_set_rewards_share(
+                &self.gaugemeister,
+                self.operator.to_account_info(),
+                self.rewarder.to_account_info(),
+                self.quarry_mine_program.to_account_info(),
+                self.quarry_operator_program.to_account_info(),
+                quarry_info.clone(),
+                new_share,
+            )?;
+        }
+
+        emit!(SyncGaugeEvent {
+            gaugemeister: self.gaugemeister.key(),
+            gauge: gauge.key(),
+            epoch,
+            previous_share: quarry.rewards_share,
+            new_share,
+            dropped_power: share.dropped_power,
+            floor_share: share.floor_share,
+            foreman_share: share.foreman_share,
+        });
+
+        Ok(())
+    }
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, SyncGauges<'info>>) -> Result<()> {
+    let gauges_accounts = ctx.remaining_accounts.chunks_exact(ACCOUNTS_PER_GAUGE);
+    invariant!(
+        gauges_accounts.remainder().is_empty(),
+        InvalidRemainingAccounts
+    );
+    let epoch_gaugemeister =
+        load_epoch_gaugemeister(&ctx.accounts.gaugemeister, &ctx.accounts.epoch_gaugemeister)?;
+    for accounts in gauges_accounts {
+        ctx.accounts.sync_gauge(
+            &epoch_gaugemeister,
+            &accounts[0],
+            &accounts[1],
+            &accounts[2],
+            &accounts[3],
+        )?;
+    }
+    Ok(())
+}
+
+impl<'info> Validate<'info> for SyncGauges<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(!self.gaugemeister.is_paused, GaugemeisterPaused);
+        assert_keys_eq!(self.gaugemeister.rewarder, self.rewarder);
+        assert_keys_eq!(self.gaugemeister.operator, self.operator);
+        assert_keys_eq!(self.operator.rewarder, self.rewarder);
+
+        invariant!(
+            self.gaugemeister.current_rewards_epoch != 0,
+            GaugeEpochCannotBeZero
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+//! Syncs a [Gauge] which received no votes in the current rewards epoch.
+//!
+//! The [quarry_mine::Quarry] only receives the [Gauge::floor_bps] and its
+//! [EpochGaugeAllocation] of the foreman slice, if any.
+
+use quarry_operator::Operator;
+
+use crate::*;
+
+/// Accounts for [gauge::sync_unvoted_gauge].
+#[derive(Accounts)]
+pub struct SyncUnvotedGauge<'info> {
+    /// The [Gaugemeister].
+    #[account(has_one = rewarder)]
+    pub gaugemeister: Account<'info, Gaugemeister>,
+
+    /// The [Gauge].
+    pub gauge: Account<'info, Gauge>,
+
+    /// The [EpochGauge] of the current rewards epoch, which must not exist.
+    /// CHECK: validated by seeds and checked to be uninitialized.
+    #[account(
+        seeds = [
+            b"EpochGauge".as_ref(),
+            gauge.key().as_ref(),
+            gaugemeister.current_rewards_epoch.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub epoch_gauge: UncheckedAccount<'info>,
+
+    /// The [EpochGaugemeister] of the current rewards epoch.
+    /// CHECK: validated by seeds, and does not exist if no votes were committed in the epoch.
+    #[account(
+        seeds = [
+            b"EpochGaugemeister".as_ref(),
+            gaugemeister.key().as_ref(),
+            gaugemeister.current_rewards_epoch.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub epoch_gaugemeister: UncheckedAccount<'info>,
+
+    /// The [EpochGaugeAllocation] of the current rewards epoch.
+    /// CHECK: validated by seeds, and does not exist if the foreman made no allocation.
+    #[account(
+        seeds = [
+            b"EpochGaugeAllocation".as_ref(),
+            gauge.key().as_ref(),
+            gaugemeister.current_rewards_epoch.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub epoch_gauge_allocation: UncheckedAccount<'info>,
+
+    /// [Gauge::quarry].
+    #[account(mut)]
+    pub quarry: Account<'info, quarry_mine::Quarry>,
+
+    /// [Gaugemeister::operator].
+    #[account(mut)]
+    pub operator: Account<'info, Operator>,
+
+    /// [Gaugemeister::rewarder].
+    /// CHECK: validated by key, not deserialized to save CU's.
+    #[account(mut)]
+    pub rewarder: UncheckedAccount<'info>,
+
+    /// [quarry_mine] program.
+    pub quarry_mine_program: Program<'info, quarry_mine::program::QuarryMine>,
+    /// [quarry_operator] program.
+    pub quarry_operator_program: Program<'info, quarry_operator::program::QuarryOperator>,
+}
+
+impl<'info> SyncUnvotedGauge<'info> {
+    /// Computes the share of the [Gauge] as if it had an [EpochGauge] with no power.
+    fn rewards_share(&self) -> Result<GaugeRewardsShare> {
+        if self.gauge.is_disabled {
+            return Ok(GaugeRewardsShare::default());
+        }
+        let epoch = self.gaugemeister.current_rewards_epoch;
+        let epoch_gaugemeister =
+            load_epoch_gaugemeister(&self.gaugemeister, &self.epoch_gaugemeister)?;
+        let allocation_weight =
+            load_allocation_weight(&self.gauge.key(), epoch, &self.epoch_gauge_allocation)?;
+        let epoch_gauge = EpochGauge {
+            gauge: self.gauge.key(),
+            voting_epoch: epoch,
+            ..Default::default()
+        };
+        compute_rewards_share(
+            &self.gaugemeister,
+            &self.gauge,
+            &epoch_gauge,
+            &epoch_gaugemeister,
+            allocation_weight,
+        )
+    }
+
+    fn set_rewards_share(&self) -> Result<()> {
+        let share = self.rewards_share()?;
+        let new_share = share.new_share;
+
+        // Only call CPI if the rewards share actually changed.
+        if self.quarry.rewards_share != new_share {
+            delegate_set_rewards_share(
+                &self.gaugemeister,
+                self.operator.to_account_info(),
+                self.rewarder.to_account_info(),
+                self.quarry_mine_program.to_account_info(),
+                self.quarry_operator_program.to_account_info(),
+                self.quarry.to_account_info(),
+                new_share,
+            )?;
+        }
+
+        emit!(SyncGaugeEvent {
+            gaugemeister: self.gaugemeister.key(),
+            gauge: self.gauge.key(),
+            epoch: self.gaugemeister.current_rewards_epoch,
+            previous_share: self.quarry.rewards_share,
+            new_share,
+            dropped_power: share.dropped_power,
+            floor_share: share.floor_share,
+            foreman_share: share.foreman_share,
+        });
+
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<SyncUnvotedGauge>) -> Result<()> {
+    ctx.accounts.set_rewards_share()
+}
+
+impl<'info> Validate<'info> for SyncUnvotedGauge<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(!self.gaugemeister.is_paused, GaugemeisterPaused);
+        assert_keys_eq!(self.gaugemeister, self.gauge.gaugemeister);
+        assert_keys_eq!(self.gaugemeister.rewarder, self.rewarder);
+        assert_keys_eq!(self.gaugemeister.operator, self.operator);
+        invariant!(self.epoch_gauge.data_is_empty(), EpochGaugeExists);
+
+        assert_keys_eq!(self.quarry, self.gauge.quarry);
+        assert_keys_eq!(self.quarry.rewarder, self.rewarder);
+        assert_keys_eq!(self.operator.rewarder, self.rewarder);
+
+        invariant!(
+            self.gaugemeister.current_rewards_epoch != 0,
+            GaugeEpochCannotBeZero
+        );
+
+        Ok(())
+    }
+}
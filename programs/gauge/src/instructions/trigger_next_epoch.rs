@@ -13,7 +13,7 @@ pub struct TriggerNextEpoch<'info> {
     pub gaugemeister: Account<'info, Gaugemeister>,
 }
 
-pub fn handler(ctx: Context<TriggerNextEpoch>) -> Result<()> {
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, TriggerNextEpoch<'info>>) -> Result<()> {
     let now = unwrap_int!(Clock::get()?.unix_timestamp.to_u64());
     msg!(
         "now: {}; next: {}",
@@ -26,14 +26,43 @@ pub fn handler(ctx: Context<TriggerNextEpoch>) -> Result<()> {
     );
 
     let gaugemeister = &mut ctx.accounts.gaugemeister;
-    gaugemeister.current_rewards_epoch = gaugemeister.voting_epoch()?;
-    gaugemeister.next_epoch_starts_at =
-        unwrap_int!(now.checked_add(unwrap_int!(gaugemeister.epoch_duration_seconds.to_u64())));
-    Ok(())
+    let prev_rewards_epoch = gaugemeister.current_rewards_epoch;
+    let (rewards_epoch, next_epoch_starts_at) = unwrap_int!(gaugemeister.next_epoch_at(now));
+    gaugemeister.current_rewards_epoch = rewards_epoch;
+    gaugemeister.next_epoch_starts_at = next_epoch_starts_at;
+
+    emit!(TriggerNextEpochEvent {
+        gaugemeister: gaugemeister.key(),
+        prev_rewards_epoch,
+        rewards_epoch,
+        next_epoch_starts_at,
+    });
+
+    pay_crank_tip(
+        &gaugemeister.key(),
+        rewards_epoch,
+        CrankKind::TriggerNextEpoch,
+        ctx.remaining_accounts,
+    )
 }
 
 impl<'info> Validate<'info> for TriggerNextEpoch<'info> {
     fn validate(&self) -> Result<()> {
+        invariant!(!self.gaugemeister.is_paused, GaugemeisterPaused);
         Ok(())
     }
 }
+
+/// Event called in [gauge::trigger_next_epoch].
+#[event]
+pub struct TriggerNextEpochEvent {
+    /// The [Gaugemeister].
+    #[index]
+    pub gaugemeister: Pubkey,
+    /// The rewards epoch before the trigger.
+    pub prev_rewards_epoch: u32,
+    /// The new [Gaugemeister::current_rewards_epoch].
+    pub rewards_epoch: u32,
+    /// When the epoch after this one starts.
+    pub next_epoch_starts_at: u64,
+}
@@ -0,0 +1,46 @@
+//! Unpauses a [Gaugemeister].
+
+use crate::*;
+
+/// Accounts for [gauge::unpause_gaugemeister].
+#[derive(Accounts)]
+pub struct UnpauseGaugemeister<'info> {
+    /// The [Gaugemeister] to unpause.
+    #[account(mut)]
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [Gaugemeister::guardian].
+    pub guardian: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UnpauseGaugemeister>) -> Result<()> {
+    let gaugemeister = &mut ctx.accounts.gaugemeister;
+    gaugemeister.is_paused = false;
+
+    emit!(UnpauseGaugemeisterEvent {
+        gaugemeister: gaugemeister.key(),
+        guardian: ctx.accounts.guardian.key(),
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for UnpauseGaugemeister<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.guardian,
+            self.gaugemeister.guardian,
+            UnauthorizedNotGuardian
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::unpause_gaugemeister].
+#[event]
+pub struct UnpauseGaugemeisterEvent {
+    /// The [Gaugemeister].
+    #[index]
+    pub gaugemeister: Pubkey,
+    /// The [Gaugemeister::guardian] that unpaused the [Gaugemeister].
+    pub guardian: Pubkey,
+}
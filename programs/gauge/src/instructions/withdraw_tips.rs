@@ -0,0 +1,110 @@
+//! Withdraws funds from a [TipVault].
+
+use anchor_spl::token::{self, Token};
+
+use crate::*;
+
+/// Accounts for [gauge::withdraw_tips].
+#[derive(Accounts)]
+pub struct WithdrawTips<'info> {
+    /// The [Gaugemeister].
+    pub gaugemeister: Account<'info, Gaugemeister>,
+    /// The [TipVault] to withdraw from.
+    #[account(mut, has_one = gaugemeister)]
+    pub tip_vault: Account<'info, TipVault>,
+    /// The [Gaugemeister::foreman].
+    pub foreman: Signer<'info>,
+
+    /// The [TipVault::token_account].
+    /// CHECK: validated by key. This is the default address if tips are paid in SOL.
+    pub token_account: UncheckedAccount<'info>,
+    /// The account receiving the funds: a system account if tips are paid in SOL,
+    /// or a token account of the [TipVault::mint] otherwise.
+    /// CHECK: arbitrary destination chosen by the foreman.
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    /// The SPL token program.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> WithdrawTips<'info> {
+    fn withdraw_sol(&self, amount: u64) -> Result<()> {
+        let tip_vault_info = self.tip_vault.to_account_info();
+        let rent_exempt_lamports = Rent::get()?.minimum_balance(tip_vault_info.data_len());
+        let vault_lamports = tip_vault_info.lamports();
+        invariant!(
+            vault_lamports.saturating_sub(rent_exempt_lamports) >= amount,
+            InsufficientTipVaultBalance
+        );
+        **tip_vault_info.try_borrow_mut_lamports()? =
+            unwrap_int!(vault_lamports.checked_sub(amount));
+        let destination_lamports = self.destination.lamports();
+        **self.destination.try_borrow_mut_lamports()? =
+            unwrap_int!(destination_lamports.checked_add(amount));
+        Ok(())
+    }
+
+    fn withdraw_tokens(&self, amount: u64) -> Result<()> {
+        let seeds: &[&[&[u8]]] = tip_vault_seeds!(self.tip_vault);
+        token::transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token::Transfer {
+                    from: self.token_account.to_account_info(),
+                    to: self.destination.to_account_info(),
+                    authority: self.tip_vault.to_account_info(),
+                },
+            )
+            .with_signer(seeds),
+            amount,
+        )
+    }
+}
+
+pub fn handler(ctx: Context<WithdrawTips>, amount: u64) -> Result<()> {
+    if ctx.accounts.tip_vault.is_sol() {
+        ctx.accounts.withdraw_sol(amount)?;
+    } else {
+        ctx.accounts.withdraw_tokens(amount)?;
+    }
+
+    emit!(WithdrawTipsEvent {
+        gaugemeister: ctx.accounts.gaugemeister.key(),
+        foreman: ctx.accounts.foreman.key(),
+        destination: ctx.accounts.destination.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for WithdrawTips<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.foreman,
+            self.gaugemeister.foreman,
+            UnauthorizedNotForeman
+        );
+        assert_keys_eq!(
+            self.token_account,
+            self.tip_vault.token_account,
+            InvalidTipVaultTokenAccount
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [gauge::withdraw_tips].
+#[event]
+pub struct WithdrawTipsEvent {
+    /// The [Gaugemeister].
+    #[index]
+    pub gaugemeister: Pubkey,
+    /// The [Gaugemeister::foreman] that withdrew the funds.
+    pub foreman: Pubkey,
+    /// The account that received the funds.
+    pub destination: Pubkey,
+    /// The amount withdrawn.
+    pub amount: u64,
+}
@@ -16,10 +16,13 @@ use vipers::prelude::*;
 mod instructions;
 mod macros;
 mod state;
+mod tips;
+mod utils;
 
 pub use state::*;
 
 use instructions::*;
+use tips::*;
 
 declare_id!("GaugesLJrnVjNNWLReiw3Q7xQhycSBRgeHGTMDUaX231");
 
@@ -38,21 +41,37 @@ pub mod gauge {
         foreman: Pubkey,
         epoch_duration_seconds: u32,
         first_epoch_starts_at: u64,
+        params_change_delay_seconds: u32,
     ) -> Result<()> {
-        create_gaugemeister::handler(ctx, foreman, epoch_duration_seconds, first_epoch_starts_at)
+        create_gaugemeister::handler(
+            ctx,
+            foreman,
+            epoch_duration_seconds,
+            first_epoch_starts_at,
+            params_change_delay_seconds,
+        )
     }
 
     /// Creates a [Gaugemeister].
     ///
     /// The V2 variant removes the need to supply a bump seed.
+    ///
+    /// `params_change_delay_seconds` is the initial [Gaugemeister::params_change_delay_seconds].
     #[access_control(ctx.accounts.validate())]
     pub fn create_gaugemeister_v2(
         ctx: Context<CreateGaugemeister>,
         foreman: Pubkey,
         epoch_duration_seconds: u32,
         first_epoch_starts_at: u64,
+        params_change_delay_seconds: u32,
     ) -> Result<()> {
-        create_gaugemeister::handler(ctx, foreman, epoch_duration_seconds, first_epoch_starts_at)
+        create_gaugemeister::handler(
+            ctx,
+            foreman,
+            epoch_duration_seconds,
+            first_epoch_starts_at,
+            params_change_delay_seconds,
+        )
     }
 
     /// Creates a [Gauge]. Permissionless.
@@ -101,15 +120,36 @@ pub mod gauge {
     }
 
     /// Creates an [EpochGauge]. Permissionless.
+    ///
+    /// The [Gaugemeister] and the accounts of its [TipVault] may be passed as
+    /// remaining accounts to receive a tip.
     #[access_control(ctx.accounts.validate())]
-    pub fn create_epoch_gauge(
-        ctx: Context<CreateEpochGauge>,
+    pub fn create_epoch_gauge<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateEpochGauge<'info>>,
         _bump: u8,
         voting_epoch: u32,
     ) -> Result<()> {
         create_epoch_gauge::handler(ctx, voting_epoch)
     }
 
+    /// Creates an [EpochGaugeAllocation]. Permissionless.
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_epoch_gauge_allocation(
+        ctx: Context<CreateEpochGaugeAllocation>,
+        voting_epoch: u32,
+    ) -> Result<()> {
+        create_epoch_gauge_allocation::handler(ctx, voting_epoch)
+    }
+
+    /// Creates an [EpochGaugemeister]. Permissionless.
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_epoch_gaugemeister(
+        ctx: Context<CreateEpochGaugemeister>,
+        voting_epoch: u32,
+    ) -> Result<()> {
+        create_epoch_gaugemeister::handler(ctx, voting_epoch)
+    }
+
     /// Creates an [EpochGaugeVoter]. Permissionless.
     #[deprecated(note = "Use `prepare_epoch_gauge_voter_v2` instead.")]
     #[access_control(ctx.accounts.validate())]
@@ -135,17 +175,41 @@ pub mod gauge {
         reset_epoch_gauge_voter::handler(ctx)
     }
 
+    /// Recomputes the voting power of an [EpochGaugeVoter] and rescales all of its
+    /// committed [EpochGaugeVote]s to it. Permissionless.
+    ///
+    /// The [GaugeVote::weight]s must not have changed since the [EpochGaugeVoter]
+    /// was prepared.
+    #[access_control(ctx.accounts.validate())]
+    pub fn refresh_epoch_gauge_voter<'info>(
+        ctx: Context<'_, '_, '_, 'info, RefreshEpochGaugeVoter<'info>>,
+    ) -> Result<()> {
+        refresh_epoch_gauge_voter::handler(ctx)
+    }
+
     /// Sets the vote of a [Gauge].
     #[access_control(ctx.accounts.validate())]
     pub fn gauge_set_vote(ctx: Context<GaugeSetVote>, weight: u32) -> Result<()> {
         gauge_set_vote::handler(ctx, weight)
     }
 
+    /// Sets all of the votes of a [GaugeVoter] at once.
+    #[access_control(ctx.accounts.validate())]
+    pub fn gauge_set_votes<'info>(
+        ctx: Context<'_, '_, '_, 'info, GaugeSetVotes<'info>>,
+        weights: Vec<u32>,
+    ) -> Result<()> {
+        gauge_set_votes::handler(ctx, weights)
+    }
+
     /// Commits the vote of a [Gauge].
     /// Anyone can call this on any voter's gauge votes.
     #[deprecated(note = "Use `gauge_commit_vote_v2` instead.")]
     #[access_control(ctx.accounts.validate())]
-    pub fn gauge_commit_vote(ctx: Context<GaugeCommitVote>, _vote_bump: u8) -> Result<()> {
+    pub fn gauge_commit_vote<'info>(
+        ctx: Context<'_, '_, '_, 'info, GaugeCommitVote<'info>>,
+        _vote_bump: u8,
+    ) -> Result<()> {
         gauge_commit_vote::handler(ctx)
     }
 
@@ -153,11 +217,59 @@ pub mod gauge {
     /// Anyone can call this on any voter's gauge votes.
     ///
     /// The V2 variant removes the need to supply a bump seed.
+    ///
+    /// The accounts of the [TipVault] may be passed as remaining accounts to receive a tip.
+    /// Tips for vote commits are limited per epoch like those of other cranks.
     #[access_control(ctx.accounts.validate())]
-    pub fn gauge_commit_vote_v2(ctx: Context<GaugeCommitVote>) -> Result<()> {
+    pub fn gauge_commit_vote_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, GaugeCommitVote<'info>>,
+    ) -> Result<()> {
         gauge_commit_vote::handler(ctx)
     }
 
+    /// Commits all of the votes of a [GaugeVoter] at once.
+    /// Anyone can call this on any voter's gauge votes.
+    #[access_control(ctx.accounts.validate())]
+    pub fn gauge_commit_votes<'info>(
+        ctx: Context<'_, '_, '_, 'info, GaugeCommitVotes<'info>>,
+    ) -> Result<()> {
+        gauge_commit_votes::handler(ctx)
+    }
+
+    /// Opts a [GaugeVoter] in or out of having its votes rolled forward every epoch.
+    /// Only the owner or vote delegate of the [locked_voter::Escrow], or the
+    /// [GaugeVoter::gauge_delegate], may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_persistent_votes(
+        ctx: Context<SetPersistentVotes>,
+        is_persistent: bool,
+    ) -> Result<()> {
+        set_persistent_votes::handler(ctx, is_persistent)
+    }
+
+    /// Sets the account which may manage the gauge votes of a [GaugeVoter],
+    /// separately from the vote delegate of its [locked_voter::Escrow].
+    /// Only the [GaugeVoter::owner] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_gauge_delegate(
+        ctx: Context<SetGaugeDelegate>,
+        gauge_delegate: Pubkey,
+        expires_epoch: u32,
+    ) -> Result<()> {
+        set_gauge_delegate::handler(ctx, gauge_delegate, expires_epoch)
+    }
+
+    /// Prepares the [EpochGaugeVoter] of a persistent [GaugeVoter] for the current voting epoch
+    /// and commits all of its votes. Anyone can call this.
+    ///
+    /// Votes for disabled or inactive [Gauge]s are skipped.
+    #[access_control(ctx.accounts.validate())]
+    pub fn roll_forward_votes<'info>(
+        ctx: Context<'_, '_, '_, 'info, RollForwardVotes<'info>>,
+    ) -> Result<()> {
+        roll_forward_votes::handler(ctx)
+    }
+
     /// Reverts a vote commitment of a [Gauge].
     /// Only the voter can call this.
     #[access_control(ctx.accounts.validate())]
@@ -165,50 +277,317 @@ pub mod gauge {
         gauge_revert_vote::handler(ctx)
     }
 
+    /// Releases power committed to a disabled or inactive [Gauge] in the current voting epoch
+    /// back to the [EpochGaugeVoter], so that it may be committed elsewhere. Permissionless.
+    ///
+    /// To recommit the power in the same epoch, the voter reverts its other votes, sets the
+    /// weight of the [GaugeVote] to zero, and resets the [EpochGaugeVoter] before committing
+    /// again.
+    #[access_control(ctx.accounts.validate())]
+    pub fn release_gauge_vote(ctx: Context<ReleaseGaugeVote>) -> Result<()> {
+        release_gauge_vote::handler(ctx)
+    }
+
     /// Enables a [Gauge].
+    /// Only the [Gaugemeister::curator] may call this.
     #[access_control(ctx.accounts.validate())]
     pub fn gauge_enable(ctx: Context<GaugeEnable>) -> Result<()> {
         gauge_enable::handler(ctx)
     }
 
     /// Disables a [Gauge].
+    /// Only the [Gaugemeister::curator] or [Gaugemeister::guardian] may call this.
     #[access_control(ctx.accounts.validate())]
     pub fn gauge_disable(ctx: Context<GaugeDisable>) -> Result<()> {
         gauge_disable::handler(ctx)
     }
 
     /// Triggers the next epoch. Permissionless.
+    ///
+    /// The accounts of the [TipVault] may be passed as remaining accounts to receive a tip.
     #[access_control(ctx.accounts.validate())]
-    pub fn trigger_next_epoch(ctx: Context<TriggerNextEpoch>) -> Result<()> {
+    pub fn trigger_next_epoch<'info>(
+        ctx: Context<'_, '_, '_, 'info, TriggerNextEpoch<'info>>,
+    ) -> Result<()> {
         trigger_next_epoch::handler(ctx)
     }
 
     /// Synchronizes the [quarry_mine::Quarry] with the relevant [EpochGauge]. Permissionless.
+    ///
+    /// The accounts of the [TipVault] may be passed as remaining accounts to receive a tip
+    /// if the rewards share changes.
     #[access_control(ctx.accounts.validate())]
-    pub fn sync_gauge(ctx: Context<SyncGauge>) -> Result<()> {
+    pub fn sync_gauge<'info>(ctx: Context<'_, '_, '_, 'info, SyncGauge<'info>>) -> Result<()> {
         sync_gauge::handler(ctx)
     }
 
+    /// Synchronizes many [quarry_mine::Quarry]s with their [EpochGauge]s,
+    /// setting the rewards of disabled gauges to zero. Permissionless.
+    #[access_control(ctx.accounts.validate())]
+    pub fn sync_gauges<'info>(ctx: Context<'_, '_, '_, 'info, SyncGauges<'info>>) -> Result<()> {
+        sync_gauges::handler(ctx)
+    }
+
+    /// Sets the epochs in which a [Gauge] may be voted on and receive rewards.
+    /// Only the [Gaugemeister::curator] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_gauge_schedule(
+        ctx: Context<SetGaugeSchedule>,
+        activation_epoch: u32,
+        sunset_epoch: u32,
+    ) -> Result<()> {
+        set_gauge_schedule::handler(ctx, activation_epoch, sunset_epoch)
+    }
+
+    /// Disables a [Gauge] which has reached its [Gauge::sunset_epoch]
+    /// and sets its [quarry_mine::Quarry] rewards to zero. Permissionless.
+    #[access_control(ctx.accounts.validate())]
+    pub fn sunset_gauge(ctx: Context<SunsetGauge>) -> Result<()> {
+        sunset_gauge::handler(ctx)
+    }
+
     /// Sets the [quarry_mine::Quarry] rewards to zero if the gauge is disabled. Permissionless.
     #[access_control(ctx.accounts.validate())]
     pub fn sync_disabled_gauge(ctx: Context<SyncDisabledGauge>) -> Result<()> {
         sync_disabled_gauge::handler(ctx)
     }
 
-    /// Sets new parameters on the [Gaugemeister].
+    /// Sets the [quarry_mine::Quarry] rewards to the [Gauge::floor_bps] and its share of the
+    /// foreman slice, which are usually zero, if no [EpochGauge] exists for the current
+    /// rewards epoch. Permissionless.
+    #[access_control(ctx.accounts.validate())]
+    pub fn sync_unvoted_gauge(ctx: Context<SyncUnvotedGauge>) -> Result<()> {
+        sync_unvoted_gauge::handler(ctx)
+    }
+
+    /// Caps an [EpochGauge] of the current rewards epoch which has more than its
+    /// maximum share of the total power, after the excess of already capped
+    /// [EpochGauge]s has been redistributed. Permissionless.
+    ///
+    /// This must be called on every such [EpochGauge] before it can be synced.
+    #[access_control(ctx.accounts.validate())]
+    pub fn cap_epoch_gauge(ctx: Context<CapEpochGauge>) -> Result<()> {
+        cap_epoch_gauge::handler(ctx)
+    }
+
+    /// Drops an [EpochGauge] of the current rewards epoch which is below the minimum
+    /// power, so that its power is excluded from the power distributed by votes.
+    /// Permissionless.
+    ///
+    /// This must be called on every such [EpochGauge] before it can be synced.
+    #[access_control(ctx.accounts.validate())]
+    pub fn drop_epoch_gauge(ctx: Context<DropEpochGauge>) -> Result<()> {
+        drop_epoch_gauge::handler(ctx)
+    }
+
+    /// Proposes new parameters on the [Gaugemeister], keeping the current
+    /// [Gaugemeister::params_change_delay_seconds].
     /// Only the [Gaugemeister::foreman] may call this.
+    #[deprecated(note = "Use `propose_gaugemeister_params` instead.")]
     #[access_control(ctx.accounts.validate())]
     pub fn set_gaugemeister_params(
         ctx: Context<SetGaugemeisterParams>,
         new_epoch_duration_seconds: u32,
         new_foreman: Pubkey,
     ) -> Result<()> {
-        set_gaugemeister_params::handler(ctx, new_epoch_duration_seconds, new_foreman)
+        let params_change_delay_seconds = ctx.accounts.gaugemeister.params_change_delay_seconds;
+        set_gaugemeister_params::handler(
+            ctx,
+            new_epoch_duration_seconds,
+            new_foreman,
+            params_change_delay_seconds,
+        )
     }
 
-    /// Closes an [EpochGaugeVote], sending lamports to a user-specified address.
-    ///
-    /// Only the [locked_voter::Escrow::vote_delegate] may call this.
+    /// Proposes new parameters on the [Gaugemeister].
+    /// The parameters may be accepted by the new foreman after [Gaugemeister::params_change_delay_seconds].
+    /// Only the [Gaugemeister::foreman] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn propose_gaugemeister_params(
+        ctx: Context<SetGaugemeisterParams>,
+        new_epoch_duration_seconds: u32,
+        new_foreman: Pubkey,
+        new_params_change_delay_seconds: u32,
+    ) -> Result<()> {
+        set_gaugemeister_params::handler(
+            ctx,
+            new_epoch_duration_seconds,
+            new_foreman,
+            new_params_change_delay_seconds,
+        )
+    }
+
+    /// Accepts the pending parameters on the [Gaugemeister].
+    /// Only the [Gaugemeister::pending_foreman] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn accept_gaugemeister_params(ctx: Context<AcceptGaugemeisterParams>) -> Result<()> {
+        accept_gaugemeister_params::handler(ctx)
+    }
+
+    /// Cancels the pending parameters on the [Gaugemeister].
+    /// Only the [Gaugemeister::foreman] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn cancel_gaugemeister_params(ctx: Context<CancelGaugemeisterParams>) -> Result<()> {
+        cancel_gaugemeister_params::handler(ctx)
+    }
+
+    /// Sets the [Gaugemeister::curator].
+    /// Only the [Gaugemeister::foreman] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_curator(ctx: Context<SetCurator>, new_curator: Pubkey) -> Result<()> {
+        set_curator::handler(ctx, new_curator)
+    }
+
+    /// Sets the [Gaugemeister::guardian].
+    /// Only the [Gaugemeister::foreman] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_guardian(ctx: Context<SetGuardian>, new_guardian: Pubkey) -> Result<()> {
+        set_guardian::handler(ctx, new_guardian)
+    }
+
+    /// Pauses the [Gaugemeister].
+    /// Only the [Gaugemeister::guardian] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn pause_gaugemeister(ctx: Context<PauseGaugemeister>) -> Result<()> {
+        pause_gaugemeister::handler(ctx)
+    }
+
+    /// Unpauses the [Gaugemeister].
+    /// Only the [Gaugemeister::guardian] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn unpause_gaugemeister(ctx: Context<UnpauseGaugemeister>) -> Result<()> {
+        unpause_gaugemeister::handler(ctx)
+    }
+
+    /// Proposes whether the [Gaugemeister] follows a fixed epoch schedule.
+    /// The schedule changes once the pending parameters are accepted with
+    /// [gauge::accept_gaugemeister_params], after [Gaugemeister::params_change_delay_seconds].
+    /// Only the [Gaugemeister::foreman] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_fixed_epoch_schedule(
+        ctx: Context<SetFixedEpochSchedule>,
+        fixed_epoch_schedule: bool,
+    ) -> Result<()> {
+        set_fixed_epoch_schedule::handler(ctx, fixed_epoch_schedule)
+    }
+
+    /// Sets whether the [Gaugemeister] normalizes rewards shares by the total power of the epoch.
+    /// Only the [Gaugemeister::foreman] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_normalize_rewards_shares(
+        ctx: Context<SetNormalizeRewardsShares>,
+        normalize_rewards_shares: bool,
+    ) -> Result<()> {
+        set_normalize_rewards_shares::handler(ctx, normalize_rewards_shares)
+    }
+
+    /// Sets the maximum share of the total power of an epoch that a [Gauge] may receive.
+    /// Only the [Gaugemeister::foreman] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_max_gauge_share_bps(
+        ctx: Context<SetMaxGaugeShareBps>,
+        max_gauge_share_bps: u16,
+    ) -> Result<()> {
+        set_max_gauge_share_bps::handler(ctx, max_gauge_share_bps)
+    }
+
+    /// Overrides the maximum share of a [Gauge].
+    /// Only the [Gaugemeister::foreman] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_gauge_max_share_bps(
+        ctx: Context<SetGaugeMaxShareBps>,
+        max_share_bps: u16,
+    ) -> Result<()> {
+        set_gauge_max_share_bps::handler(ctx, max_share_bps)
+    }
+
+    /// Sets the minimum power, absolute and as a share of the total power of an epoch,
+    /// that a [Gauge] needs to receive rewards.
+    /// Only the [Gaugemeister::foreman] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_min_gauge_power(
+        ctx: Context<SetMinGaugePower>,
+        min_gauge_power: u64,
+        min_gauge_share_bps: u16,
+    ) -> Result<()> {
+        set_min_gauge_power::handler(ctx, min_gauge_power, min_gauge_share_bps)
+    }
+
+    /// Guarantees a [Gauge] a share of the rewards of every epoch, regardless of its votes.
+    /// Only the [Gaugemeister::foreman] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_gauge_floor_bps(ctx: Context<SetGaugeFloorBps>, floor_bps: u16) -> Result<()> {
+        set_gauge_floor_bps::handler(ctx, floor_bps)
+    }
+
+    /// Sets the share of the rewards of every epoch that the [Gaugemeister::foreman]
+    /// distributes directly through [EpochGaugeAllocation]s.
+    /// Only the [Gaugemeister::foreman] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_foreman_slice_bps(
+        ctx: Context<SetForemanSliceBps>,
+        foreman_slice_bps: u16,
+    ) -> Result<()> {
+        set_foreman_slice_bps::handler(ctx, foreman_slice_bps)
+    }
+
+    /// Sets the weight of a [Gauge] in the foreman slice of the current voting epoch.
+    /// Only the [Gaugemeister::foreman] or the smart wallet of the
+    /// [Gaugemeister::locker_governor] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_epoch_gauge_allocation(
+        ctx: Context<SetEpochGaugeAllocation>,
+        weight: u32,
+    ) -> Result<()> {
+        set_epoch_gauge_allocation::handler(ctx, weight)
+    }
+
+    /// Assigns a [Gauge] to a group. This applies to [EpochGauge]s created afterwards.
+    /// Only the [Gaugemeister::foreman] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_gauge_group(ctx: Context<SetGaugeGroup>, group: u8) -> Result<()> {
+        set_gauge_group::handler(ctx, group)
+    }
+
+    /// Sets the budget weight of a [Gauge] group. This applies to [EpochGaugemeister]s
+    /// created afterwards. Only the [Gaugemeister::foreman] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_gauge_group_budget(
+        ctx: Context<SetGaugeGroupBudget>,
+        group: u8,
+        budget_weight: u32,
+    ) -> Result<()> {
+        set_gauge_group_budget::handler(ctx, group, budget_weight)
+    }
+
+    /// Creates the [TipVault] which pays tips to the signers of crank instructions.
+    /// Only the [Gaugemeister::foreman] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_tip_vault(ctx: Context<CreateTipVault>, mint: Pubkey) -> Result<()> {
+        create_tip_vault::handler(ctx, mint)
+    }
+
+    /// Sets the tip paid per crank instruction and the maximum number of tips
+    /// per epoch for each kind of crank instruction.
+    /// Only the [Gaugemeister::foreman] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_tip_params(
+        ctx: Context<SetTipParams>,
+        tip_amount: u64,
+        max_tips_per_epoch: u32,
+    ) -> Result<()> {
+        set_tip_params::handler(ctx, tip_amount, max_tips_per_epoch)
+    }
+
+    /// Withdraws funds from the [TipVault].
+    /// Only the [Gaugemeister::foreman] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn withdraw_tips(ctx: Context<WithdrawTips>, amount: u64) -> Result<()> {
+        withdraw_tips::handler(ctx, amount)
+    }
+
+    /// Closes an [EpochGaugeVote] of a past epoch, refunding rent to the [EpochGaugeVote::rent_payer].
+    /// Anyone can call this.
     #[access_control(ctx.accounts.validate())]
     pub fn close_epoch_gauge_vote(
         ctx: Context<CloseEpochGaugeVote>,
@@ -216,6 +595,154 @@ pub mod gauge {
     ) -> Result<()> {
         instructions::close_epoch_gauge_vote::handler(ctx, voting_epoch)
     }
+
+    /// Closes an [EpochGauge] of a past epoch, refunding rent to the [EpochGauge::rent_payer].
+    /// Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn close_epoch_gauge(ctx: Context<CloseEpochGauge>) -> Result<()> {
+        instructions::close_epoch_gauge::handler(ctx)
+    }
+
+    /// Closes an [EpochGaugemeister] of a past epoch, refunding rent to the
+    /// [EpochGaugemeister::rent_payer]. Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn close_epoch_gaugemeister(ctx: Context<CloseEpochGaugemeister>) -> Result<()> {
+        instructions::close_epoch_gaugemeister::handler(ctx)
+    }
+
+    /// Closes an [EpochGaugeAllocation] of a past epoch, refunding rent to the
+    /// [EpochGaugeAllocation::rent_payer]. Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn close_epoch_gauge_allocation(ctx: Context<CloseEpochGaugeAllocation>) -> Result<()> {
+        instructions::close_epoch_gauge_allocation::handler(ctx)
+    }
+
+    /// Creates an [EpochGaugeIncentive] rewarding the voters of a [Gauge] in a voting epoch.
+    /// Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_epoch_gauge_incentive(ctx: Context<CreateEpochGaugeIncentive>) -> Result<()> {
+        instructions::create_epoch_gauge_incentive::handler(ctx)
+    }
+
+    /// Deposits tokens into an [EpochGaugeIncentive] before voting on its epoch ends.
+    /// Only the [EpochGaugeIncentive::depositor] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn deposit_incentive(ctx: Context<DepositIncentive>, amount: u64) -> Result<()> {
+        instructions::deposit_incentive::handler(ctx, amount)
+    }
+
+    /// Claims the share of an [EpochGaugeIncentive] owed to a [GaugeVoter].
+    /// Only the [GaugeVoter::owner] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn claim_incentive(ctx: Context<ClaimIncentive>) -> Result<()> {
+        instructions::claim_incentive::handler(ctx)
+    }
+
+    /// Returns the unclaimed tokens of an [EpochGaugeIncentive] after its claim window,
+    /// closing it. Only the [EpochGaugeIncentive::depositor] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn reclaim_incentive(ctx: Context<ReclaimIncentive>) -> Result<()> {
+        instructions::reclaim_incentive::handler(ctx)
+    }
+
+    /// Closes an [IncentiveClaim] after its claim window, refunding rent to the
+    /// [IncentiveClaim::rent_payer]. Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn close_incentive_claim(ctx: Context<CloseIncentiveClaim>) -> Result<()> {
+        instructions::close_incentive_claim::handler(ctx)
+    }
+
+    /// Closes an [EpochGaugeVoter] of a past epoch, refunding rent to the [EpochGaugeVoter::rent_payer].
+    /// Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn close_epoch_gauge_voter(ctx: Context<CloseEpochGaugeVoter>) -> Result<()> {
+        instructions::close_epoch_gauge_voter::handler(ctx)
+    }
+
+    /// Closes a [GaugeVote] with zero weight, sending lamports to a user-specified address.
+    /// All of its [EpochGaugeVote]s must have been closed or reverted first.
+    ///
+    /// Only the owner or vote delegate of the [locked_voter::Escrow] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn close_gauge_vote(ctx: Context<CloseGaugeVote>) -> Result<()> {
+        instructions::close_gauge_vote::handler(ctx)
+    }
+
+    /// Closes a [GaugeVoter] with zero weight and no committed power,
+    /// sending lamports to a user-specified address.
+    /// All of its [EpochGaugeVoter]s and [EpochGaugeVote]s must have been closed first.
+    ///
+    /// Only the owner or vote delegate of the [locked_voter::Escrow] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn close_gauge_voter(ctx: Context<CloseGaugeVoter>) -> Result<()> {
+        instructions::close_gauge_voter::handler(ctx)
+    }
+
+    /// Migrates a [Gaugemeister] created with the legacy layout, setting the
+    /// [Gaugemeister::curator] and [Gaugemeister::guardian] to the foreman.
+    /// Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn migrate_gaugemeister(ctx: Context<MigrateGaugemeister>) -> Result<()> {
+        instructions::migrate_gaugemeister::handler(ctx)
+    }
+
+    /// Migrates a [Gauge] created with the legacy layout. Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn migrate_gauge(ctx: Context<MigrateGauge>) -> Result<()> {
+        instructions::migrate_gauge::handler(ctx)
+    }
+
+    /// Migrates a [GaugeVoter] created with the legacy layout. Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn migrate_gauge_voter(ctx: Context<MigrateGaugeVoter>) -> Result<()> {
+        instructions::migrate_gauge_voter::handler(ctx)
+    }
+
+    /// Migrates a [GaugeVote] created with the legacy layout. Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn migrate_gauge_vote(ctx: Context<MigrateGaugeVote>) -> Result<()> {
+        instructions::migrate_gauge_vote::handler(ctx)
+    }
+
+    /// Migrates an [EpochGauge] created with the legacy layout, refunding its rent
+    /// to the [Gaugemeister::foreman] once closed. Its power is counted in the
+    /// [EpochGaugemeister] of its epoch if it exists. Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn migrate_epoch_gauge(ctx: Context<MigrateEpochGauge>) -> Result<()> {
+        instructions::migrate_epoch_gauge::handler(ctx)
+    }
+
+    /// Migrates an [EpochGaugeVoter] created with the legacy layout, refunding its rent
+    /// to the [GaugeVoter::owner] once closed. It is counted in the
+    /// [EpochGaugemeister] of its epoch if it exists. Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn migrate_epoch_gauge_voter(ctx: Context<MigrateEpochGaugeVoter>) -> Result<()> {
+        instructions::migrate_epoch_gauge_voter::handler(ctx)
+    }
+
+    /// Migrates an [EpochGaugeVote] of `voting_epoch` created with the legacy layout,
+    /// so that it can be reverted or closed, refunding its rent to the [GaugeVoter::owner].
+    /// Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn migrate_epoch_gauge_vote(
+        ctx: Context<MigrateEpochGaugeVote>,
+        voting_epoch: u32,
+    ) -> Result<()> {
+        instructions::migrate_epoch_gauge_vote::handler(ctx, voting_epoch)
+    }
+
+    /// Migrates an [EpochGaugemeister] created with the legacy layout, snapshotting
+    /// the current [Gaugemeister::group_budget_weights]. Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn migrate_epoch_gaugemeister(ctx: Context<MigrateEpochGaugemeister>) -> Result<()> {
+        instructions::migrate_epoch_gaugemeister::handler(ctx)
+    }
+
+    /// Migrates a [TipVault] created with the legacy layout. Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn migrate_tip_vault(ctx: Context<MigrateTipVault>) -> Result<()> {
+        instructions::migrate_tip_vault::handler(ctx)
+    }
 }
 
 /// Errors.
@@ -247,6 +774,88 @@ pub enum ErrorCode {
     AllocatedPowerMustBeZero,
     #[msg("The epoch in which you are closing an account for has not yet elapsed.")]
     CloseEpochNotElapsed,
-    #[msg("You must be the vote delegate of the escrow to perform this action.")]
+    #[msg(
+        "You must be the vote delegate of the escrow or the gauge delegate to perform this action."
+    )]
     UnauthorizedNotDelegate,
+    #[msg("There is no pending parameter change.")]
+    NoPendingParams,
+    #[msg("The pending parameter change may not be accepted yet.")]
+    ParamsChangeNotExecutable,
+    #[msg("You must be the pending foreman to perform this action.")]
+    UnauthorizedNotPendingForeman,
+    #[msg("You must be the curator to perform this action.")]
+    UnauthorizedNotCurator,
+    #[msg("You must be the guardian to perform this action.")]
+    UnauthorizedNotGuardian,
+    #[msg("The Gaugemeister is paused.")]
+    GaugemeisterPaused,
+    #[msg("The remaining accounts must be passed in groups of the expected size.")]
+    InvalidRemainingAccounts,
+    #[msg("Gauge votes must be sorted by address.")]
+    GaugeVotesNotSorted,
+    #[msg(
+        "The weights of the gauge votes provided do not add up to the total weight of the voter."
+    )]
+    GaugeVotesIncomplete,
+    #[msg("You must be the owner or vote delegate of the escrow, or the gauge delegate, to perform this action.")]
+    UnauthorizedNotVoterAuthority,
+    #[msg("The gauge vote must have zero weight.")]
+    GaugeVoteWeightMustBeZero,
+    #[msg("The gauge voter must have zero total weight.")]
+    GaugeVoterTotalWeightMustBeZero,
+    #[msg("The epoch gauge exists, so the gauge must be synced with sync_gauge.")]
+    EpochGaugeExists,
+    #[msg("Basis points may not exceed 10,000.")]
+    InvalidBps,
+    #[msg("The gauge is over its maximum share and must be capped with cap_epoch_gauge before syncing.")]
+    GaugeCapNotApplied,
+    #[msg("The epoch gauge has already been capped.")]
+    EpochGaugeAlreadyCapped,
+    #[msg("The epoch gauge is not over its maximum share.")]
+    EpochGaugeNotOverCap,
+    #[msg("The gauge is below the minimum power and must be dropped with drop_epoch_gauge before syncing.")]
+    GaugeThresholdNotApplied,
+    #[msg("The epoch gauge has already been dropped.")]
+    EpochGaugeAlreadyDropped,
+    #[msg("The epoch gauge is not below the minimum power.")]
+    EpochGaugeNotBelowThreshold,
+    #[msg("The gauge group does not exist.")]
+    InvalidGaugeGroup,
+    #[msg("The gauge is not active in the current voting epoch.")]
+    GaugeNotActive,
+    #[msg("The sunset epoch must be after the activation epoch.")]
+    InvalidGaugeSchedule,
+    #[msg("The gauge has not reached its sunset epoch.")]
+    GaugeNotSunset,
+    #[msg("The gauge must be disabled or inactive to release its votes.")]
+    GaugeNotDisabled,
+    #[msg("The gauge voter has not opted in to persistent votes.")]
+    GaugeVoterNotPersistent,
+    #[msg("The recipient of a crank tip must sign the transaction.")]
+    TipRecipientMustSign,
+    #[msg("Invalid token account for the tip vault.")]
+    InvalidTipVaultTokenAccount,
+    #[msg("The tip vault does not hold enough funds.")]
+    InsufficientTipVaultBalance,
+    #[msg("Incentives may only be deposited before voting on the epoch has ended.")]
+    IncentiveDepositsClosed,
+    #[msg(
+        "Incentives may only be claimed after voting has ended and before the claim window ends."
+    )]
+    IncentiveClaimsNotOpen,
+    #[msg("Incentives may still be claimed.")]
+    IncentiveClaimsOpen,
+    #[msg("Invalid token account for the incentive.")]
+    InvalidIncentiveTokenAccount,
+    #[msg("You must be the owner of the gauge voter to perform this action.")]
+    UnauthorizedNotGaugeVoterOwner,
+    #[msg("The epoch accounts of this voter must be closed first.")]
+    EpochAccountsMustBeClosed,
+    #[msg("The account does not have a legacy layout which can be migrated.")]
+    AccountNotLegacy,
+    #[msg("You must be the curator or the guardian to perform this action.")]
+    UnauthorizedNotCuratorOrGuardian,
+    #[msg("You must be the foreman or the smart wallet of the governor to perform this action.")]
+    UnauthorizedNotForemanOrGovernor,
 }
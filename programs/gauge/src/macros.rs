@@ -7,3 +7,30 @@ macro_rules! gaugemeister_seeds {
         &[&[b"Gaugemeister" as &[u8], &$gm.base.to_bytes(), &[$gm.bump]]]
     };
 }
+
+/// Generates the signer seeds for an EpochGaugeIncentive.
+#[macro_export]
+macro_rules! epoch_gauge_incentive_seeds {
+    ($egi: expr) => {
+        &[&[
+            b"EpochGaugeIncentive" as &[u8],
+            &$egi.gauge.to_bytes(),
+            &$egi.voting_epoch.to_le_bytes(),
+            &$egi.depositor.to_bytes(),
+            &$egi.mint.to_bytes(),
+            &[$egi.bump],
+        ]]
+    };
+}
+
+/// Generates the signer seeds for a TipVault.
+#[macro_export]
+macro_rules! tip_vault_seeds {
+    ($tv: expr) => {
+        &[&[
+            b"TipVault" as &[u8],
+            &$tv.gaugemeister.to_bytes(),
+            &[$tv.bump],
+        ]]
+    };
+}
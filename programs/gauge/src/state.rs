@@ -1,9 +1,31 @@
 //! Struct definitions for accounts that hold state.
+//!
+//! Fields are only ever appended to the layout of an account. When the length of an
+//! account which may already exist on chain changes, the previous length is kept as a
+//! `LEGACY_LEN` and a `migrate_*` instruction reallocates existing accounts in place.
 
 use anchor_lang::solana_program::pubkey::PUBKEY_BYTES;
 
 use crate::*;
 
+/// The sum of the rewards shares of all [Gauge]s in an epoch
+/// if [Gaugemeister::normalize_rewards_shares] is set.
+pub const NORMALIZED_REWARDS_SHARES_TOTAL: u64 = 1_000_000_000;
+
+/// The number of basis points in 100%.
+pub const BPS_DENOMINATOR: u16 = 10_000;
+
+/// The maximum number of [Gauge] groups of a [Gaugemeister].
+pub const MAX_GAUGE_GROUPS: usize = 8;
+
+/// The number of rewards epochs, starting from the voting epoch of an [EpochGaugeIncentive],
+/// in which voters may claim their share of it.
+pub const INCENTIVE_CLAIM_EPOCHS: u32 = 4;
+
+/// The number of kinds of crank instructions counted in [TipVault::tips_paid_in_epoch].
+/// Kinds added since are counted in fields appended to the [TipVault].
+pub const NUM_CRANK_KINDS: usize = 3;
+
 /// Manages the rewards shares of all [Gauge]s of a [quarry_mine::rewarder].
 #[account]
 #[derive(Copy, Debug, Default)]
@@ -20,10 +42,25 @@ pub struct Gaugemeister {
     /// The [locked_voter::Locker].
     pub locker: Pubkey,
 
-    /// Account which may enable/disable gauges on the [Gaugemeister].
+    /// Account which administers the parameters and roles of the [Gaugemeister].
     /// May call the following instructions:
-    /// - gauge_enable
-    /// - gauge_disable
+    /// - propose_gaugemeister_params
+    /// - cancel_gaugemeister_params
+    /// - set_fixed_epoch_schedule
+    /// - set_normalize_rewards_shares
+    /// - set_max_gauge_share_bps
+    /// - set_gauge_max_share_bps
+    /// - set_min_gauge_power
+    /// - set_gauge_floor_bps
+    /// - set_foreman_slice_bps
+    /// - set_epoch_gauge_allocation
+    /// - set_gauge_group
+    /// - set_gauge_group_budget
+    /// - set_curator
+    /// - set_guardian
+    /// - create_tip_vault
+    /// - set_tip_params
+    /// - withdraw_tips
     pub foreman: Pubkey,
     /// Number of seconds per rewards epoch.
     /// This may be modified later.
@@ -37,19 +74,336 @@ pub struct Gaugemeister {
 
     /// Token mint. Unused but useful for frontends.
     pub locker_token_mint: Pubkey,
-    /// Governor associated with the Locker. Unused but useful for frontends.
+    /// Governor associated with the Locker. Its smart wallet may allocate the
+    /// foreman slice alongside the [Self::foreman].
     pub locker_governor: Pubkey,
+
+    /// If true, epochs start at fixed multiples of [Self::epoch_duration_seconds]
+    /// after the previously scheduled epoch start, rather than relative to when
+    /// [gauge::trigger_next_epoch] was called.
+    /// If epochs were missed, the next epoch still starts on the schedule but lasts
+    /// until the next scheduled start, so the votes of the voting epoch are never orphaned.
+    pub fixed_epoch_schedule: bool,
+
+    /// Number of seconds that a proposed parameter change must wait
+    /// before it may be accepted.
+    pub params_change_delay_seconds: u32,
+    /// The proposed [Self::foreman]. This account must sign to accept the pending parameters.
+    pub pending_foreman: Pubkey,
+    /// The proposed [Self::epoch_duration_seconds].
+    pub pending_epoch_duration_seconds: u32,
+    /// The proposed [Self::params_change_delay_seconds].
+    pub pending_params_change_delay_seconds: u32,
+    /// When the pending parameters may be accepted.
+    /// If zero, there is no pending parameter change.
+    pub pending_params_executable_at: u64,
+
+    /// Account which may enable/disable gauges on the [Gaugemeister].
+    /// May call the following instructions:
+    /// - gauge_enable
+    /// - gauge_disable
+    /// - set_gauge_schedule
+    pub curator: Pubkey,
+    /// Account which may take emergency actions on the [Gaugemeister].
+    /// May call the following instructions:
+    /// - gauge_disable
+    /// - pause_gaugemeister
+    /// - unpause_gaugemeister
+    pub guardian: Pubkey,
+    /// If true, votes may not be changed or committed, gauges may not be synced,
+    /// and epochs may not be advanced.
+    /// Reverts and account closes are still allowed.
+    pub is_paused: bool,
+
+    /// If true, rewards shares are a fraction of the total power committed in the epoch,
+    /// scaled to [NORMALIZED_REWARDS_SHARES_TOTAL], rather than the raw committed power.
+    pub normalize_rewards_shares: bool,
+
+    /// The maximum share of the total power of an epoch that a [Gauge] may receive,
+    /// in basis points. This may be overridden by [Gauge::max_share_bps].
+    /// If zero, [Gauge]s are uncapped.
+    pub max_gauge_share_bps: u16,
+
+    /// [Gauge]s with less committed power than this in an epoch receive no rewards.
+    pub min_gauge_power: u64,
+    /// [Gauge]s with less than this share of the total power of an epoch, in basis points,
+    /// receive no rewards.
+    pub min_gauge_share_bps: u16,
+
+    /// The sum of [Gauge::floor_bps] of all enabled [Gauge]s.
+    /// This share of the rewards is reserved for floors rather than distributed by votes.
+    pub total_floor_bps: u16,
+
+    /// The share of the rewards of every epoch, in basis points, which is distributed
+    /// by the [Self::foreman] through [EpochGaugeAllocation]s rather than by votes.
+    pub foreman_slice_bps: u16,
+
+    /// The budget weight of each [Gauge] group, indexed by [Gauge::group].
+    /// Votes only decide the shares of [Gauge]s within their group, and each group
+    /// receives its weight out of the sum of all weights.
+    /// If all weights are zero, [Gauge] groups are not used.
+    pub group_budget_weights: [u32; MAX_GAUGE_GROUPS],
+
+    /// The proposed [Self::fixed_epoch_schedule].
+    pub pending_fixed_epoch_schedule: bool,
 }
 
 impl Gaugemeister {
     /// Length of a [Gaugemeister] in bytes.
-    pub const LEN: usize = PUBKEY_BYTES + 1 + PUBKEY_BYTES * 4 + 4 + 4 + 8 + PUBKEY_BYTES * 2;
+    pub const LEN: usize = PUBKEY_BYTES
+        + 1
+        + PUBKEY_BYTES * 4
+        + 4
+        + 4
+        + 8
+        + PUBKEY_BYTES * 2
+        + 1
+        + 4
+        + PUBKEY_BYTES
+        + 4
+        + 4
+        + 8
+        + PUBKEY_BYTES * 2
+        + 1
+        + 1
+        + 2
+        + 8
+        + 2
+        + 2
+        + 2
+        + 4 * MAX_GAUGE_GROUPS
+        + 1;
+
+    /// Length of a [Gaugemeister] created before its layout was extended, which must be migrated.
+    pub const LEGACY_LEN: usize =
+        PUBKEY_BYTES + 1 + PUBKEY_BYTES * 4 + 4 + 4 + 8 + PUBKEY_BYTES * 2;
 
     /// Fetches the current voting epoch. This is always the epoch after [Self::current_rewards_epoch].
     pub fn voting_epoch(&self) -> Result<u32> {
         let voting_epoch = unwrap_int!(self.current_rewards_epoch.checked_add(1));
         Ok(voting_epoch)
     }
+
+    /// Returns true if the [EpochGaugeIncentive]s of `voting_epoch` may be claimed.
+    /// Claims open once voting has ended and last for [INCENTIVE_CLAIM_EPOCHS].
+    pub fn incentive_claims_open(&self, voting_epoch: u32) -> bool {
+        self.current_rewards_epoch >= voting_epoch && !self.incentive_claims_ended(voting_epoch)
+    }
+
+    /// Returns true if the claim window of the [EpochGaugeIncentive]s of `voting_epoch` has ended.
+    pub fn incentive_claims_ended(&self, voting_epoch: u32) -> bool {
+        self.current_rewards_epoch >= voting_epoch.saturating_add(INCENTIVE_CLAIM_EPOCHS)
+    }
+
+    /// Returns true if there is a parameter change awaiting acceptance.
+    pub fn has_pending_params(&self) -> bool {
+        self.pending_params_executable_at != 0
+    }
+
+    /// Computes the rewards epoch to enter if the next epoch is triggered at `now`,
+    /// along with the time at which the epoch after it starts.
+    ///
+    /// The rewards epoch always advances by one, into the epoch that was being voted on.
+    /// If [Self::fixed_epoch_schedule] is set, the start of the epoch after it is re-anchored
+    /// to the first scheduled start after `now`, skipping the starts that were missed.
+    pub fn next_epoch_at(&self, now: u64) -> Option<(u32, u64)> {
+        let duration: u64 = self.epoch_duration_seconds.into();
+        if !self.fixed_epoch_schedule || duration == 0 {
+            return Some((
+                self.current_rewards_epoch.checked_add(1)?,
+                now.checked_add(duration)?,
+            ));
+        }
+
+        let missed_epochs = now
+            .checked_sub(self.next_epoch_starts_at)?
+            .checked_div(duration)?;
+        let elapsed_epochs = missed_epochs.checked_add(1)?;
+        let next_epoch_starts_at = self
+            .next_epoch_starts_at
+            .checked_add(elapsed_epochs.checked_mul(duration)?)?;
+        Some((
+            self.current_rewards_epoch.checked_add(1)?,
+            next_epoch_starts_at,
+        ))
+    }
+
+    /// Scales power committed in an epoch to a [quarry_mine::Quarry::rewards_share].
+    pub fn scale_power(&self, gauge_power: u64, epoch_total_power: u64) -> Option<u64> {
+        if !self.normalize_rewards_shares {
+            return Some(gauge_power);
+        }
+        if epoch_total_power == 0 {
+            return Some(0);
+        }
+        ::u128::mul_div_u64(
+            gauge_power,
+            NORMALIZED_REWARDS_SHARES_TOTAL,
+            epoch_total_power,
+        )
+    }
+
+    /// The sum of all rewards shares of an epoch with the given total power.
+    pub fn total_rewards_shares(&self, epoch_total_power: u64) -> u64 {
+        if self.normalize_rewards_shares {
+            NORMALIZED_REWARDS_SHARES_TOTAL
+        } else {
+            epoch_total_power
+        }
+    }
+
+    /// The share of the rewards, in basis points, which is not distributed by votes.
+    pub fn reserved_bps(&self) -> u16 {
+        self.total_floor_bps.saturating_add(self.foreman_slice_bps)
+    }
+
+    /// The sum of the vote-driven rewards shares of all [EpochGauge]s of an epoch,
+    /// against which the floors and the foreman slice are sized.
+    ///
+    /// Caps, dropped [EpochGauge]s and group budgets can make this differ from the
+    /// total power of the epoch. If nothing is distributed by votes, the total
+    /// power of the epoch is used instead.
+    pub fn total_vote_shares(&self, epoch_gaugemeister: &EpochGaugemeister) -> Option<u64> {
+        let total_power = epoch_gaugemeister.total_power;
+        let distributed_power = if epoch_gaugemeister.has_gauge_groups() {
+            // Every group with power distributes its part of the total power.
+            if epoch_gaugemeister.funded_group_budget_weight() == 0 {
+                0
+            } else {
+                total_power
+            }
+        } else {
+            epoch_gaugemeister.distributed_power()?
+        };
+        if distributed_power == 0 {
+            return Some(self.total_rewards_shares(total_power));
+        }
+        self.scale_power(distributed_power, total_power)
+    }
+
+    /// Applies the [Gauge::floor_bps] to the vote-driven rewards share of a [Gauge].
+    ///
+    /// Vote-driven shares are reduced by [Self::reserved_bps] so that the floors and the
+    /// foreman slice are taken out of every [Gauge] proportionally. Floors are sized
+    /// against the [Self::total_vote_shares]. Returns the new rewards share and the part
+    /// of it which comes from the floor.
+    pub fn apply_floor(
+        &self,
+        gauge: &Gauge,
+        vote_share: u64,
+        total_vote_shares: u64,
+    ) -> Option<(u64, u64)> {
+        if self.reserved_bps() == 0 {
+            return Some((vote_share, 0));
+        }
+        let bps_denominator: u64 = BPS_DENOMINATOR.into();
+        let floor_share =
+            ::u128::mul_div_u64(total_vote_shares, gauge.floor_bps.into(), bps_denominator)?;
+        let vote_share = ::u128::mul_div_u64(
+            vote_share,
+            bps_denominator.checked_sub(self.reserved_bps().into())?,
+            bps_denominator,
+        )?;
+        Some((vote_share.checked_add(floor_share)?, floor_share))
+    }
+
+    /// Computes the part of the [Self::foreman_slice_bps] that goes to a [Gauge]
+    /// with the given [EpochGaugeAllocation::weight].
+    ///
+    /// Like the floors, the slice is sized against the [Self::total_vote_shares].
+    pub fn foreman_share(
+        &self,
+        allocation_weight: u32,
+        epoch_gaugemeister: &EpochGaugemeister,
+        total_vote_shares: u64,
+    ) -> Option<u64> {
+        let total_allocation_weight = epoch_gaugemeister.total_allocation_weight;
+        if self.foreman_slice_bps == 0 || total_allocation_weight == 0 {
+            return Some(0);
+        }
+        let slice = ::u128::mul_div_u64(
+            total_vote_shares,
+            self.foreman_slice_bps.into(),
+            BPS_DENOMINATOR.into(),
+        )?;
+        ::u128::mul_div_u64(slice, allocation_weight.into(), total_allocation_weight)
+    }
+
+    /// The sum of [Self::group_budget_weights].
+    pub fn total_group_budget_weight(&self) -> u64 {
+        self.group_budget_weights
+            .iter()
+            .map(|w| u64::from(*w))
+            .sum()
+    }
+
+    /// Returns true if rewards are split between [Gauge] groups.
+    ///
+    /// This only applies to epochs whose [EpochGaugemeister] is created afterwards.
+    pub fn has_gauge_groups(&self) -> bool {
+        self.total_group_budget_weight() != 0
+    }
+
+    /// The maximum share of a [Gauge] in basis points, or zero if it is uncapped.
+    pub fn max_share_bps(&self, gauge: &Gauge) -> u16 {
+        if gauge.max_share_bps != 0 {
+            gauge.max_share_bps
+        } else {
+            self.max_gauge_share_bps
+        }
+    }
+
+    /// Returns true if `gauge_power` is more than the maximum share of the [Gauge].
+    ///
+    /// Without an epoch total, such as in an epoch without an [EpochGaugemeister],
+    /// there is nothing to take a share of, so no [Gauge] is over its cap.
+    pub fn is_over_cap(&self, gauge: &Gauge, gauge_power: u64, epoch_total_power: u64) -> bool {
+        let max_share_bps = self.max_share_bps(gauge);
+        max_share_bps != 0
+            && epoch_total_power != 0
+            && u128::from(gauge_power) * u128::from(BPS_DENOMINATOR)
+                > u128::from(max_share_bps) * u128::from(epoch_total_power)
+    }
+
+    /// Returns true if `gauge_power` is too small for the [Gauge] to receive rewards.
+    pub fn is_below_threshold(&self, gauge_power: u64, epoch_total_power: u64) -> bool {
+        gauge_power < self.min_gauge_power
+            || u128::from(gauge_power) * u128::from(BPS_DENOMINATOR)
+                < u128::from(self.min_gauge_share_bps) * u128::from(epoch_total_power)
+    }
+
+    /// Computes the [quarry_mine::Quarry::rewards_share] of the [Gauge] of an [EpochGauge].
+    ///
+    /// A capped [EpochGauge] receives exactly its cap of the total rewards. Uncapped gauges
+    /// keep a share proportional to their power, so the excess above the cap is
+    /// redistributed to them pro-rata.
+    pub fn rewards_share(
+        &self,
+        epoch_gauge: &EpochGauge,
+        epoch_gaugemeister: &EpochGaugemeister,
+    ) -> Option<u64> {
+        let total_power = epoch_gaugemeister.total_power;
+        if epoch_gauge.cap_bps == 0 {
+            return self.scale_power(epoch_gauge.total_power, total_power);
+        }
+
+        let uncapped_power = total_power.checked_sub(epoch_gaugemeister.capped_power)?;
+        let capped_bps: u64 = epoch_gaugemeister.capped_bps.into();
+        let bps_denominator: u64 = BPS_DENOMINATOR.into();
+        if uncapped_power == 0 || capped_bps >= bps_denominator {
+            // Every gauge is capped, so rewards are split by cap.
+            let capped_power =
+                ::u128::mul_div_u64(total_power, epoch_gauge.cap_bps.into(), bps_denominator)?;
+            return self.scale_power(capped_power, total_power);
+        }
+
+        let uncapped_share = self.scale_power(uncapped_power, total_power)?;
+        ::u128::mul_div_u64(
+            uncapped_share,
+            epoch_gauge.cap_bps.into(),
+            bps_denominator.checked_sub(capped_bps)?,
+        )
+    }
 }
 
 /// A [Gauge] determines the rewards shares to give to a [quarry_mine::Quarry].
@@ -63,11 +417,38 @@ pub struct Gauge {
     /// If true, this Gauge cannot receive any more votes
     /// and rewards shares cannot be synchronized from it.
     pub is_disabled: bool,
+    /// Overrides [Gaugemeister::max_gauge_share_bps] for this [Gauge] if non-zero.
+    pub max_share_bps: u16,
+    /// The minimum share of the rewards of every epoch that this [Gauge] receives,
+    /// in basis points, regardless of votes.
+    pub floor_bps: u16,
+    /// The index of the group of this [Gauge] in [Gaugemeister::group_budget_weights].
+    pub group: u8,
+    /// The first epoch in which this [Gauge] may be voted on and receive rewards.
+    /// If zero, the [Gauge] is active immediately.
+    pub activation_epoch: u32,
+    /// The first epoch in which this [Gauge] may no longer be voted on or receive rewards.
+    /// Once this is the rewards epoch, anyone may disable the [Gauge] with [gauge::sunset_gauge].
+    /// If zero, the [Gauge] never sunsets.
+    pub sunset_epoch: u32,
 }
 
 impl Gauge {
     /// Length of a [Gauge] in bytes.
-    pub const LEN: usize = PUBKEY_BYTES * 2 + 1;
+    pub const LEN: usize = PUBKEY_BYTES * 2 + 1 + 2 + 2 + 1 + 4 + 4;
+
+    /// Length of a [Gauge] created before its layout was extended, which must be migrated.
+    pub const LEGACY_LEN: usize = PUBKEY_BYTES * 2 + 1;
+
+    /// Returns true if the [Gauge] may be voted on and receive rewards in `epoch`.
+    pub fn is_active_in(&self, epoch: u32) -> bool {
+        epoch >= self.activation_epoch && (self.sunset_epoch == 0 || epoch < self.sunset_epoch)
+    }
+
+    /// Returns true if the [Gauge] should be disabled in `rewards_epoch`.
+    pub fn is_sunset(&self, rewards_epoch: u32) -> bool {
+        self.sunset_epoch != 0 && rewards_epoch >= self.sunset_epoch
+    }
 }
 
 /// A [GaugeVoter] represents an [locked_voter::Escrow] that can vote on gauges.
@@ -91,11 +472,47 @@ pub struct GaugeVoter {
     /// 2. The [Self::weight_change_seqno] gets written to [EpochGaugeVoter::weight_change_seqno].
     /// 3. In [gauge::gauge_commit_vote], if the [Self::weight_change_seqno] has changed, the transaction is blocked with a [crate::ErrorCode::WeightSeqnoChanged] error.
     pub weight_change_seqno: u64,
+
+    /// If true, anyone may commit the votes of this [GaugeVoter] for every new voting epoch
+    /// with [gauge::roll_forward_votes], using its current [GaugeVote] weights.
+    pub is_persistent: bool,
+
+    /// Account which may manage the gauge votes of this [GaugeVoter] in addition to
+    /// the vote delegate of the Escrow, set by the owner with [gauge::set_gauge_delegate].
+    /// This is the default address if there is no gauge delegate.
+    pub gauge_delegate: Pubkey,
+    /// The last voting epoch in which the [Self::gauge_delegate] may manage votes.
+    /// If zero, the [Self::gauge_delegate] does not expire.
+    pub gauge_delegate_expires_epoch: u32,
+
+    /// The number of [EpochGaugeVoter]s and [EpochGaugeVote]s of this [GaugeVoter]
+    /// which have not been closed. The [GaugeVoter] may only be closed once this is zero,
+    /// so that the rent of past epochs can still be refunded and incentives claimed.
+    pub open_epoch_accounts: u32,
 }
 
 impl GaugeVoter {
     /// Length of a [GaugeVoter] in bytes.
-    pub const LEN: usize = PUBKEY_BYTES * 3 + 4 + 8;
+    pub const LEN: usize = PUBKEY_BYTES * 3 + 4 + 8 + 1 + PUBKEY_BYTES + 4 + 4;
+
+    /// Length of a [GaugeVoter] created before its layout was extended, which must be migrated.
+    pub const LEGACY_LEN: usize = PUBKEY_BYTES * 3 + 4 + 8;
+
+    /// Returns true if `authority` is the unexpired [Self::gauge_delegate] in `voting_epoch`.
+    pub fn is_gauge_delegate(&self, authority: &Pubkey, voting_epoch: u32) -> bool {
+        self.gauge_delegate != Pubkey::default()
+            && self.gauge_delegate == *authority
+            && (self.gauge_delegate_expires_epoch == 0
+                || voting_epoch <= self.gauge_delegate_expires_epoch)
+    }
+
+    /// The part of `voting_power` committed by a [GaugeVote] with the given [GaugeVote::weight].
+    pub fn power_for_weight(&self, voting_power: u64, weight: u32) -> Option<u64> {
+        if weight == 0 {
+            return Some(0);
+        }
+        ::u128::mul_div_u64(voting_power, weight.into(), self.total_weight.into())
+    }
 }
 
 /// A [GaugeVote] is a user's vote for a given [Gauge].
@@ -109,11 +526,32 @@ pub struct GaugeVote {
 
     /// Proportion of votes that the voter is applying to this gauge.
     pub weight: u32,
+
+    /// The number of [EpochGaugeVote]s of this [GaugeVote] which have not been closed.
+    /// The [GaugeVote] may only be closed once this is zero.
+    pub open_epoch_votes: u32,
 }
 
 impl GaugeVote {
     /// Length of a [GaugeVote] in bytes.
-    pub const LEN: usize = PUBKEY_BYTES * 2 + 4;
+    pub const LEN: usize = PUBKEY_BYTES * 2 + 4 + 4;
+
+    /// Length of a [GaugeVote] created before its layout was extended, which must be migrated.
+    pub const LEGACY_LEN: usize = PUBKEY_BYTES * 2 + 4;
+
+    /// Records that an [EpochGaugeVote] of this [GaugeVote] and its [GaugeVoter] was created.
+    pub fn open_epoch_vote(&mut self, gauge_voter: &mut GaugeVoter) -> Option<()> {
+        self.open_epoch_votes = self.open_epoch_votes.checked_add(1)?;
+        gauge_voter.open_epoch_accounts = gauge_voter.open_epoch_accounts.checked_add(1)?;
+        Some(())
+    }
+
+    /// Records that an [EpochGaugeVote] of this [GaugeVote] and its [GaugeVoter] was closed.
+    pub fn close_epoch_vote(&mut self, gauge_voter: &mut GaugeVoter) -> Option<()> {
+        self.open_epoch_votes = self.open_epoch_votes.checked_sub(1)?;
+        gauge_voter.open_epoch_accounts = gauge_voter.open_epoch_accounts.checked_sub(1)?;
+        Some(())
+    }
 }
 
 /// An [EpochGauge] is a [Gauge]'s total committed votes for a given epoch.
@@ -136,11 +574,34 @@ pub struct EpochGauge {
     /// The total number of power to be applied to the latest voted epoch.
     /// If this number is non-zero, vote weights cannot be changed until they are all withdrawn.
     pub total_power: u64,
+    /// The account that paid the rent for this [EpochGauge].
+    /// Rent is refunded to this account when the [EpochGauge] is closed.
+    pub rent_payer: Pubkey,
+    /// The maximum share of this [EpochGauge] in basis points, set by [gauge::cap_epoch_gauge].
+    /// If zero, the [EpochGauge] is uncapped.
+    pub cap_bps: u16,
+    /// The [Gauge::group] at the time the [EpochGauge] was created.
+    pub group: u8,
+    /// The number of [EpochGaugeIncentive]s created for this [EpochGauge].
+    /// If non-zero, the [EpochGauge] and its [EpochGaugeVote]s may not be closed
+    /// until the incentive claim window has ended.
+    pub num_incentives: u32,
+    /// If true, the [EpochGauge] was below the minimum power and was dropped by
+    /// [gauge::drop_epoch_gauge], so it receives no vote-driven rewards.
+    pub is_dropped: bool,
 }
 
 impl EpochGauge {
     /// Length of an [EpochGauge] in bytes.
-    pub const LEN: usize = PUBKEY_BYTES + 4 + 8;
+    pub const LEN: usize = PUBKEY_BYTES + 4 + 8 + PUBKEY_BYTES + 2 + 1 + 4 + 1;
+
+    /// Length of an [EpochGauge] created before its layout was extended, which must be migrated.
+    pub const LEGACY_LEN: usize = PUBKEY_BYTES + 4 + 8;
+
+    /// Returns true if the [EpochGauge] must be kept so voters can claim its incentives.
+    pub fn has_open_incentives(&self, gaugemeister: &Gaugemeister) -> bool {
+        self.num_incentives != 0 && !gaugemeister.incentive_claims_ended(self.voting_epoch)
+    }
 }
 
 /// An [EpochGaugeVoter] is a [GaugeVoter]'s total committed votes for a
@@ -161,11 +622,17 @@ pub struct EpochGaugeVoter {
     /// The total amount of gauge voting power that has been allocated.
     /// If this number is non-zero, vote weights cannot be changed until they are all withdrawn.
     pub allocated_power: u64,
+    /// The account that paid the rent for this [EpochGaugeVoter].
+    /// Rent is refunded to this account when the [EpochGaugeVoter] is closed.
+    pub rent_payer: Pubkey,
 }
 
 impl EpochGaugeVoter {
     /// Length of an [EpochGaugeVoter] in bytes.
-    pub const LEN: usize = PUBKEY_BYTES + 4 + 8 * 3;
+    pub const LEN: usize = PUBKEY_BYTES + 4 + 8 * 3 + PUBKEY_BYTES;
+
+    /// Length of an [EpochGaugeVoter] created before its layout was extended, which must be migrated.
+    pub const LEGACY_LEN: usize = PUBKEY_BYTES + 4 + 8 * 3;
 }
 
 /// An [EpochGaugeVote] is a user's committed votes for a given [Gauge] at a given epoch.
@@ -187,11 +654,17 @@ pub struct EpochGaugeVote {
     /// vote_power_at_expiry * (weight / total_weight)
     /// ```
     pub allocated_power: u64,
+    /// The account that paid the rent for this [EpochGaugeVote].
+    /// Rent is refunded to this account when the [EpochGaugeVote] is closed.
+    pub rent_payer: Pubkey,
 }
 
 impl EpochGaugeVote {
     /// Length of an [EpochGaugeVote] in bytes.
-    pub const LEN: usize = 8;
+    pub const LEN: usize = 8 + PUBKEY_BYTES;
+
+    /// Length of an [EpochGaugeVote] created before its layout was extended, which must be migrated.
+    pub const LEGACY_LEN: usize = 8;
 }
 
 impl EpochGaugeVote {
@@ -205,6 +678,414 @@ impl EpochGaugeVote {
     }
 }
 
+/// An [EpochGaugemeister] is the total committed votes across all [Gauge]s
+/// of a [Gaugemeister] for a given epoch.
+///
+/// Seeds:
+/// ```text
+/// [
+///     b"EpochGaugemeister".as_ref(),
+///     gaugemeister.key().as_ref(),
+///     voting_epoch.to_le_bytes().as_ref()
+/// ],
+/// ```
+#[account]
+#[derive(Copy, Debug, Default)]
+pub struct EpochGaugemeister {
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    /// The epoch associated with this [EpochGaugemeister].
+    pub voting_epoch: u32,
+    /// The total power committed to all [EpochGauge]s of the epoch.
+    pub total_power: u64,
+    /// The number of [EpochGauge]s with non-zero power.
+    pub num_gauges_voted: u32,
+    /// The number of [EpochGaugeVoter]s with non-zero allocated power.
+    pub num_voters: u32,
+    /// The total power of all capped and dropped [EpochGauge]s.
+    pub capped_power: u64,
+    /// The sum of [EpochGauge::cap_bps] of all capped [EpochGauge]s.
+    pub capped_bps: u32,
+    /// The sum of [EpochGaugeAllocation::weight] of all [EpochGaugeAllocation]s of the epoch.
+    pub total_allocation_weight: u64,
+    /// The total power committed to the [EpochGauge]s of each [Gauge] group,
+    /// excluding dropped [EpochGauge]s.
+    pub group_power: [u64; MAX_GAUGE_GROUPS],
+    /// The account that paid the rent of this [EpochGaugemeister].
+    pub rent_payer: Pubkey,
+    /// The [Gaugemeister::group_budget_weights] when this [EpochGaugemeister] was created,
+    /// so that budget changes only apply from the next voting epoch.
+    pub group_budget_weights: [u32; MAX_GAUGE_GROUPS],
+}
+
+impl EpochGaugemeister {
+    /// Length of an [EpochGaugemeister] in bytes.
+    pub const LEN: usize = PUBKEY_BYTES
+        + 4
+        + 8
+        + 4
+        + 4
+        + 8
+        + 4
+        + 8
+        + 8 * MAX_GAUGE_GROUPS
+        + PUBKEY_BYTES
+        + 4 * MAX_GAUGE_GROUPS;
+
+    /// Length of an [EpochGaugemeister] created before its layout was extended, which must be migrated.
+    pub const LEGACY_LEN: usize =
+        PUBKEY_BYTES + 4 + 8 + 4 + 4 + 8 + 4 + 8 + 8 * MAX_GAUGE_GROUPS + PUBKEY_BYTES;
+
+    /// Returns true if the rewards of the epoch are split between [Gauge] groups.
+    pub fn has_gauge_groups(&self) -> bool {
+        self.group_budget_weights.iter().any(|w| *w != 0)
+    }
+
+    /// The sum of [Self::group_budget_weights] of the groups which received power.
+    pub fn funded_group_budget_weight(&self) -> u64 {
+        self.group_budget_weights
+            .iter()
+            .zip(self.group_power.iter())
+            .filter(|(_, group_power)| **group_power != 0)
+            .map(|(w, _)| u64::from(*w))
+            .sum()
+    }
+
+    /// Scales the vote-driven rewards share of an [EpochGauge] from a share of the
+    /// power of the whole epoch to a share of the budget of the group of the [EpochGauge].
+    ///
+    /// Budgets are renormalized over the groups which received power, so the budget
+    /// of a group without power goes to the other groups.
+    pub fn apply_group_budget(&self, epoch_gauge: &EpochGauge, vote_share: u64) -> Option<u64> {
+        if !self.has_gauge_groups() {
+            return Some(vote_share);
+        }
+        let total_budget_weight = self.funded_group_budget_weight();
+        let group = usize::from(epoch_gauge.group);
+        let budget_weight = *self.group_budget_weights.get(group)?;
+        let group_power = *self.group_power.get(group)?;
+        if budget_weight == 0 || group_power == 0 {
+            return Some(0);
+        }
+        let group_share = ::u128::mul_div_u64(vote_share, self.total_power, group_power)?;
+        ::u128::mul_div_u64(group_share, budget_weight.into(), total_budget_weight)
+    }
+
+    /// The power which is distributed by votes once capped and dropped [EpochGauge]s
+    /// have been accounted for. The share of an uncapped [EpochGauge] is measured
+    /// against this total.
+    ///
+    /// Redistribution can push an uncapped [EpochGauge] over its cap, so caps are
+    /// checked against this total until no [EpochGauge] is over its cap.
+    pub fn distributed_power(&self) -> Option<u64> {
+        if self.capped_power == 0 {
+            return Some(self.total_power);
+        }
+        let capped_bps: u64 = self.capped_bps.into();
+        let bps_denominator: u64 = BPS_DENOMINATOR.into();
+        let uncapped_power = self.total_power.checked_sub(self.capped_power)?;
+        if uncapped_power == 0 || capped_bps >= bps_denominator {
+            // Capped gauges receive their cap of the total power.
+            let capped_shares = ::u128::mul_div_u64(self.total_power, capped_bps, bps_denominator)?;
+            return capped_shares.checked_add(uncapped_power);
+        }
+        ::u128::mul_div_u64(
+            uncapped_power,
+            bps_denominator,
+            bps_denominator.checked_sub(capped_bps)?,
+        )
+    }
+
+    /// Removes the power of a dropped [EpochGauge] from the power distributed by votes.
+    pub fn drop_power(&mut self, epoch_gauge: &EpochGauge) -> Option<()> {
+        self.capped_power = self.capped_power.checked_add(epoch_gauge.total_power)?;
+        let group_power = self.group_power.get_mut(usize::from(epoch_gauge.group))?;
+        *group_power = group_power.checked_sub(epoch_gauge.total_power)?;
+        Some(())
+    }
+
+    /// Commits `power` from an [EpochGaugeVoter] to an [EpochGauge],
+    /// updating all three totals and the power of the group of the [EpochGauge].
+    pub fn commit_power(
+        &mut self,
+        epoch_gauge: &mut EpochGauge,
+        epoch_voter: &mut EpochGaugeVoter,
+        power: u64,
+    ) -> Option<()> {
+        if power == 0 {
+            return Some(());
+        }
+        if epoch_gauge.total_power == 0 {
+            self.num_gauges_voted = self.num_gauges_voted.checked_add(1)?;
+        }
+        if epoch_voter.allocated_power == 0 {
+            self.num_voters = self.num_voters.checked_add(1)?;
+        }
+        epoch_gauge.total_power = epoch_gauge.total_power.checked_add(power)?;
+        epoch_voter.allocated_power = epoch_voter.allocated_power.checked_add(power)?;
+        self.total_power = self.total_power.checked_add(power)?;
+        let group_power = self.group_power.get_mut(usize::from(epoch_gauge.group))?;
+        *group_power = group_power.checked_add(power)?;
+        Some(())
+    }
+
+    /// Reverts `power` committed from an [EpochGaugeVoter] to an [EpochGauge],
+    /// updating all three totals and the power of the group of the [EpochGauge].
+    pub fn revert_power(
+        &mut self,
+        epoch_gauge: &mut EpochGauge,
+        epoch_voter: &mut EpochGaugeVoter,
+        power: u64,
+    ) -> Option<()> {
+        if power == 0 {
+            return Some(());
+        }
+        epoch_gauge.total_power = epoch_gauge.total_power.checked_sub(power)?;
+        epoch_voter.allocated_power = epoch_voter.allocated_power.checked_sub(power)?;
+        self.total_power = self.total_power.checked_sub(power)?;
+        let group_power = self.group_power.get_mut(usize::from(epoch_gauge.group))?;
+        *group_power = group_power.checked_sub(power)?;
+        if epoch_gauge.total_power == 0 {
+            self.num_gauges_voted = self.num_gauges_voted.checked_sub(1)?;
+        }
+        if epoch_voter.allocated_power == 0 {
+            self.num_voters = self.num_voters.checked_sub(1)?;
+        }
+        Some(())
+    }
+
+    /// Counts the power committed to a legacy [EpochGauge] before this
+    /// [EpochGaugemeister] existed, so that it can be reverted.
+    pub fn count_legacy_epoch_gauge(&mut self, epoch_gauge: &EpochGauge) -> Option<()> {
+        if epoch_gauge.total_power == 0 {
+            return Some(());
+        }
+        self.num_gauges_voted = self.num_gauges_voted.checked_add(1)?;
+        self.total_power = self.total_power.checked_add(epoch_gauge.total_power)?;
+        let group_power = self.group_power.get_mut(usize::from(epoch_gauge.group))?;
+        *group_power = group_power.checked_add(epoch_gauge.total_power)?;
+        Some(())
+    }
+
+    /// Counts a legacy [EpochGaugeVoter] which allocated power before this
+    /// [EpochGaugemeister] existed.
+    pub fn count_legacy_epoch_gauge_voter(&mut self, epoch_voter: &EpochGaugeVoter) -> Option<()> {
+        if epoch_voter.allocated_power != 0 {
+            self.num_voters = self.num_voters.checked_add(1)?;
+        }
+        Some(())
+    }
+}
+
+/// An [EpochGaugeAllocation] is the part of the [Gaugemeister::foreman_slice_bps]
+/// that the [Gaugemeister::foreman] allocates to a [Gauge] for a given epoch.
+///
+/// Seeds:
+/// ```text
+/// [
+///     b"EpochGaugeAllocation".as_ref(),
+///     gauge.key().as_ref(),
+///     voting_epoch.to_le_bytes().as_ref()
+/// ],
+/// ```
+#[account]
+#[derive(Copy, Debug, Default)]
+pub struct EpochGaugeAllocation {
+    /// The [Gauge].
+    pub gauge: Pubkey,
+    /// The epoch associated with this [EpochGaugeAllocation].
+    pub voting_epoch: u32,
+    /// Proportion of the foreman slice allocated to the [Gauge].
+    pub weight: u32,
+    /// The account that paid the rent for this [EpochGaugeAllocation].
+    /// Rent is refunded to this account when the [EpochGaugeAllocation] is closed.
+    pub rent_payer: Pubkey,
+}
+
+impl EpochGaugeAllocation {
+    /// Length of an [EpochGaugeAllocation] in bytes.
+    pub const LEN: usize = PUBKEY_BYTES + 4 + 4 + PUBKEY_BYTES;
+
+    /// Finds the address of an [EpochGaugeAllocation] for a given [Gauge] and voting epoch.
+    pub fn find_program_address(gauge: &Pubkey, voting_epoch: u32) -> (Pubkey, u8) {
+        let epoch_bytes = voting_epoch.to_le_bytes();
+        Pubkey::find_program_address(
+            &[
+                b"EpochGaugeAllocation",
+                gauge.as_ref(),
+                epoch_bytes.as_ref(),
+            ],
+            &crate::ID,
+        )
+    }
+}
+
+/// A [TipVault] pays a bounty to the signers of permissionless crank instructions
+/// of a [Gaugemeister], such as [gauge::trigger_next_epoch] and [gauge::sync_gauge].
+///
+/// Tips are paid in SOL held by the [TipVault] itself if [TipVault::mint] is the default
+/// address, or in tokens held by [TipVault::token_account] otherwise. Anyone may fund it.
+///
+/// Seeds:
+/// ```text
+/// [
+///     b"TipVault".as_ref(),
+///     gaugemeister.key().as_ref()
+/// ],
+/// ```
+#[account]
+#[derive(Copy, Debug, Default)]
+pub struct TipVault {
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    /// Bump seed.
+    pub bump: u8,
+
+    /// The mint of the tips, or the default address if tips are paid in SOL.
+    pub mint: Pubkey,
+    /// The token account, owned by this [TipVault], which holds the tips.
+    /// This is the default address if tips are paid in SOL.
+    pub token_account: Pubkey,
+
+    /// The tip paid for each eligible crank instruction.
+    pub tip_amount: u64,
+    /// The maximum number of tips paid for each `CrankKind` in a single rewards epoch.
+    /// Each `CrankKind` has its own budget, so busy cranks cannot starve the others.
+    pub max_tips_per_epoch: u32,
+
+    /// The rewards epoch of [Self::tips_paid_in_epoch].
+    pub tips_epoch: u32,
+    /// The number of tips paid in [Self::tips_epoch], indexed by `CrankKind`.
+    pub tips_paid_in_epoch: [u32; NUM_CRANK_KINDS],
+    /// The total amount of tips ever paid.
+    pub total_tips_paid: u64,
+    /// The number of tips paid for `CrankKind::CommitVote` in [Self::tips_epoch].
+    pub commit_vote_tips_paid_in_epoch: u32,
+}
+
+impl TipVault {
+    /// Length of a [TipVault] in bytes.
+    pub const LEN: usize =
+        PUBKEY_BYTES + 1 + PUBKEY_BYTES * 2 + 8 + 4 + 4 + 4 * NUM_CRANK_KINDS + 8 + 4;
+
+    /// Length of a [TipVault] created before its layout was extended, which must be migrated.
+    pub const LEGACY_LEN: usize =
+        PUBKEY_BYTES + 1 + PUBKEY_BYTES * 2 + 8 + 4 + 4 + 4 * NUM_CRANK_KINDS + 8;
+
+    /// Returns true if tips are paid in SOL.
+    pub fn is_sol(&self) -> bool {
+        self.mint == Pubkey::default()
+    }
+
+    /// Records a tip for a `CrankKind` paid in the rewards epoch `epoch`.
+    /// Returns false if no more tips may be paid for the `CrankKind` in the epoch.
+    pub fn record_tip(&mut self, epoch: u32, kind: CrankKind) -> Option<bool> {
+        if self.tips_epoch != epoch {
+            self.tips_epoch = epoch;
+            self.tips_paid_in_epoch = [0; NUM_CRANK_KINDS];
+            self.commit_vote_tips_paid_in_epoch = 0;
+        }
+        let tips_paid = match kind {
+            CrankKind::CommitVote => &mut self.commit_vote_tips_paid_in_epoch,
+            _ => self.tips_paid_in_epoch.get_mut(kind as usize)?,
+        };
+        if *tips_paid >= self.max_tips_per_epoch {
+            return Some(false);
+        }
+        *tips_paid = tips_paid.checked_add(1)?;
+        self.total_tips_paid = self.total_tips_paid.checked_add(self.tip_amount)?;
+        Some(true)
+    }
+}
+
+/// An [EpochGaugeIncentive] holds tokens deposited to reward the voters of a [Gauge]
+/// in a given voting epoch.
+///
+/// Once voting has ended, each voter may claim a share of the deposit proportional to
+/// [EpochGaugeVote::allocated_power] relative to [EpochGauge::total_power].
+/// Tokens which are unclaimed after [INCENTIVE_CLAIM_EPOCHS] may be reclaimed by the depositor.
+///
+/// Seeds:
+/// ```text
+/// [
+///     b"EpochGaugeIncentive".as_ref(),
+///     gauge.key().as_ref(),
+///     voting_epoch.to_le_bytes().as_ref(),
+///     depositor.key().as_ref(),
+///     mint.key().as_ref()
+/// ],
+/// ```
+#[account]
+#[derive(Copy, Debug, Default)]
+pub struct EpochGaugeIncentive {
+    /// The [Gauge].
+    pub gauge: Pubkey,
+    /// The voting epoch of the votes which are rewarded.
+    pub voting_epoch: u32,
+    /// The account which deposits and may reclaim the incentive.
+    pub depositor: Pubkey,
+    /// Bump seed.
+    pub bump: u8,
+
+    /// The mint of the incentive.
+    pub mint: Pubkey,
+    /// The token account, owned by this [EpochGaugeIncentive], which holds the incentive.
+    pub token_account: Pubkey,
+
+    /// The total amount deposited.
+    pub amount: u64,
+    /// The total amount claimed by voters.
+    pub claimed_amount: u64,
+}
+
+impl EpochGaugeIncentive {
+    /// Length of an [EpochGaugeIncentive] in bytes.
+    pub const LEN: usize = PUBKEY_BYTES + 4 + PUBKEY_BYTES + 1 + PUBKEY_BYTES * 2 + 8 + 8;
+
+    /// The amount of the incentive owed to a voter which allocated `allocated_power`
+    /// to an [EpochGauge] with `total_power`.
+    pub fn claim_amount(&self, allocated_power: u64, total_power: u64) -> Option<u64> {
+        if total_power == 0 {
+            return Some(0);
+        }
+        ::u128::mul_div_u64(self.amount, allocated_power, total_power)
+    }
+}
+
+/// An [IncentiveClaim] is a receipt recording that a [GaugeVoter] has claimed
+/// its share of an [EpochGaugeIncentive], preventing it from claiming twice.
+///
+/// Seeds:
+/// ```text
+/// [
+///     b"IncentiveClaim".as_ref(),
+///     epoch_gauge_incentive.key().as_ref(),
+///     gauge_voter.key().as_ref()
+/// ],
+/// ```
+#[account]
+#[derive(Copy, Debug, Default)]
+pub struct IncentiveClaim {
+    /// The [EpochGaugeIncentive].
+    pub epoch_gauge_incentive: Pubkey,
+    /// The [GaugeVoter] which claimed.
+    pub gauge_voter: Pubkey,
+    /// The [EpochGaugeIncentive::gauge].
+    pub gauge: Pubkey,
+    /// The [EpochGaugeIncentive::voting_epoch].
+    pub voting_epoch: u32,
+    /// The amount claimed.
+    pub amount: u64,
+    /// The account that paid the rent for this [IncentiveClaim].
+    /// Rent is refunded to this account when the [IncentiveClaim] is closed.
+    pub rent_payer: Pubkey,
+}
+
+impl IncentiveClaim {
+    /// Length of an [IncentiveClaim] in bytes.
+    pub const LEN: usize = PUBKEY_BYTES * 3 + 4 + 8 + PUBKEY_BYTES;
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -218,6 +1099,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_next_epoch_at_drifts_by_default() {
+        let gm = Gaugemeister {
+            epoch_duration_seconds: 100,
+            current_rewards_epoch: 3,
+            next_epoch_starts_at: 1_000,
+            ..Default::default()
+        };
+        assert_eq!(gm.next_epoch_at(1_030).unwrap(), (4, 1_130));
+    }
+
+    #[test]
+    fn test_next_epoch_at_fixed_schedule() {
+        let gm = Gaugemeister {
+            epoch_duration_seconds: 100,
+            current_rewards_epoch: 3,
+            next_epoch_starts_at: 1_000,
+            fixed_epoch_schedule: true,
+            ..Default::default()
+        };
+        // on time
+        assert_eq!(gm.next_epoch_at(1_000).unwrap(), (4, 1_100));
+        // late, but within the epoch
+        assert_eq!(gm.next_epoch_at(1_099).unwrap(), (4, 1_100));
+        // missed one epoch: the voting epoch is applied, the schedule is re-anchored
+        assert_eq!(gm.next_epoch_at(1_100).unwrap(), (4, 1_200));
+        // missed several epochs
+        assert_eq!(gm.next_epoch_at(1_350).unwrap(), (4, 1_400));
+        // not yet reached
+        assert!(gm.next_epoch_at(999).is_none());
+    }
+
     #[test]
     fn test_gauge_voter_len() {
         assert_eq!(
@@ -226,11 +1139,246 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_legacy_layouts_are_prefixes() {
+        fn assert_prefix<T: AnchorSerialize>(
+            account: &T,
+            legacy: impl AnchorSerialize,
+            len: usize,
+        ) {
+            let data = account.try_to_vec().unwrap();
+            let legacy = legacy.try_to_vec().unwrap();
+            assert_eq!(legacy.len(), len);
+            assert_eq!(&data[..len], legacy.as_slice());
+        }
+
+        let keys: Vec<Pubkey> = (0..6).map(|_| Pubkey::new_unique()).collect();
+        let gm = Gaugemeister {
+            base: keys[0],
+            bump: 254,
+            rewarder: keys[1],
+            operator: keys[2],
+            locker: keys[3],
+            foreman: keys[4],
+            epoch_duration_seconds: 7,
+            current_rewards_epoch: 9,
+            next_epoch_starts_at: 11,
+            locker_token_mint: keys[5],
+            locker_governor: keys[0],
+            is_paused: true,
+            ..Default::default()
+        };
+        assert_prefix(
+            &gm,
+            (
+                gm.base,
+                gm.bump,
+                gm.rewarder,
+                gm.operator,
+                gm.locker,
+                gm.foreman,
+                gm.epoch_duration_seconds,
+                gm.current_rewards_epoch,
+                gm.next_epoch_starts_at,
+                gm.locker_token_mint,
+                gm.locker_governor,
+            ),
+            Gaugemeister::LEGACY_LEN,
+        );
+
+        let gauge = Gauge {
+            gaugemeister: keys[0],
+            quarry: keys[1],
+            is_disabled: true,
+            floor_bps: 3,
+            ..Default::default()
+        };
+        assert_prefix(
+            &gauge,
+            (gauge.gaugemeister, gauge.quarry, gauge.is_disabled),
+            Gauge::LEGACY_LEN,
+        );
+
+        let voter = GaugeVoter {
+            gaugemeister: keys[0],
+            escrow: keys[1],
+            owner: keys[2],
+            total_weight: 5,
+            weight_change_seqno: 6,
+            is_persistent: true,
+            ..Default::default()
+        };
+        assert_prefix(
+            &voter,
+            (
+                voter.gaugemeister,
+                voter.escrow,
+                voter.owner,
+                voter.total_weight,
+                voter.weight_change_seqno,
+            ),
+            GaugeVoter::LEGACY_LEN,
+        );
+
+        let vote = GaugeVote {
+            gauge_voter: keys[0],
+            gauge: keys[1],
+            weight: 5,
+            open_epoch_votes: 2,
+        };
+        assert_prefix(
+            &vote,
+            (vote.gauge_voter, vote.gauge, vote.weight),
+            GaugeVote::LEGACY_LEN,
+        );
+
+        let epoch_gauge = EpochGauge {
+            gauge: keys[0],
+            voting_epoch: 3,
+            total_power: 4,
+            rent_payer: keys[1],
+            ..Default::default()
+        };
+        assert_prefix(
+            &epoch_gauge,
+            (
+                epoch_gauge.gauge,
+                epoch_gauge.voting_epoch,
+                epoch_gauge.total_power,
+            ),
+            EpochGauge::LEGACY_LEN,
+        );
+
+        let epoch_voter = EpochGaugeVoter {
+            gauge_voter: keys[0],
+            voting_epoch: 3,
+            weight_change_seqno: 4,
+            voting_power: 5,
+            allocated_power: 6,
+            rent_payer: keys[1],
+        };
+        assert_prefix(
+            &epoch_voter,
+            (
+                epoch_voter.gauge_voter,
+                epoch_voter.voting_epoch,
+                epoch_voter.weight_change_seqno,
+                epoch_voter.voting_power,
+                epoch_voter.allocated_power,
+            ),
+            EpochGaugeVoter::LEGACY_LEN,
+        );
+
+        let epoch_vote = EpochGaugeVote {
+            allocated_power: 7,
+            rent_payer: keys[0],
+        };
+        assert_prefix(
+            &epoch_vote,
+            epoch_vote.allocated_power,
+            EpochGaugeVote::LEGACY_LEN,
+        );
+
+        let tip_vault = TipVault {
+            gaugemeister: keys[0],
+            bump: 253,
+            mint: keys[1],
+            token_account: keys[2],
+            tip_amount: 3,
+            max_tips_per_epoch: 4,
+            tips_epoch: 5,
+            tips_paid_in_epoch: [6; NUM_CRANK_KINDS],
+            total_tips_paid: 7,
+            commit_vote_tips_paid_in_epoch: 8,
+        };
+        assert_prefix(
+            &tip_vault,
+            (
+                (
+                    tip_vault.gaugemeister,
+                    tip_vault.bump,
+                    tip_vault.mint,
+                    tip_vault.token_account,
+                ),
+                tip_vault.tip_amount,
+                tip_vault.max_tips_per_epoch,
+                tip_vault.tips_epoch,
+                tip_vault.tips_paid_in_epoch,
+                tip_vault.total_tips_paid,
+            ),
+            TipVault::LEGACY_LEN,
+        );
+
+        let epoch_gm = EpochGaugemeister {
+            gaugemeister: keys[0],
+            voting_epoch: 3,
+            total_power: 4,
+            group_power: [5; MAX_GAUGE_GROUPS],
+            rent_payer: keys[1],
+            group_budget_weights: [6; MAX_GAUGE_GROUPS],
+            ..Default::default()
+        };
+        assert_prefix(
+            &epoch_gm,
+            (
+                (
+                    epoch_gm.gaugemeister,
+                    epoch_gm.voting_epoch,
+                    epoch_gm.total_power,
+                    epoch_gm.num_gauges_voted,
+                    epoch_gm.num_voters,
+                    epoch_gm.capped_power,
+                    epoch_gm.capped_bps,
+                    epoch_gm.total_allocation_weight,
+                ),
+                epoch_gm.group_power,
+                epoch_gm.rent_payer,
+            ),
+            EpochGaugemeister::LEGACY_LEN,
+        );
+    }
+
     #[test]
     fn test_gauge_len() {
         assert_eq!(Gauge::default().try_to_vec().unwrap().len(), Gauge::LEN);
     }
 
+    #[test]
+    fn test_gauge_is_active_in() {
+        let gauge = Gauge {
+            activation_epoch: 3,
+            sunset_epoch: 6,
+            ..Default::default()
+        };
+        assert!(!gauge.is_active_in(2));
+        assert!(gauge.is_active_in(3));
+        assert!(gauge.is_active_in(5));
+        assert!(!gauge.is_active_in(6));
+        assert!(!gauge.is_sunset(5));
+        assert!(gauge.is_sunset(6));
+
+        let unscheduled = Gauge::default();
+        assert!(unscheduled.is_active_in(0));
+        assert!(unscheduled.is_active_in(u32::MAX));
+        assert!(!unscheduled.is_sunset(u32::MAX));
+    }
+
+    #[test]
+    fn test_gauge_voter_is_gauge_delegate() {
+        let delegate = Pubkey::new_unique();
+        let mut gauge_voter = GaugeVoter::default();
+        // no gauge delegate
+        assert!(!gauge_voter.is_gauge_delegate(&Pubkey::default(), 1));
+
+        gauge_voter.gauge_delegate = delegate;
+        assert!(gauge_voter.is_gauge_delegate(&delegate, 100));
+        assert!(!gauge_voter.is_gauge_delegate(&Pubkey::new_unique(), 100));
+
+        gauge_voter.gauge_delegate_expires_epoch = 5;
+        assert!(gauge_voter.is_gauge_delegate(&delegate, 5));
+        assert!(!gauge_voter.is_gauge_delegate(&delegate, 6));
+    }
+
     #[test]
     fn test_gauge_vote_len() {
         assert_eq!(
@@ -247,6 +1395,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_gauge_voter_power_for_weight() {
+        let gauge_voter = GaugeVoter {
+            total_weight: 3,
+            ..Default::default()
+        };
+        assert_eq!(gauge_voter.power_for_weight(600, 1).unwrap(), 200);
+        assert_eq!(gauge_voter.power_for_weight(100, 2).unwrap(), 66);
+        assert_eq!(gauge_voter.power_for_weight(300, 3).unwrap(), 300);
+
+        // after a released vote's weight is removed, the power goes to the other votes.
+        let released = GaugeVoter {
+            total_weight: 2,
+            ..gauge_voter
+        };
+        assert_eq!(released.power_for_weight(600, 1).unwrap(), 300);
+
+        let empty = GaugeVoter::default();
+        assert_eq!(empty.power_for_weight(1_000, 0).unwrap(), 0);
+    }
+
     #[test]
     fn test_epoch_gauge_len() {
         assert_eq!(
@@ -262,4 +1431,459 @@ mod tests {
             EpochGaugeVote::LEN
         );
     }
+
+    #[test]
+    fn test_epoch_gaugemeister_len() {
+        assert_eq!(
+            EpochGaugemeister::default().try_to_vec().unwrap().len(),
+            EpochGaugemeister::LEN
+        );
+    }
+
+    #[test]
+    fn test_epoch_gauge_allocation_len() {
+        assert_eq!(
+            EpochGaugeAllocation::default().try_to_vec().unwrap().len(),
+            EpochGaugeAllocation::LEN
+        );
+    }
+
+    #[test]
+    fn test_tip_vault_len() {
+        assert_eq!(
+            TipVault::default().try_to_vec().unwrap().len(),
+            TipVault::LEN
+        );
+    }
+
+    #[test]
+    fn test_tip_vault_record_tip() {
+        let mut tip_vault = TipVault {
+            tip_amount: 10,
+            max_tips_per_epoch: 2,
+            ..Default::default()
+        };
+        assert!(tip_vault.record_tip(1, CrankKind::SyncGauge).unwrap());
+        assert!(tip_vault.record_tip(1, CrankKind::SyncGauge).unwrap());
+        assert!(!tip_vault.record_tip(1, CrankKind::SyncGauge).unwrap());
+        assert_eq!(tip_vault.total_tips_paid, 20);
+
+        // syncs cannot starve the epoch trigger
+        assert!(tip_vault
+            .record_tip(1, CrankKind::TriggerNextEpoch)
+            .unwrap());
+        assert_eq!(tip_vault.tips_paid_in_epoch, [1, 0, 2]);
+
+        // vote commits have their own budget
+        assert!(tip_vault.record_tip(1, CrankKind::CommitVote).unwrap());
+        assert!(tip_vault.record_tip(1, CrankKind::CommitVote).unwrap());
+        assert!(!tip_vault.record_tip(1, CrankKind::CommitVote).unwrap());
+        assert_eq!(tip_vault.commit_vote_tips_paid_in_epoch, 2);
+        assert_eq!(tip_vault.tips_paid_in_epoch, [1, 0, 2]);
+
+        // the limit resets every epoch
+        assert!(tip_vault.record_tip(2, CrankKind::SyncGauge).unwrap());
+        assert_eq!(tip_vault.tips_paid_in_epoch, [0, 0, 1]);
+        assert_eq!(tip_vault.commit_vote_tips_paid_in_epoch, 0);
+        assert_eq!(tip_vault.total_tips_paid, 60);
+    }
+
+    #[test]
+    fn test_epoch_gauge_incentive_len() {
+        assert_eq!(
+            EpochGaugeIncentive::default().try_to_vec().unwrap().len(),
+            EpochGaugeIncentive::LEN
+        );
+    }
+
+    #[test]
+    fn test_incentive_claim_len() {
+        assert_eq!(
+            IncentiveClaim::default().try_to_vec().unwrap().len(),
+            IncentiveClaim::LEN
+        );
+    }
+
+    #[test]
+    fn test_incentive_claim_window() {
+        let mut gaugemeister = Gaugemeister {
+            current_rewards_epoch: 4,
+            ..Default::default()
+        };
+        // voting has not ended
+        assert!(!gaugemeister.incentive_claims_open(5));
+        assert!(gaugemeister.incentive_claims_open(4));
+        assert!(!gaugemeister.incentive_claims_ended(4));
+
+        gaugemeister.current_rewards_epoch = 4 + INCENTIVE_CLAIM_EPOCHS - 1;
+        assert!(gaugemeister.incentive_claims_open(4));
+
+        gaugemeister.current_rewards_epoch = 4 + INCENTIVE_CLAIM_EPOCHS;
+        assert!(!gaugemeister.incentive_claims_open(4));
+        assert!(gaugemeister.incentive_claims_ended(4));
+    }
+
+    #[test]
+    fn test_epoch_gauge_incentive_claim_amount() {
+        let incentive = EpochGaugeIncentive {
+            amount: 1_000,
+            ..Default::default()
+        };
+        assert_eq!(incentive.claim_amount(1, 3).unwrap(), 333);
+        assert_eq!(incentive.claim_amount(2, 3).unwrap(), 666);
+        assert_eq!(incentive.claim_amount(3, 3).unwrap(), 1_000);
+        assert_eq!(incentive.claim_amount(0, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_epoch_gaugemeister_commit_and_revert() {
+        let mut epoch_gm = EpochGaugemeister::default();
+        let mut gauge_a = EpochGauge::default();
+        let mut gauge_b = EpochGauge::default();
+        let mut voter_a = EpochGaugeVoter::default();
+        let mut voter_b = EpochGaugeVoter::default();
+
+        epoch_gm
+            .commit_power(&mut gauge_a, &mut voter_a, 10)
+            .unwrap();
+        epoch_gm
+            .commit_power(&mut gauge_b, &mut voter_a, 20)
+            .unwrap();
+        epoch_gm
+            .commit_power(&mut gauge_a, &mut voter_b, 5)
+            .unwrap();
+        assert_eq!(epoch_gm.total_power, 35);
+        assert_eq!(epoch_gm.num_gauges_voted, 2);
+        assert_eq!(epoch_gm.num_voters, 2);
+        assert_eq!(gauge_a.total_power, 15);
+        assert_eq!(voter_a.allocated_power, 30);
+
+        epoch_gm
+            .revert_power(&mut gauge_b, &mut voter_a, 20)
+            .unwrap();
+        assert_eq!(epoch_gm.total_power, 15);
+        assert_eq!(epoch_gm.num_gauges_voted, 1);
+        assert_eq!(epoch_gm.num_voters, 2);
+
+        epoch_gm
+            .revert_power(&mut gauge_a, &mut voter_a, 10)
+            .unwrap();
+        assert_eq!(epoch_gm.total_power, 5);
+        assert_eq!(epoch_gm.num_gauges_voted, 1);
+        assert_eq!(epoch_gm.num_voters, 1);
+
+        // zero power does not count as a vote
+        epoch_gm
+            .commit_power(&mut gauge_b, &mut voter_a, 0)
+            .unwrap();
+        assert_eq!(epoch_gm.num_gauges_voted, 1);
+        assert_eq!(epoch_gm.num_voters, 1);
+    }
+
+    #[test]
+    fn test_scale_power() {
+        let mut gm = Gaugemeister::default();
+        assert_eq!(gm.scale_power(123, 1_000).unwrap(), 123);
+
+        gm.normalize_rewards_shares = true;
+        assert_eq!(gm.scale_power(250, 1_000).unwrap(), 250_000_000);
+        assert_eq!(gm.scale_power(1_000, 1_000).unwrap(), 1_000_000_000);
+        assert_eq!(gm.scale_power(0, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_rewards_share_without_epoch_gaugemeister() {
+        // power committed before the EpochGaugemeister existed
+        let epoch_gauge = EpochGauge {
+            total_power: 500,
+            ..Default::default()
+        };
+        let missing = EpochGaugemeister::default();
+
+        let mut gm = Gaugemeister::default();
+        assert_eq!(gm.rewards_share(&epoch_gauge, &missing).unwrap(), 500);
+
+        gm.normalize_rewards_shares = true;
+        assert_eq!(gm.rewards_share(&epoch_gauge, &missing).unwrap(), 0);
+
+        // budgets are only snapshotted into an existing EpochGaugemeister
+        assert_eq!(missing.apply_group_budget(&epoch_gauge, 500).unwrap(), 500);
+        assert_eq!(gm.total_vote_shares(&missing).unwrap(), 1_000_000_000);
+    }
+
+    #[test]
+    fn test_rewards_share_capped() {
+        let gm = Gaugemeister {
+            max_gauge_share_bps: 5_000,
+            ..Default::default()
+        };
+        let gauge = Gauge::default();
+        assert!(gm.is_over_cap(&gauge, 800, 1_000));
+        assert!(!gm.is_over_cap(&gauge, 500, 1_000));
+        // no epoch total without an EpochGaugemeister
+        assert!(!gm.is_over_cap(&gauge, 800, 0));
+
+        let whale = EpochGauge {
+            total_power: 800,
+            cap_bps: 5_000,
+            ..Default::default()
+        };
+        let small_a = EpochGauge {
+            total_power: 150,
+            ..Default::default()
+        };
+        let small_b = EpochGauge {
+            total_power: 50,
+            ..Default::default()
+        };
+        let epoch_gm = EpochGaugemeister {
+            total_power: 1_000,
+            capped_power: 800,
+            capped_bps: 5_000,
+            ..Default::default()
+        };
+
+        let whale_share = gm.rewards_share(&whale, &epoch_gm).unwrap();
+        let small_a_share = gm.rewards_share(&small_a, &epoch_gm).unwrap();
+        let small_b_share = gm.rewards_share(&small_b, &epoch_gm).unwrap();
+        // the whale gets exactly half, and the rest is split 3:1.
+        assert_eq!(whale_share, 200);
+        assert_eq!(small_a_share, 150);
+        assert_eq!(small_b_share, 50);
+    }
+
+    #[test]
+    fn test_rewards_share_capped_iteratively() {
+        let gm = Gaugemeister {
+            max_gauge_share_bps: 3_000,
+            ..Default::default()
+        };
+        let gauge = Gauge::default();
+        let powers = [40, 31, 29];
+        let mut epoch_gauges = powers.map(|total_power| EpochGauge {
+            total_power,
+            ..Default::default()
+        });
+        let mut epoch_gm = EpochGaugemeister {
+            total_power: 100,
+            ..Default::default()
+        };
+
+        // capping the largest gauge pushes the others over the cap.
+        let mut rounds = 0;
+        loop {
+            let total = epoch_gm.distributed_power().unwrap();
+            let over_cap = epoch_gauges.iter_mut().find(|epoch_gauge| {
+                epoch_gauge.cap_bps == 0 && gm.is_over_cap(&gauge, epoch_gauge.total_power, total)
+            });
+            let epoch_gauge = match over_cap {
+                Some(epoch_gauge) => epoch_gauge,
+                None => break,
+            };
+            epoch_gauge.cap_bps = gm.max_share_bps(&gauge);
+            epoch_gm.capped_power += epoch_gauge.total_power;
+            epoch_gm.capped_bps += u32::from(epoch_gauge.cap_bps);
+            rounds += 1;
+        }
+        assert_eq!(rounds, 3);
+
+        // every gauge is capped, so the rewards are split evenly.
+        let shares =
+            epoch_gauges.map(|epoch_gauge| gm.rewards_share(&epoch_gauge, &epoch_gm).unwrap());
+        assert_eq!(shares, [30, 30, 30]);
+
+        // after capping only the largest gauge, the smallest receives ~34% of the rewards.
+        let epoch_gm = EpochGaugemeister {
+            total_power: 100,
+            capped_power: 40,
+            capped_bps: 3_000,
+            ..Default::default()
+        };
+        let total = epoch_gm.distributed_power().unwrap();
+        assert_eq!(total, 85);
+        assert!(!gm.is_over_cap(&gauge, 29, epoch_gm.total_power));
+        assert!(gm.is_over_cap(&gauge, 29, total));
+    }
+
+    #[test]
+    fn test_is_below_threshold() {
+        let mut gm = Gaugemeister::default();
+        assert!(!gm.is_below_threshold(0, 1_000));
+
+        gm.min_gauge_power = 10;
+        assert!(gm.is_below_threshold(9, 1_000));
+        assert!(!gm.is_below_threshold(10, 1_000));
+
+        gm.min_gauge_share_bps = 100;
+        assert!(gm.is_below_threshold(10, 10_000));
+        assert!(!gm.is_below_threshold(100, 10_000));
+    }
+
+    #[test]
+    fn test_revert_legacy_power() {
+        // power committed before the [EpochGaugemeister] existed
+        let mut voter = EpochGaugeVoter {
+            allocated_power: 300,
+            ..Default::default()
+        };
+        let mut epoch_gauge = EpochGauge {
+            total_power: 300,
+            group: 1,
+            ..Default::default()
+        };
+        let mut epoch_gm = EpochGaugemeister::default();
+        assert!(epoch_gm
+            .revert_power(&mut epoch_gauge.clone(), &mut voter.clone(), 300)
+            .is_none());
+
+        epoch_gm.count_legacy_epoch_gauge(&epoch_gauge).unwrap();
+        epoch_gm.count_legacy_epoch_gauge_voter(&voter).unwrap();
+        assert_eq!(epoch_gm.total_power, 300);
+        assert_eq!(epoch_gm.group_power[1], 300);
+
+        epoch_gm
+            .revert_power(&mut epoch_gauge, &mut voter, 300)
+            .unwrap();
+        assert_eq!(epoch_gm.total_power, 0);
+        assert_eq!(epoch_gm.group_power[1], 0);
+        assert_eq!(epoch_gm.num_gauges_voted, 0);
+        assert_eq!(epoch_gm.num_voters, 0);
+    }
+
+    #[test]
+    fn test_apply_floor() {
+        let gm = Gaugemeister {
+            total_floor_bps: 2_000,
+            ..Default::default()
+        };
+        let core = Gauge {
+            floor_bps: 2_000,
+            ..Default::default()
+        };
+        let other = Gauge::default();
+
+        // core has 10% of votes, other has 90%
+        let (core_share, core_floor) = gm.apply_floor(&core, 100, 1_000).unwrap();
+        let (other_share, other_floor) = gm.apply_floor(&other, 900, 1_000).unwrap();
+        assert_eq!((core_share, core_floor), (280, 200));
+        assert_eq!((other_share, other_floor), (720, 0));
+        assert_eq!(core_share + other_share, 1_000);
+    }
+
+    #[test]
+    fn test_total_vote_shares() {
+        let gm = Gaugemeister {
+            total_floor_bps: 2_000,
+            min_gauge_power: 100,
+            ..Default::default()
+        };
+        let core = Gauge {
+            floor_bps: 2_000,
+            ..Default::default()
+        };
+        let mut epoch_gm = EpochGaugemeister::default();
+        let mut voter = EpochGaugeVoter::default();
+        let mut big = EpochGauge::default();
+        let mut small = EpochGauge::default();
+        epoch_gm.commit_power(&mut big, &mut voter, 750).unwrap();
+        epoch_gm.commit_power(&mut small, &mut voter, 50).unwrap();
+        assert_eq!(gm.total_vote_shares(&epoch_gm).unwrap(), 800);
+
+        // the dropped power is no longer distributed, so the floor shrinks with it.
+        assert!(gm.is_below_threshold(small.total_power, epoch_gm.total_power));
+        epoch_gm.drop_power(&small).unwrap();
+        assert_eq!(epoch_gm.distributed_power().unwrap(), 750);
+        let total_vote_shares = gm.total_vote_shares(&epoch_gm).unwrap();
+        assert_eq!(total_vote_shares, 750);
+
+        let (big_share, _) = gm
+            .apply_floor(&Gauge::default(), 750, total_vote_shares)
+            .unwrap();
+        let (core_share, core_floor) = gm.apply_floor(&core, 0, total_vote_shares).unwrap();
+        assert_eq!((big_share, core_share, core_floor), (600, 150, 150));
+        // the core gauge receives exactly its floor of the rewards.
+        assert_eq!(core_share * 5, big_share + core_share);
+
+        // nothing is distributed by votes, so floors are sized against the total power.
+        epoch_gm.drop_power(&big).unwrap();
+        assert_eq!(epoch_gm.distributed_power().unwrap(), 0);
+        assert_eq!(gm.total_vote_shares(&epoch_gm).unwrap(), 800);
+    }
+
+    #[test]
+    fn test_foreman_share() {
+        let gm = Gaugemeister {
+            foreman_slice_bps: 2_000,
+            ..Default::default()
+        };
+        let epoch_gm = EpochGaugemeister {
+            total_power: 1_000,
+            total_allocation_weight: 4,
+            ..Default::default()
+        };
+        let gauge = Gauge::default();
+
+        // votes only distribute the remaining 80%
+        let (vote_share, _) = gm.apply_floor(&gauge, 500, 1_000).unwrap();
+        assert_eq!(vote_share, 400);
+        assert_eq!(gm.foreman_share(1, &epoch_gm, 1_000).unwrap(), 50);
+        assert_eq!(gm.foreman_share(3, &epoch_gm, 1_000).unwrap(), 150);
+
+        // a dropped gauge shrinks the slice along with the vote-driven shares.
+        let dropped = EpochGaugemeister {
+            capped_power: 200,
+            ..epoch_gm
+        };
+        let total_vote_shares = gm.total_vote_shares(&dropped).unwrap();
+        assert_eq!(total_vote_shares, 800);
+        let (vote_share, _) = gm.apply_floor(&gauge, 800, total_vote_shares).unwrap();
+        let slice = gm.foreman_share(4, &dropped, total_vote_shares).unwrap();
+        assert_eq!((vote_share, slice), (640, 160));
+
+        // nothing is allocated
+        let unallocated = EpochGaugemeister {
+            total_allocation_weight: 0,
+            ..epoch_gm
+        };
+        assert_eq!(gm.foreman_share(0, &unallocated, 1_000).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_apply_group_budget() {
+        let mut group_budget_weights = [0; MAX_GAUGE_GROUPS];
+        group_budget_weights[0] = 70;
+        group_budget_weights[1] = 30;
+        // nobody voted for the third group, so its budget goes to the others.
+        group_budget_weights[2] = 50;
+
+        // LP gauges have 900 power, single-asset gauges have 100
+        let mut epoch_gm = EpochGaugemeister {
+            group_budget_weights,
+            ..Default::default()
+        };
+        let mut voter = EpochGaugeVoter::default();
+        let mut lp_a = EpochGauge::default();
+        let mut lp_b = EpochGauge::default();
+        let mut single = EpochGauge {
+            group: 1,
+            ..Default::default()
+        };
+        epoch_gm.commit_power(&mut lp_a, &mut voter, 600).unwrap();
+        epoch_gm.commit_power(&mut lp_b, &mut voter, 300).unwrap();
+        epoch_gm.commit_power(&mut single, &mut voter, 100).unwrap();
+        assert_eq!(epoch_gm.group_power[0], 900);
+        assert_eq!(epoch_gm.group_power[1], 100);
+        assert_eq!(epoch_gm.funded_group_budget_weight(), 100);
+
+        assert_eq!(epoch_gm.apply_group_budget(&lp_a, 600).unwrap(), 466);
+        assert_eq!(epoch_gm.apply_group_budget(&lp_b, 300).unwrap(), 233);
+        assert_eq!(epoch_gm.apply_group_budget(&single, 100).unwrap(), 300);
+
+        // groups are not used in epochs without budgets
+        let ungrouped = EpochGaugemeister {
+            group_budget_weights: [0; MAX_GAUGE_GROUPS],
+            ..epoch_gm
+        };
+        assert_eq!(ungrouped.apply_group_budget(&single, 100).unwrap(), 100);
+    }
 }
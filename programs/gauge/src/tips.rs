@@ -0,0 +1,118 @@
+//! Tips paid from a [TipVault] to the signers of permissionless crank instructions.
+
+use anchor_spl::token::{self, Token, TokenAccount};
+
+use crate::*;
+
+/// A permissionless instruction which may pay a tip.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CrankKind {
+    /// [gauge::trigger_next_epoch].
+    TriggerNextEpoch,
+    /// [gauge::create_epoch_gauge] for the current voting epoch.
+    CreateEpochGauge,
+    /// [gauge::sync_gauge] which changed the rewards share.
+    SyncGauge,
+    /// [gauge::gauge_commit_vote] which committed non-zero power.
+    CommitVote,
+}
+
+/// Pays the [TipVault::tip_amount] to the signer of a crank instruction.
+///
+/// The `tip_accounts` are passed as remaining accounts, and may be empty if no tip is wanted:
+/// 1. The [TipVault] of the [Gaugemeister], which must be writable.
+/// 2. The cranker, which must sign, and must be writable if tips are paid in SOL.
+///
+/// If tips are paid in an SPL token, the following accounts must also be passed:
+/// 3. The [TipVault::token_account], which must be writable.
+/// 4. The token account of the cranker receiving the tip, which must be writable.
+/// 5. The SPL token program.
+///
+/// The crank still succeeds if the [TipVault] cannot afford the tip or has
+/// already paid [TipVault::max_tips_per_epoch] tips for the [CrankKind] in the rewards epoch.
+pub(crate) fn pay_crank_tip<'info>(
+    gaugemeister: &Pubkey,
+    epoch: u32,
+    kind: CrankKind,
+    tip_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    if tip_accounts.is_empty() {
+        return Ok(());
+    }
+    invariant!(tip_accounts.len() >= 2, InvalidRemainingAccounts);
+    let tip_vault_info = &tip_accounts[0];
+    let cranker = &tip_accounts[1];
+    invariant!(cranker.is_signer, TipRecipientMustSign);
+
+    let mut tip_vault: Account<TipVault> = Account::try_from(tip_vault_info)?;
+    assert_keys_eq!(tip_vault.gaugemeister, *gaugemeister);
+    let amount = tip_vault.tip_amount;
+    if amount == 0 {
+        return Ok(());
+    }
+
+    if tip_vault.is_sol() {
+        invariant!(tip_accounts.len() == 2, InvalidRemainingAccounts);
+        let rent_exempt_lamports = Rent::get()?.minimum_balance(tip_vault_info.data_len());
+        let available = tip_vault_info
+            .lamports()
+            .saturating_sub(rent_exempt_lamports);
+        if available < amount || !unwrap_int!(tip_vault.record_tip(epoch, kind)) {
+            return Ok(());
+        }
+        let vault_lamports = tip_vault_info.lamports();
+        **tip_vault_info.try_borrow_mut_lamports()? =
+            unwrap_int!(vault_lamports.checked_sub(amount));
+        let cranker_lamports = cranker.lamports();
+        **cranker.try_borrow_mut_lamports()? = unwrap_int!(cranker_lamports.checked_add(amount));
+    } else {
+        invariant!(tip_accounts.len() == 5, InvalidRemainingAccounts);
+        let vault_token_account: Account<TokenAccount> = Account::try_from(&tip_accounts[2])?;
+        assert_keys_eq!(vault_token_account, tip_vault.token_account);
+        let token_program: Program<Token> = Program::try_from(&tip_accounts[4])?;
+        if vault_token_account.amount < amount || !unwrap_int!(tip_vault.record_tip(epoch, kind)) {
+            return Ok(());
+        }
+        let seeds: &[&[&[u8]]] = tip_vault_seeds!(tip_vault);
+        token::transfer(
+            CpiContext::new(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: vault_token_account.to_account_info(),
+                    to: tip_accounts[3].clone(),
+                    authority: tip_vault_info.clone(),
+                },
+            )
+            .with_signer(seeds),
+            amount,
+        )?;
+    }
+    tip_vault.exit(&crate::ID)?;
+
+    emit!(CrankTipEvent {
+        gaugemeister: *gaugemeister,
+        cranker: cranker.key(),
+        kind,
+        epoch,
+        amount,
+    });
+
+    Ok(())
+}
+
+/// Event called when a [TipVault] pays a tip.
+#[event]
+pub struct CrankTipEvent {
+    #[index]
+    /// The [Gaugemeister].
+    pub gaugemeister: Pubkey,
+    #[index]
+    /// The signer that ran the crank instruction.
+    pub cranker: Pubkey,
+    /// The crank instruction that was run.
+    pub kind: CrankKind,
+    /// The rewards epoch in which the tip was paid.
+    pub epoch: u32,
+    /// The amount of the tip.
+    pub amount: u64,
+}
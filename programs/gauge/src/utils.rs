@@ -0,0 +1,213 @@
+//! Utilities for working with accounts passed outside of an [Accounts] struct.
+
+use anchor_lang::system_program;
+use num_traits::ToPrimitive;
+
+use crate::*;
+
+/// Creates a program-owned account at a PDA, funded by the `payer`.
+///
+/// This mirrors Anchor's `init` constraint, so the account is still created
+/// if someone has already sent lamports to its address.
+pub(crate) fn create_program_account<'info>(
+    payer: AccountInfo<'info>,
+    account: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let required_lamports = Rent::get()?.minimum_balance(space);
+    let space = unwrap_int!(space.to_u64());
+    let current_lamports = account.lamports();
+    if current_lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new(
+                system_program,
+                system_program::CreateAccount {
+                    from: payer,
+                    to: account,
+                },
+            )
+            .with_signer(signer_seeds),
+            required_lamports,
+            space,
+            &crate::ID,
+        );
+    }
+
+    let top_up_lamports = required_lamports.saturating_sub(current_lamports);
+    if top_up_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer,
+                    to: account.clone(),
+                },
+            ),
+            top_up_lamports,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new(
+            system_program.clone(),
+            system_program::Allocate {
+                account_to_allocate: account.clone(),
+            },
+        )
+        .with_signer(signer_seeds),
+        space,
+    )?;
+    system_program::assign(
+        CpiContext::new(
+            system_program,
+            system_program::Assign {
+                account_to_assign: account,
+            },
+        )
+        .with_signer(signer_seeds),
+        &crate::ID,
+    )
+}
+
+/// Extends a program account of type `T` which was created with a legacy layout
+/// of `legacy_len` bytes to `len` bytes, zeroing the fields which were appended.
+///
+/// The `payer` tops up the rent of the extended account.
+pub(crate) fn migrate_legacy_account<'info, T: anchor_lang::Discriminator>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    legacy_len: usize,
+    len: usize,
+) -> Result<()> {
+    invariant!(*account.owner == crate::ID, AccountNotLegacy);
+    invariant!(account.data_len() == 8 + legacy_len, AccountNotLegacy);
+    invariant!(
+        account.try_borrow_data()?[..8] == T::discriminator(),
+        AccountNotLegacy
+    );
+
+    let space = 8 + len;
+    let top_up_lamports = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(account.lamports());
+    if top_up_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            top_up_lamports,
+        )?;
+    }
+    account.realloc(space, true)?;
+    Ok(())
+}
+
+/// Loads the [EpochGaugemeister] of `voting_epoch`, so that the power of a legacy
+/// epoch account of that epoch can be counted in its totals.
+///
+/// The [EpochGaugemeister] must exist while the epoch is being voted on, since
+/// votes can still be reverted. After voting, it may not exist, in which case
+/// [gauge::sync_gauge] does not use the totals.
+pub(crate) fn load_legacy_epoch_gaugemeister<'info>(
+    gaugemeister: &Account<'info, Gaugemeister>,
+    epoch_gaugemeister: &AccountInfo<'info>,
+    voting_epoch: u32,
+) -> Result<Option<Account<'info, EpochGaugemeister>>> {
+    let (key, _) = Pubkey::find_program_address(
+        &[
+            b"EpochGaugemeister".as_ref(),
+            gaugemeister.key().as_ref(),
+            voting_epoch.to_le_bytes().as_ref(),
+        ],
+        &crate::ID,
+    );
+    assert_keys_eq!(epoch_gaugemeister.key(), key);
+    if epoch_gaugemeister.data_is_empty() {
+        if voting_epoch == gaugemeister.voting_epoch()? {
+            return Err(error!(anchor_lang::error::ErrorCode::AccountNotInitialized));
+        }
+        return Ok(None);
+    }
+    Ok(Some(Account::try_from(epoch_gaugemeister)?))
+}
+
+/// Serializes an account, including its discriminator, into the data of `info`.
+pub(crate) fn write_account<T: AccountSerialize>(info: &AccountInfo, account: &T) -> Result<()> {
+    let mut data = info.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    account.try_serialize(&mut writer)
+}
+
+/// Checks that the `authority` may manage the votes of a [GaugeVoter].
+///
+/// The owner and the unexpired [GaugeVoter::gauge_delegate] are always allowed.
+/// The vote delegate is allowed for as long as the [locked_voter::Escrow] exists.
+pub(crate) fn assert_is_voter_authority<'info>(
+    gaugemeister: &Account<'info, Gaugemeister>,
+    gauge_voter: &GaugeVoter,
+    escrow: &AccountInfo<'info>,
+    authority: &Pubkey,
+) -> Result<()> {
+    if gauge_voter.is_gauge_delegate(authority, gaugemeister.voting_epoch()?) {
+        assert_keys_eq!(gauge_voter.gaugemeister, *gaugemeister);
+        assert_keys_eq!(gauge_voter.escrow, escrow.key());
+        return Ok(());
+    }
+    assert_is_owner_or_escrow_delegate(gaugemeister, gauge_voter, escrow, authority)
+}
+
+/// Checks that the `authority` is the owner of a [GaugeVoter] or the vote delegate
+/// of its [locked_voter::Escrow].
+///
+/// Unlike [assert_is_voter_authority], the [GaugeVoter::gauge_delegate] is not allowed,
+/// so it is used for instructions which close accounts and refund their rent.
+pub(crate) fn assert_is_owner_or_escrow_delegate<'info>(
+    gaugemeister: &Account<'info, Gaugemeister>,
+    gauge_voter: &GaugeVoter,
+    escrow: &AccountInfo<'info>,
+    authority: &Pubkey,
+) -> Result<()> {
+    assert_keys_eq!(gauge_voter.gaugemeister, *gaugemeister);
+    assert_keys_eq!(gauge_voter.escrow, escrow.key());
+    if *authority == gauge_voter.owner {
+        return Ok(());
+    }
+    invariant!(
+        *escrow.owner == locked_voter::ID,
+        UnauthorizedNotVoterAuthority
+    );
+    let escrow: Account<locked_voter::Escrow> = Account::try_from(escrow)?;
+    assert_keys_eq!(
+        escrow.vote_delegate,
+        *authority,
+        UnauthorizedNotVoterAuthority
+    );
+    Ok(())
+}
+
+/// Checks that the `vote_delegate` may set or revert the votes of a [GaugeVoter].
+///
+/// Both the vote delegate of the [locked_voter::Escrow] and the unexpired
+/// [GaugeVoter::gauge_delegate] are allowed.
+pub(crate) fn assert_is_vote_delegate(
+    gaugemeister: &Gaugemeister,
+    gauge_voter: &GaugeVoter,
+    escrow: &locked_voter::Escrow,
+    vote_delegate: &Pubkey,
+) -> Result<()> {
+    if gauge_voter.is_gauge_delegate(vote_delegate, gaugemeister.voting_epoch()?) {
+        return Ok(());
+    }
+    assert_keys_eq!(
+        escrow.vote_delegate,
+        *vote_delegate,
+        UnauthorizedNotDelegate
+    );
+    Ok(())
+}
@@ -36,3 +36,8 @@ export const GAUGE_CODERS = buildCoderMap<{
  * Default epoch duration (seconds) -- 7 days
  */
 export const DEFAULT_EPOCH_DURATION_SECONDS = 60 * 60 * 24 * 7;
+
+/**
+ * Default delay (seconds) before a Gaugemeister parameter change may be accepted -- 2 days
+ */
+export const DEFAULT_PARAMS_CHANGE_DELAY_SECONDS = 60 * 60 * 24 * 2;
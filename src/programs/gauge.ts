@@ -15,6 +15,11 @@ export type GaugeTypes = AnchorTypes<
     epochGauge: EpochGaugeData;
     epochGaugeVoter: EpochGaugeVoterData;
     epochGaugeVote: EpochGaugeVoteData;
+    epochGaugemeister: EpochGaugemeisterData;
+    epochGaugeAllocation: EpochGaugeAllocationData;
+    tipVault: TipVaultData;
+    epochGaugeIncentive: EpochGaugeIncentiveData;
+    incentiveClaim: IncentiveClaimData;
   }
 >;
 
@@ -27,5 +32,10 @@ export type GaugeVoteData = Accounts["gaugeVote"];
 export type EpochGaugeData = Accounts["epochGauge"];
 export type EpochGaugeVoterData = Accounts["epochGaugeVoter"];
 export type EpochGaugeVoteData = Accounts["epochGaugeVote"];
+export type EpochGaugemeisterData = Accounts["epochGaugemeister"];
+export type EpochGaugeAllocationData = Accounts["epochGaugeAllocation"];
+export type TipVaultData = Accounts["tipVault"];
+export type EpochGaugeIncentiveData = Accounts["epochGaugeIncentive"];
+export type IncentiveClaimData = Accounts["incentiveClaim"];
 
 export type GaugeProgram = Program<GaugeIDL>;
@@ -1,13 +1,19 @@
 import { QUARRY_ADDRESSES } from "@quarryprotocol/quarry-sdk";
 import type { TransactionEnvelope } from "@saberhq/solana-contrib";
-import { u64 } from "@saberhq/token-utils";
-import type { PublicKey } from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, u64 } from "@saberhq/token-utils";
+import type { AccountMeta, PublicKey } from "@solana/web3.js";
 import { Keypair, SystemProgram } from "@solana/web3.js";
 import { findEscrowAddress } from "@tribecahq/tribeca-sdk";
 
-import { DEFAULT_EPOCH_DURATION_SECONDS } from "../../constants";
+import {
+  DEFAULT_EPOCH_DURATION_SECONDS,
+  DEFAULT_PARAMS_CHANGE_DELAY_SECONDS,
+} from "../../constants";
 import type {
+  EpochGaugeAllocationData,
   EpochGaugeData,
+  EpochGaugeIncentiveData,
+  EpochGaugemeisterData,
   EpochGaugeVoteData,
   EpochGaugeVoterData,
   GaugeData,
@@ -15,15 +21,21 @@ import type {
   GaugeProgram,
   GaugeVoteData,
   GaugeVoterData,
+  TipVaultData,
 } from "../../programs/gauge";
 import type { GaugeSDK } from "../../sdk";
 import { findEpochGaugeVoterAddress, findGaugeVoterAddress } from ".";
 import {
   findEpochGaugeAddress,
+  findEpochGaugeAllocationAddress,
+  findEpochGaugeIncentiveAddress,
+  findEpochGaugemeisterAddress,
   findEpochGaugeVoteAddress,
   findGaugeAddress,
   findGaugemeisterAddress,
   findGaugeVoteAddress,
+  findIncentiveClaimAddress,
+  findTipVaultAddress,
 } from "./pda";
 
 /**
@@ -76,6 +88,28 @@ export class GaugeWrapper {
     return await this.program.account.epochGauge.fetchNullable(key);
   }
 
+  async fetchEpochGaugemeister(
+    key: PublicKey
+  ): Promise<EpochGaugemeisterData | null> {
+    return await this.program.account.epochGaugemeister.fetchNullable(key);
+  }
+
+  async fetchEpochGaugeAllocation(
+    key: PublicKey
+  ): Promise<EpochGaugeAllocationData | null> {
+    return await this.program.account.epochGaugeAllocation.fetchNullable(key);
+  }
+
+  async fetchEpochGaugeIncentive(
+    key: PublicKey
+  ): Promise<EpochGaugeIncentiveData | null> {
+    return await this.program.account.epochGaugeIncentive.fetchNullable(key);
+  }
+
+  async fetchTipVault(key: PublicKey): Promise<TipVaultData | null> {
+    return await this.program.account.tipVault.fetchNullable(key);
+  }
+
   /**
    * Creates a Gaugemeister.
    * @returns
@@ -87,6 +121,7 @@ export class GaugeWrapper {
     foreman = this.provider.wallet.publicKey,
     baseKP = Keypair.generate(),
     epochDurationSeconds = DEFAULT_EPOCH_DURATION_SECONDS,
+    paramsChangeDelaySeconds = DEFAULT_PARAMS_CHANGE_DELAY_SECONDS,
   }: {
     /**
      * When to start the first epoch.
@@ -112,6 +147,10 @@ export class GaugeWrapper {
      * Epoch duration seconds
      */
     epochDurationSeconds?: number;
+    /**
+     * Delay (seconds) before a parameter change may be accepted.
+     */
+    paramsChangeDelaySeconds?: number;
   }): Promise<{
     gaugemeister: PublicKey;
     tx: TransactionEnvelope;
@@ -125,6 +164,7 @@ export class GaugeWrapper {
             foreman,
             epochDurationSeconds,
             new u64(Math.floor(firstEpochStartsAt.getTime() / 1_000)),
+            paramsChangeDelaySeconds,
             {
               accounts: {
                 base: baseKP.publicKey,
@@ -252,7 +292,7 @@ export class GaugeWrapper {
         accounts: {
           gaugemeister: gaugeData.gaugemeister,
           gauge,
-          foreman: gmData.foreman,
+          curator: gmData.curator,
         },
       }),
     ]);
@@ -264,8 +304,13 @@ export class GaugeWrapper {
    */
   async disableGauge({
     gauge,
+    authority,
   }: {
     gauge: PublicKey;
+    /**
+     * The curator or the guardian. Defaults to the curator.
+     */
+    authority?: PublicKey;
   }): Promise<TransactionEnvelope> {
     const gaugeData = await this.fetchGauge(gauge);
     if (!gaugeData) {
@@ -280,7 +325,7 @@ export class GaugeWrapper {
         accounts: {
           gaugemeister: gaugeData.gaugemeister,
           gauge,
-          foreman: gmData.foreman,
+          authority: authority ?? gmData.curator,
         },
       }),
     ]);
@@ -539,6 +584,77 @@ export class GaugeWrapper {
     ]);
   }
 
+  /**
+   * Refreshes the voting power of an Epoch Gauge Voter, rescaling all of its
+   * committed votes without reverting them.
+   *
+   * Fails if the vote weights changed since the Epoch Gauge Voter was prepared.
+   * @returns
+   */
+  async refreshEpochGaugeVoter({
+    gaugemeister,
+    gauges,
+    owner = this.provider.wallet.publicKey,
+  }: {
+    gaugemeister: PublicKey;
+    /**
+     * All gauges that the voter has committed votes to.
+     */
+    gauges: PublicKey[];
+    owner?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const gmData = await this.fetchGaugemeister(gaugemeister);
+    if (!gmData) {
+      throw new Error("gaugemeister not found");
+    }
+    const [escrow] = await findEscrowAddress(gmData.locker, owner);
+    const [gaugeVoter] = await findGaugeVoterAddress(gaugemeister, escrow);
+
+    const votingEpoch = gmData.currentRewardsEpoch + 1;
+    const [epochGaugeVoter] = await findEpochGaugeVoterAddress(
+      gaugeVoter,
+      votingEpoch
+    );
+    const [epochGaugemeister] = await findEpochGaugemeisterAddress(
+      gaugemeister,
+      votingEpoch
+    );
+
+    const votes = await Promise.all(
+      gauges.map(async (gauge) => {
+        const [gaugeVote] = await findGaugeVoteAddress(gaugeVoter, gauge);
+        const [epochGauge] = await findEpochGaugeAddress(gauge, votingEpoch);
+        const [epochGaugeVote] = await findEpochGaugeVoteAddress(
+          gaugeVote,
+          votingEpoch
+        );
+        return { gauge, gaugeVote, epochGauge, epochGaugeVote };
+      })
+    );
+    votes.sort((a, b) =>
+      a.gaugeVote.toBuffer().compare(b.gaugeVote.toBuffer())
+    );
+
+    return this.provider.newTX([
+      this.program.instruction.refreshEpochGaugeVoter({
+        accounts: {
+          gaugemeister,
+          locker: gmData.locker,
+          escrow,
+          gaugeVoter,
+          epochGaugeVoter,
+          epochGaugemeister,
+        },
+        remainingAccounts: votes.flatMap((vote) => [
+          { pubkey: vote.gauge, isSigner: false, isWritable: false },
+          { pubkey: vote.gaugeVote, isSigner: false, isWritable: false },
+          { pubkey: vote.epochGauge, isSigner: false, isWritable: true },
+          { pubkey: vote.epochGaugeVote, isSigner: false, isWritable: true },
+        ]),
+      }),
+    ]);
+  }
+
   /**
    * Prepares the Epoch Gauge Voter, which must be created before committing
    * votes for an epoch.
@@ -615,6 +731,113 @@ export class GaugeWrapper {
     ]);
   }
 
+  /**
+   * Creates the EpochGaugemeister for the current voting epoch.
+   * @returns
+   */
+  async createEpochGaugemeister({
+    gaugemeister,
+    payer = this.provider.wallet.publicKey,
+  }: {
+    gaugemeister: PublicKey;
+    payer?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const gmData = await this.fetchGaugemeister(gaugemeister);
+    if (!gmData) {
+      throw new Error("gaugemeister not found");
+    }
+    const votingEpoch = gmData.currentRewardsEpoch + 1;
+    const [epochGaugemeister] = await findEpochGaugemeisterAddress(
+      gaugemeister,
+      votingEpoch
+    );
+    return this.provider.newTX([
+      this.program.instruction.createEpochGaugemeister(votingEpoch, {
+        accounts: {
+          gaugemeister,
+          epochGaugemeister,
+          payer,
+          systemProgram: SystemProgram.programId,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Sets the foreman's allocation to a gauge for the current voting epoch,
+   * creating the EpochGaugeAllocation if it does not exist.
+   * The authority may be the foreman or the smart wallet of the locker's governor.
+   * @returns
+   */
+  async setEpochGaugeAllocation({
+    gauge,
+    weight,
+    authority = this.provider.wallet.publicKey,
+    payer = this.provider.wallet.publicKey,
+  }: {
+    gauge: PublicKey;
+    weight: number;
+    authority?: PublicKey;
+    payer?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const gaugeData = await this.fetchGauge(gauge);
+    if (!gaugeData) {
+      throw new Error("gauge not found");
+    }
+    const gmData = await this.fetchGaugemeister(gaugeData.gaugemeister);
+    if (!gmData) {
+      throw new Error("gaugemeister not found");
+    }
+    const votingEpoch = gmData.currentRewardsEpoch + 1;
+    const [epochGaugeAllocation] = await findEpochGaugeAllocationAddress(
+      gauge,
+      votingEpoch
+    );
+    const [epochGaugemeister] = await findEpochGaugemeisterAddress(
+      gaugeData.gaugemeister,
+      votingEpoch
+    );
+
+    const createEpochGaugemeister = !(await this.fetchEpochGaugemeister(
+      epochGaugemeister
+    ));
+    const createEpochGaugeAllocation = !(await this.fetchEpochGaugeAllocation(
+      epochGaugeAllocation
+    ));
+
+    return this.provider.newTX([
+      createEpochGaugemeister &&
+        this.program.instruction.createEpochGaugemeister(votingEpoch, {
+          accounts: {
+            gaugemeister: gaugeData.gaugemeister,
+            epochGaugemeister,
+            payer,
+            systemProgram: SystemProgram.programId,
+          },
+        }),
+      createEpochGaugeAllocation &&
+        this.program.instruction.createEpochGaugeAllocation(votingEpoch, {
+          accounts: {
+            gaugemeister: gaugeData.gaugemeister,
+            gauge,
+            epochGaugeAllocation,
+            payer,
+            systemProgram: SystemProgram.programId,
+          },
+        }),
+      this.program.instruction.setEpochGaugeAllocation(weight, {
+        accounts: {
+          gaugemeister: gaugeData.gaugemeister,
+          gauge,
+          epochGaugeAllocation,
+          epochGaugemeister,
+          lockerGovernor: gmData.lockerGovernor,
+          authority,
+        },
+      }),
+    ]);
+  }
+
   /**
    * Commits a vote.
    * @returns
@@ -624,6 +847,7 @@ export class GaugeWrapper {
     owner = this.provider.wallet.publicKey,
     payer = this.provider.wallet.publicKey,
     skipEpochGaugeCreation = false,
+    tipAccounts = [],
   }: {
     gauge: PublicKey;
     owner?: PublicKey;
@@ -632,6 +856,10 @@ export class GaugeWrapper {
      * If true, skips the creation of the epoch gauge if it is not found.
      */
     skipEpochGaugeCreation?: boolean;
+    /**
+     * Accounts of the tip vault, from {@link getCrankTipAccounts}, to receive a tip.
+     */
+    tipAccounts?: AccountMeta[];
   }): Promise<TransactionEnvelope> {
     const gaugeData = await this.fetchGauge(gauge);
     if (!gaugeData) {
@@ -659,6 +887,13 @@ export class GaugeWrapper {
     );
     const createEpochGauge =
       !skipEpochGaugeCreation && !(await this.fetchEpochGauge(epochGauge));
+    const [epochGaugemeister] = await findEpochGaugemeisterAddress(
+      gaugeData.gaugemeister,
+      votingEpoch
+    );
+    const createEpochGaugemeister =
+      !skipEpochGaugeCreation &&
+      !(await this.fetchEpochGaugemeister(epochGaugemeister));
     const [epochGaugeVote] = await findEpochGaugeVoteAddress(
       gaugeVote,
       gmData.currentRewardsEpoch + 1
@@ -673,10 +908,20 @@ export class GaugeWrapper {
       systemProgram: SystemProgram.programId,
       epochGauge,
       epochGaugeVoter,
+      epochGaugemeister,
       epochGaugeVote,
     };
 
     return this.provider.newTX([
+      createEpochGaugemeister &&
+        this.program.instruction.createEpochGaugemeister(votingEpoch, {
+          accounts: {
+            gaugemeister: gaugeData.gaugemeister,
+            epochGaugemeister,
+            payer,
+            systemProgram: SystemProgram.programId,
+          },
+        }),
       createEpochGauge &&
         this.program.instruction.createEpochGauge(epochGaugeBump, votingEpoch, {
           accounts: {
@@ -688,6 +933,7 @@ export class GaugeWrapper {
         }),
       this.program.instruction.gaugeCommitVoteV2({
         accounts,
+        remainingAccounts: tipAccounts,
       }),
     ]);
   }
@@ -761,6 +1007,25 @@ export class GaugeWrapper {
         )
       : [];
 
+    const [epochGaugemeister] = await findEpochGaugemeisterAddress(
+      gaugemeister,
+      votingEpoch
+    );
+    const createEpochGaugemeisterTX =
+      !skipEpochGaugeCreation &&
+      !(await this.fetchEpochGaugemeister(epochGaugemeister))
+        ? this.provider.newTX([
+            this.program.instruction.createEpochGaugemeister(votingEpoch, {
+              accounts: {
+                gaugemeister,
+                epochGaugemeister,
+                payer,
+                systemProgram: SystemProgram.programId,
+              },
+            }),
+          ])
+        : null;
+
     const voteTXs = await Promise.all(
       myGaugeVotes.map(async (myGaugeVote, i) => {
         const { gaugeKey, gaugeVote, epochGauge, epochGaugeBump } = myGaugeVote;
@@ -789,6 +1054,7 @@ export class GaugeWrapper {
           systemProgram: SystemProgram.programId,
           epochGauge,
           epochGaugeVoter,
+          epochGaugemeister,
           epochGaugeVote,
         };
         return this.provider.newTX([
@@ -811,7 +1077,9 @@ export class GaugeWrapper {
         ]);
       })
     );
-    return voteTXs.filter((tx): tx is TransactionEnvelope => !!tx);
+    return [createEpochGaugemeisterTX, ...voteTXs].filter(
+      (tx): tx is TransactionEnvelope => !!tx
+    );
   }
 
   /**
@@ -822,12 +1090,10 @@ export class GaugeWrapper {
     gauge,
     owner = this.provider.wallet.publicKey,
     voteDelegate = this.provider.wallet.publicKey,
-    payer = this.provider.wallet.publicKey,
   }: {
     gauge: PublicKey;
     owner?: PublicKey;
     voteDelegate?: PublicKey;
-    payer?: PublicKey;
   }): Promise<TransactionEnvelope> {
     const gaugeData = await this.fetchGauge(gauge);
     if (!gaugeData) {
@@ -850,10 +1116,18 @@ export class GaugeWrapper {
       gaugeVoter,
       votingEpoch
     );
+    const [epochGaugemeister] = await findEpochGaugemeisterAddress(
+      gaugeData.gaugemeister,
+      votingEpoch
+    );
     const [epochGaugeVote] = await findEpochGaugeVoteAddress(
       gaugeVote,
       gmData.currentRewardsEpoch + 1
     );
+    const epochGaugeVoteData = await this.fetchEpochGaugeVote(epochGaugeVote);
+    if (!epochGaugeVoteData) {
+      throw new Error("epoch gauge vote not found");
+    }
 
     const accounts = {
       gaugemeister: gaugeData.gaugemeister,
@@ -862,8 +1136,9 @@ export class GaugeWrapper {
       gaugeVote,
       epochGauge,
       epochGaugeVoter,
+      epochGaugemeister,
       epochGaugeVote,
-      payer,
+      rentPayer: epochGaugeVoteData.rentPayer,
       escrow,
       voteDelegate,
     };
@@ -876,76 +1151,303 @@ export class GaugeWrapper {
   }
 
   /**
-   * Reverts votes for multiple gauges.
+   * Releases a voter's power committed to a disabled gauge
+   * in the current voting epoch. Anyone can call this.
    * @returns
    */
-  async revertVotes({
-    gaugemeister,
-    gauges,
-    owner = this.provider.wallet.publicKey,
-    voteDelegate = this.provider.wallet.publicKey,
-    payer = this.provider.wallet.publicKey,
+  async releaseGaugeVote({
+    gauge,
+    owner,
   }: {
-    gaugemeister: PublicKey;
+    gauge: PublicKey;
     /**
-     * List of all gauges to check for reversion.
+     * Owner of the escrow whose vote is released.
      */
-    gauges: PublicKey[];
-    owner?: PublicKey;
-    voteDelegate?: PublicKey;
-    payer?: PublicKey;
-  }): Promise<TransactionEnvelope[]> {
-    const gmData = await this.fetchGaugemeister(gaugemeister);
+    owner: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const gaugeData = await this.fetchGauge(gauge);
+    if (!gaugeData) {
+      throw new Error("gauge not found");
+    }
+    const gmData = await this.fetchGaugemeister(gaugeData.gaugemeister);
     if (!gmData) {
       throw new Error("gaugemeister not found");
     }
     const [escrow] = await findEscrowAddress(gmData.locker, owner);
-    const [gaugeVoter] = await findGaugeVoterAddress(gaugemeister, escrow);
+    const [gaugeVoter] = await findGaugeVoterAddress(
+      gaugeData.gaugemeister,
+      escrow
+    );
+    const [gaugeVote] = await findGaugeVoteAddress(gaugeVoter, gauge);
 
     const votingEpoch = gmData.currentRewardsEpoch + 1;
-
-    const myGaugeVotes = await Promise.all(
-      gauges.map(async (gaugeKey) => {
-        const [gaugeVote] = await findGaugeVoteAddress(gaugeVoter, gaugeKey);
-        const [epochGaugeVote] = await findEpochGaugeVoteAddress(
-          gaugeVote,
-          votingEpoch
-        );
-        return { gaugeKey, gaugeVote, epochGaugeVote };
-      })
+    const [epochGauge] = await findEpochGaugeAddress(gauge, votingEpoch);
+    const [epochGaugeVoter] = await findEpochGaugeVoterAddress(
+      gaugeVoter,
+      votingEpoch
     );
-    const epochGaugeVotesInfo =
-      await this.provider.connection.getMultipleAccountsInfo(
-        myGaugeVotes.map((gv) => gv.epochGaugeVote)
-      );
-
-    const revertTXs = await Promise.all(
-      epochGaugeVotesInfo.map(async (gvi, i) => {
-        if (!gvi) {
-          return null;
-        }
-        const myGaugeVote = myGaugeVotes[i];
-        if (!myGaugeVote) {
-          return null;
-        }
-
-        const { gaugeKey, gaugeVote, epochGaugeVote } = myGaugeVote;
-        const [epochGauge] = await findEpochGaugeAddress(gaugeKey, votingEpoch);
-        const [epochGaugeVoter] = await findEpochGaugeVoterAddress(
-          gaugeVoter,
-          votingEpoch
-        );
+    const [epochGaugemeister] = await findEpochGaugemeisterAddress(
+      gaugeData.gaugemeister,
+      votingEpoch
+    );
+    const [epochGaugeVote] = await findEpochGaugeVoteAddress(
+      gaugeVote,
+      votingEpoch
+    );
+    const epochGaugeVoteData = await this.fetchEpochGaugeVote(epochGaugeVote);
+    if (!epochGaugeVoteData) {
+      throw new Error("epoch gauge vote not found");
+    }
 
-        const accounts = {
-          gaugemeister,
-          gauge: gaugeKey,
+    return this.provider.newTX([
+      this.program.instruction.releaseGaugeVote({
+        accounts: {
+          gaugemeister: gaugeData.gaugemeister,
+          gauge,
           gaugeVoter,
           gaugeVote,
           epochGauge,
           epochGaugeVoter,
+          epochGaugemeister,
           epochGaugeVote,
-          payer,
-          escrow,
+          rentPayer: epochGaugeVoteData.rentPayer,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Opts a gauge voter in or out of persistent votes.
+   * @returns
+   */
+  async setPersistentVotes({
+    gaugemeister,
+    isPersistent,
+    owner = this.provider.wallet.publicKey,
+    authority = this.provider.wallet.publicKey,
+  }: {
+    gaugemeister: PublicKey;
+    isPersistent: boolean;
+    /**
+     * Escrow owner.
+     */
+    owner?: PublicKey;
+    /**
+     * Escrow owner or vote delegate.
+     */
+    authority?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const gmData = await this.fetchGaugemeister(gaugemeister);
+    if (!gmData) {
+      throw new Error("gaugemeister not found");
+    }
+    const [escrow] = await findEscrowAddress(gmData.locker, owner);
+    const [gaugeVoter] = await findGaugeVoterAddress(gaugemeister, escrow);
+    return this.provider.newTX([
+      this.program.instruction.setPersistentVotes(isPersistent, {
+        accounts: {
+          gaugemeister,
+          gaugeVoter,
+          escrow,
+          authority,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Sets the account which may manage the gauge votes of a gauge voter,
+   * separately from the vote delegate of its escrow.
+   * @returns
+   */
+  async setGaugeDelegate({
+    gaugemeister,
+    gaugeDelegate,
+    expiresEpoch = 0,
+    owner = this.provider.wallet.publicKey,
+  }: {
+    gaugemeister: PublicKey;
+    /**
+     * The gauge delegate. Use the default public key to remove it.
+     */
+    gaugeDelegate: PublicKey;
+    /**
+     * The last voting epoch of the delegation. Zero never expires.
+     */
+    expiresEpoch?: number;
+    owner?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const gmData = await this.fetchGaugemeister(gaugemeister);
+    if (!gmData) {
+      throw new Error("gaugemeister not found");
+    }
+    const [escrow] = await findEscrowAddress(gmData.locker, owner);
+    const [gaugeVoter] = await findGaugeVoterAddress(gaugemeister, escrow);
+    return this.provider.newTX([
+      this.program.instruction.setGaugeDelegate(gaugeDelegate, expiresEpoch, {
+        accounts: {
+          gaugeVoter,
+          owner,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Prepares the epoch gauge voter of a persistent gauge voter
+   * and commits all of its votes. Anyone can call this.
+   * @returns
+   */
+  async rollForwardVotes({
+    gaugemeister,
+    gauges,
+    owner,
+    payer = this.provider.wallet.publicKey,
+  }: {
+    gaugemeister: PublicKey;
+    /**
+     * All gauges that the voter has a GaugeVote for.
+     */
+    gauges: PublicKey[];
+    /**
+     * Escrow owner.
+     */
+    owner: PublicKey;
+    payer?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const gmData = await this.fetchGaugemeister(gaugemeister);
+    if (!gmData) {
+      throw new Error("gaugemeister not found");
+    }
+    const [escrow] = await findEscrowAddress(gmData.locker, owner);
+    const [gaugeVoter] = await findGaugeVoterAddress(gaugemeister, escrow);
+
+    const votingEpoch = gmData.currentRewardsEpoch + 1;
+    const [epochGaugeVoter] = await findEpochGaugeVoterAddress(
+      gaugeVoter,
+      votingEpoch
+    );
+    const [epochGaugemeister] = await findEpochGaugemeisterAddress(
+      gaugemeister,
+      votingEpoch
+    );
+
+    const votes = await Promise.all(
+      gauges.map(async (gauge) => {
+        const [gaugeVote] = await findGaugeVoteAddress(gaugeVoter, gauge);
+        const [epochGauge] = await findEpochGaugeAddress(gauge, votingEpoch);
+        const [epochGaugeVote] = await findEpochGaugeVoteAddress(
+          gaugeVote,
+          votingEpoch
+        );
+        return { gauge, gaugeVote, epochGauge, epochGaugeVote };
+      })
+    );
+    votes.sort((a, b) =>
+      a.gaugeVote.toBuffer().compare(b.gaugeVote.toBuffer())
+    );
+
+    return this.provider.newTX([
+      this.program.instruction.rollForwardVotes({
+        accounts: {
+          gaugemeister,
+          locker: gmData.locker,
+          escrow,
+          gaugeVoter,
+          epochGaugeVoter,
+          epochGaugemeister,
+          payer,
+          systemProgram: SystemProgram.programId,
+        },
+        remainingAccounts: votes.flatMap((vote) => [
+          { pubkey: vote.gauge, isSigner: false, isWritable: false },
+          { pubkey: vote.gaugeVote, isSigner: false, isWritable: true },
+          { pubkey: vote.epochGauge, isSigner: false, isWritable: true },
+          { pubkey: vote.epochGaugeVote, isSigner: false, isWritable: true },
+        ]),
+      }),
+    ]);
+  }
+
+  /**
+   * Reverts votes for multiple gauges.
+   * @returns
+   */
+  async revertVotes({
+    gaugemeister,
+    gauges,
+    owner = this.provider.wallet.publicKey,
+    voteDelegate = this.provider.wallet.publicKey,
+  }: {
+    gaugemeister: PublicKey;
+    /**
+     * List of all gauges to check for reversion.
+     */
+    gauges: PublicKey[];
+    owner?: PublicKey;
+    voteDelegate?: PublicKey;
+  }): Promise<TransactionEnvelope[]> {
+    const gmData = await this.fetchGaugemeister(gaugemeister);
+    if (!gmData) {
+      throw new Error("gaugemeister not found");
+    }
+    const [escrow] = await findEscrowAddress(gmData.locker, owner);
+    const [gaugeVoter] = await findGaugeVoterAddress(gaugemeister, escrow);
+
+    const votingEpoch = gmData.currentRewardsEpoch + 1;
+    const [epochGaugemeister] = await findEpochGaugemeisterAddress(
+      gaugemeister,
+      votingEpoch
+    );
+
+    const myGaugeVotes = await Promise.all(
+      gauges.map(async (gaugeKey) => {
+        const [gaugeVote] = await findGaugeVoteAddress(gaugeVoter, gaugeKey);
+        const [epochGaugeVote] = await findEpochGaugeVoteAddress(
+          gaugeVote,
+          votingEpoch
+        );
+        return { gaugeKey, gaugeVote, epochGaugeVote };
+      })
+    );
+    const epochGaugeVotesInfo =
+      await this.provider.connection.getMultipleAccountsInfo(
+        myGaugeVotes.map((gv) => gv.epochGaugeVote)
+      );
+
+    const revertTXs = await Promise.all(
+      epochGaugeVotesInfo.map(async (gvi, i) => {
+        if (!gvi) {
+          return null;
+        }
+        const myGaugeVote = myGaugeVotes[i];
+        if (!myGaugeVote) {
+          return null;
+        }
+
+        const { gaugeKey, gaugeVote, epochGaugeVote } = myGaugeVote;
+        const epochGaugeVoteData =
+          this.program.coder.accounts.decode<EpochGaugeVoteData>(
+            "EpochGaugeVote",
+            gvi.data
+          );
+        const [epochGauge] = await findEpochGaugeAddress(gaugeKey, votingEpoch);
+        const [epochGaugeVoter] = await findEpochGaugeVoterAddress(
+          gaugeVoter,
+          votingEpoch
+        );
+
+        const accounts = {
+          gaugemeister,
+          gauge: gaugeKey,
+          gaugeVoter,
+          gaugeVote,
+          epochGauge,
+          epochGaugeVoter,
+          epochGaugemeister,
+          epochGaugeVote,
+          rentPayer: epochGaugeVoteData.rentPayer,
+          escrow,
           voteDelegate,
         };
         return this.provider.newTX([
@@ -993,6 +1495,25 @@ export class GaugeWrapper {
     if (!gmData) {
       throw new Error("gaugemeister data not found");
     }
+    if (
+      !gaugeData.isDisabled &&
+      gaugeData.sunsetEpoch !== 0 &&
+      gmData.currentRewardsEpoch >= gaugeData.sunsetEpoch
+    ) {
+      return this.provider.newTX([
+        this.program.instruction.sunsetGauge({
+          accounts: {
+            gaugemeister: gaugeData.gaugemeister,
+            gauge,
+            quarry: gaugeData.quarry,
+            operator: gmData.operator,
+            rewarder: gmData.rewarder,
+            quarryMineProgram: QUARRY_ADDRESSES.Mine,
+            quarryOperatorProgram: QUARRY_ADDRESSES.Operator,
+          },
+        }),
+      ]);
+    }
     if (gaugeData.isDisabled) {
       return this.provider.newTX([
         this.program.instruction.syncDisabledGauge({
@@ -1013,12 +1534,41 @@ export class GaugeWrapper {
       gauge,
       gmData.currentRewardsEpoch
     );
+
+    const [epochGaugemeister] = await findEpochGaugemeisterAddress(
+      gaugeData.gaugemeister,
+      gmData.currentRewardsEpoch
+    );
+    const [epochGaugeAllocation] = await findEpochGaugeAllocationAddress(
+      gauge,
+      gmData.currentRewardsEpoch
+    );
+    if (!(await this.fetchEpochGauge(epochGauge))) {
+      return this.provider.newTX([
+        this.program.instruction.syncUnvotedGauge({
+          accounts: {
+            gaugemeister: gaugeData.gaugemeister,
+            gauge,
+            epochGauge,
+            epochGaugemeister,
+            epochGaugeAllocation,
+            quarry: gaugeData.quarry,
+            operator: gmData.operator,
+            rewarder: gmData.rewarder,
+            quarryMineProgram: QUARRY_ADDRESSES.Mine,
+            quarryOperatorProgram: QUARRY_ADDRESSES.Operator,
+          },
+        }),
+      ]);
+    }
     return this.provider.newTX([
       this.program.instruction.syncGauge({
         accounts: {
           gaugemeister: gaugeData.gaugemeister,
           gauge,
           epochGauge,
+          epochGaugemeister,
+          epochGaugeAllocation,
           quarry: gaugeData.quarry,
           operator: gmData.operator,
           rewarder: gmData.rewarder,
@@ -1030,16 +1580,19 @@ export class GaugeWrapper {
   }
 
   /**
-   * Sets parameters on a given Gaugemeister.
+   * Proposes new parameters on a given Gaugemeister.
+   * The parameters must be accepted by the new foreman once the delay has elapsed.
    */
   async setGaugemeisterParams({
     gaugemeister,
     newEpochDurationSeconds,
     newForeman,
+    newParamsChangeDelaySeconds,
   }: {
     gaugemeister: PublicKey;
     newEpochDurationSeconds?: number;
     newForeman?: PublicKey;
+    newParamsChangeDelaySeconds?: number;
   }): Promise<TransactionEnvelope> {
     const gmData = await this.fetchGaugemeister(gaugemeister);
     if (!gmData) {
@@ -1049,11 +1602,14 @@ export class GaugeWrapper {
     const epochDurationSeconds =
       newEpochDurationSeconds ?? gmData.epochDurationSeconds;
     const foreman = newForeman ?? gmData.foreman;
+    const paramsChangeDelaySeconds =
+      newParamsChangeDelaySeconds ?? gmData.paramsChangeDelaySeconds;
 
     return this.provider.newTX([
-      this.program.instruction.setGaugemeisterParams(
+      this.program.instruction.proposeGaugemeisterParams(
         epochDurationSeconds,
         foreman,
+        paramsChangeDelaySeconds,
         {
           accounts: {
             gaugemeister,
@@ -1065,41 +1621,883 @@ export class GaugeWrapper {
   }
 
   /**
-   * Closes an EpochGaugeVote account.
-   * @returns
+   * Accepts the pending parameters on a given Gaugemeister.
    */
-  async closeEpochGaugeVote({
-    gauge,
+  async acceptGaugemeisterParams({
     gaugemeister,
-    voteDelegate = this.provider.walletKey,
-    escrow,
-    votingEpoch,
-    recipient = voteDelegate,
   }: {
-    gauge: PublicKey;
     gaugemeister: PublicKey;
-    voteDelegate?: PublicKey;
-    escrow: PublicKey;
-    votingEpoch: number;
-    recipient?: PublicKey;
   }): Promise<TransactionEnvelope> {
-    const [gaugeVoter] = await findGaugeVoterAddress(gaugemeister, escrow);
-    const [gaugeVote] = await findGaugeVoteAddress(gaugeVoter, gauge);
-    const [epochGaugeVote] = await findEpochGaugeVoteAddress(
-      gaugeVote,
-      votingEpoch
-    );
+    const gmData = await this.fetchGaugemeister(gaugemeister);
+    if (!gmData) {
+      throw new Error("gaugemeister data not found");
+    }
     return this.provider.newTX([
-      this.program.instruction.closeEpochGaugeVote(votingEpoch, {
+      this.program.instruction.acceptGaugemeisterParams({
         accounts: {
-          epochGaugeVote,
           gaugemeister,
-          gauge,
-          gaugeVoter,
-          gaugeVote,
-          escrow,
-          voteDelegate,
-          recipient,
+          pendingForeman: gmData.pendingForeman,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Cancels the pending parameters on a given Gaugemeister.
+   */
+  async cancelGaugemeisterParams({
+    gaugemeister,
+  }: {
+    gaugemeister: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const gmData = await this.fetchGaugemeister(gaugemeister);
+    if (!gmData) {
+      throw new Error("gaugemeister data not found");
+    }
+    return this.provider.newTX([
+      this.program.instruction.cancelGaugemeisterParams({
+        accounts: {
+          gaugemeister,
+          foreman: gmData.foreman,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Sets the curator of a Gaugemeister, which may enable and disable gauges.
+   */
+  async setCurator({
+    gaugemeister,
+    newCurator,
+  }: {
+    gaugemeister: PublicKey;
+    newCurator: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const gmData = await this.fetchGaugemeister(gaugemeister);
+    if (!gmData) {
+      throw new Error("gaugemeister data not found");
+    }
+    return this.provider.newTX([
+      this.program.instruction.setCurator(newCurator, {
+        accounts: {
+          gaugemeister,
+          foreman: gmData.foreman,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Sets the guardian of a Gaugemeister, which may take emergency actions.
+   */
+  async setGuardian({
+    gaugemeister,
+    newGuardian,
+  }: {
+    gaugemeister: PublicKey;
+    newGuardian: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const gmData = await this.fetchGaugemeister(gaugemeister);
+    if (!gmData) {
+      throw new Error("gaugemeister data not found");
+    }
+    return this.provider.newTX([
+      this.program.instruction.setGuardian(newGuardian, {
+        accounts: {
+          gaugemeister,
+          foreman: gmData.foreman,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Pauses a Gaugemeister. Only the guardian may call this.
+   */
+  async pauseGaugemeister({
+    gaugemeister,
+  }: {
+    gaugemeister: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const gmData = await this.fetchGaugemeister(gaugemeister);
+    if (!gmData) {
+      throw new Error("gaugemeister data not found");
+    }
+    return this.provider.newTX([
+      this.program.instruction.pauseGaugemeister({
+        accounts: {
+          gaugemeister,
+          guardian: gmData.guardian,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Unpauses a Gaugemeister. Only the guardian may call this.
+   */
+  async unpauseGaugemeister({
+    gaugemeister,
+  }: {
+    gaugemeister: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const gmData = await this.fetchGaugemeister(gaugemeister);
+    if (!gmData) {
+      throw new Error("gaugemeister data not found");
+    }
+    return this.provider.newTX([
+      this.program.instruction.unpauseGaugemeister({
+        accounts: {
+          gaugemeister,
+          guardian: gmData.guardian,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Proposes a new epoch scheduling mode on a given Gaugemeister.
+   * The change must be accepted once the params change delay has elapsed.
+   */
+  async setFixedEpochSchedule({
+    gaugemeister,
+    fixedEpochSchedule,
+  }: {
+    gaugemeister: PublicKey;
+    fixedEpochSchedule: boolean;
+  }): Promise<TransactionEnvelope> {
+    const gmData = await this.fetchGaugemeister(gaugemeister);
+    if (!gmData) {
+      throw new Error("gaugemeister data not found");
+    }
+    return this.provider.newTX([
+      this.program.instruction.setFixedEpochSchedule(fixedEpochSchedule, {
+        accounts: {
+          gaugemeister,
+          foreman: gmData.foreman,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Sets whether a Gaugemeister normalizes rewards shares by the total power of each epoch.
+   */
+  async setNormalizeRewardsShares({
+    gaugemeister,
+    normalizeRewardsShares,
+  }: {
+    gaugemeister: PublicKey;
+    normalizeRewardsShares: boolean;
+  }): Promise<TransactionEnvelope> {
+    const gmData = await this.fetchGaugemeister(gaugemeister);
+    if (!gmData) {
+      throw new Error("gaugemeister data not found");
+    }
+    return this.provider.newTX([
+      this.program.instruction.setNormalizeRewardsShares(
+        normalizeRewardsShares,
+        {
+          accounts: {
+            gaugemeister,
+            foreman: gmData.foreman,
+          },
+        }
+      ),
+    ]);
+  }
+
+  /**
+   * Sets the maximum share of an epoch that any Gauge of a Gaugemeister may receive.
+   */
+  async setMaxGaugeShareBps({
+    gaugemeister,
+    maxGaugeShareBps,
+  }: {
+    gaugemeister: PublicKey;
+    maxGaugeShareBps: number;
+  }): Promise<TransactionEnvelope> {
+    const gmData = await this.fetchGaugemeister(gaugemeister);
+    if (!gmData) {
+      throw new Error("gaugemeister data not found");
+    }
+    return this.provider.newTX([
+      this.program.instruction.setMaxGaugeShareBps(maxGaugeShareBps, {
+        accounts: {
+          gaugemeister,
+          foreman: gmData.foreman,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Sets the minimum power a Gauge needs to receive rewards, either absolute
+   * or as a share of the epoch total.
+   */
+  async setMinGaugePower({
+    gaugemeister,
+    minGaugePower,
+    minGaugeShareBps,
+  }: {
+    gaugemeister: PublicKey;
+    minGaugePower: u64;
+    minGaugeShareBps: number;
+  }): Promise<TransactionEnvelope> {
+    const gmData = await this.fetchGaugemeister(gaugemeister);
+    if (!gmData) {
+      throw new Error("gaugemeister data not found");
+    }
+    return this.provider.newTX([
+      this.program.instruction.setMinGaugePower(
+        minGaugePower,
+        minGaugeShareBps,
+        {
+          accounts: {
+            gaugemeister,
+            foreman: gmData.foreman,
+          },
+        }
+      ),
+    ]);
+  }
+
+  /**
+   * Drops the EpochGauge of the current rewards epoch of a Gauge which is below the minimum power.
+   */
+  async dropEpochGauge({
+    gauge,
+  }: {
+    gauge: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const gaugeData = await this.fetchGauge(gauge);
+    if (!gaugeData) {
+      throw new Error("gauge data not found");
+    }
+    const gmData = await this.fetchGaugemeister(gaugeData.gaugemeister);
+    if (!gmData) {
+      throw new Error("gaugemeister data not found");
+    }
+    const [epochGauge] = await findEpochGaugeAddress(
+      gauge,
+      gmData.currentRewardsEpoch
+    );
+    const [epochGaugemeister] = await findEpochGaugemeisterAddress(
+      gaugeData.gaugemeister,
+      gmData.currentRewardsEpoch
+    );
+    return this.provider.newTX([
+      this.program.instruction.dropEpochGauge({
+        accounts: {
+          gaugemeister: gaugeData.gaugemeister,
+          gauge,
+          epochGauge,
+          epochGaugemeister,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Sets the epochs in which a Gauge may be voted on and receive rewards.
+   * An epoch of 0 leaves that end of the window open.
+   */
+  async setGaugeSchedule({
+    gauge,
+    activationEpoch,
+    sunsetEpoch,
+  }: {
+    gauge: PublicKey;
+    activationEpoch: number;
+    sunsetEpoch: number;
+  }): Promise<TransactionEnvelope> {
+    const gaugeData = await this.fetchGauge(gauge);
+    if (!gaugeData) {
+      throw new Error("gauge data not found");
+    }
+    const gmData = await this.fetchGaugemeister(gaugeData.gaugemeister);
+    if (!gmData) {
+      throw new Error("gaugemeister data not found");
+    }
+    return this.provider.newTX([
+      this.program.instruction.setGaugeSchedule(activationEpoch, sunsetEpoch, {
+        accounts: {
+          gaugemeister: gaugeData.gaugemeister,
+          gauge,
+          curator: gmData.curator,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Assigns a Gauge to a group. This applies to EpochGauges created afterwards.
+   */
+  async setGaugeGroup({
+    gauge,
+    group,
+  }: {
+    gauge: PublicKey;
+    group: number;
+  }): Promise<TransactionEnvelope> {
+    const gaugeData = await this.fetchGauge(gauge);
+    if (!gaugeData) {
+      throw new Error("gauge data not found");
+    }
+    const gmData = await this.fetchGaugemeister(gaugeData.gaugemeister);
+    if (!gmData) {
+      throw new Error("gaugemeister data not found");
+    }
+    return this.provider.newTX([
+      this.program.instruction.setGaugeGroup(group, {
+        accounts: {
+          gaugemeister: gaugeData.gaugemeister,
+          gauge,
+          foreman: gmData.foreman,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Sets the budget weight of a Gauge group.
+   * This applies to EpochGaugemeisters created afterwards.
+   */
+  async setGaugeGroupBudget({
+    gaugemeister,
+    group,
+    budgetWeight,
+  }: {
+    gaugemeister: PublicKey;
+    group: number;
+    budgetWeight: number;
+  }): Promise<TransactionEnvelope> {
+    const gmData = await this.fetchGaugemeister(gaugemeister);
+    if (!gmData) {
+      throw new Error("gaugemeister data not found");
+    }
+    return this.provider.newTX([
+      this.program.instruction.setGaugeGroupBudget(group, budgetWeight, {
+        accounts: {
+          gaugemeister,
+          foreman: gmData.foreman,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Sets the share of every epoch which the foreman allocates directly.
+   */
+  async setForemanSliceBps({
+    gaugemeister,
+    foremanSliceBps,
+  }: {
+    gaugemeister: PublicKey;
+    foremanSliceBps: number;
+  }): Promise<TransactionEnvelope> {
+    const gmData = await this.fetchGaugemeister(gaugemeister);
+    if (!gmData) {
+      throw new Error("gaugemeister data not found");
+    }
+    return this.provider.newTX([
+      this.program.instruction.setForemanSliceBps(foremanSliceBps, {
+        accounts: {
+          gaugemeister,
+          foreman: gmData.foreman,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Sets the minimum share of every epoch that a Gauge receives regardless of votes.
+   */
+  async setGaugeFloorBps({
+    gauge,
+    floorBps,
+  }: {
+    gauge: PublicKey;
+    floorBps: number;
+  }): Promise<TransactionEnvelope> {
+    const gaugeData = await this.fetchGauge(gauge);
+    if (!gaugeData) {
+      throw new Error("gauge data not found");
+    }
+    const gmData = await this.fetchGaugemeister(gaugeData.gaugemeister);
+    if (!gmData) {
+      throw new Error("gaugemeister data not found");
+    }
+    return this.provider.newTX([
+      this.program.instruction.setGaugeFloorBps(floorBps, {
+        accounts: {
+          gaugemeister: gaugeData.gaugemeister,
+          gauge,
+          foreman: gmData.foreman,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Caps the EpochGauge of the current rewards epoch of a Gauge which is over its maximum share.
+   */
+  async capEpochGauge({
+    gauge,
+  }: {
+    gauge: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const gaugeData = await this.fetchGauge(gauge);
+    if (!gaugeData) {
+      throw new Error("gauge data not found");
+    }
+    const gmData = await this.fetchGaugemeister(gaugeData.gaugemeister);
+    if (!gmData) {
+      throw new Error("gaugemeister data not found");
+    }
+    const [epochGauge] = await findEpochGaugeAddress(
+      gauge,
+      gmData.currentRewardsEpoch
+    );
+    const [epochGaugemeister] = await findEpochGaugemeisterAddress(
+      gaugeData.gaugemeister,
+      gmData.currentRewardsEpoch
+    );
+    return this.provider.newTX([
+      this.program.instruction.capEpochGauge({
+        accounts: {
+          gaugemeister: gaugeData.gaugemeister,
+          gauge,
+          epochGauge,
+          epochGaugemeister,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Closes an EpochGaugeVote account, refunding rent to its payer.
+   * @returns
+   */
+  async closeEpochGaugeVote({
+    gauge,
+    gaugemeister,
+    escrow,
+    votingEpoch,
+  }: {
+    gauge: PublicKey;
+    gaugemeister: PublicKey;
+    escrow: PublicKey;
+    votingEpoch: number;
+  }): Promise<TransactionEnvelope> {
+    const [gaugeVoter] = await findGaugeVoterAddress(gaugemeister, escrow);
+    const [gaugeVote] = await findGaugeVoteAddress(gaugeVoter, gauge);
+    const [epochGaugeVote] = await findEpochGaugeVoteAddress(
+      gaugeVote,
+      votingEpoch
+    );
+    const [epochGauge] = await findEpochGaugeAddress(gauge, votingEpoch);
+    const epochGaugeVoteData = await this.fetchEpochGaugeVote(epochGaugeVote);
+    return this.provider.newTX([
+      this.program.instruction.closeEpochGaugeVote(votingEpoch, {
+        accounts: {
+          epochGaugeVote,
+          gaugemeister,
+          gauge,
+          gaugeVoter,
+          gaugeVote,
+          epochGauge,
+          rentPayer: epochGaugeVoteData?.rentPayer ?? this.provider.walletKey,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Closes the EpochGaugemeister of a past epoch, refunding its rent payer.
+   * @returns
+   */
+  async closeEpochGaugemeister({
+    gaugemeister,
+    votingEpoch,
+  }: {
+    gaugemeister: PublicKey;
+    votingEpoch: number;
+  }): Promise<TransactionEnvelope> {
+    const [epochGaugemeister] = await findEpochGaugemeisterAddress(
+      gaugemeister,
+      votingEpoch
+    );
+    const egmData = await this.fetchEpochGaugemeister(epochGaugemeister);
+    if (!egmData) {
+      throw new Error("epoch gaugemeister not found");
+    }
+    return this.provider.newTX([
+      this.program.instruction.closeEpochGaugemeister({
+        accounts: {
+          gaugemeister,
+          epochGaugemeister,
+          rentPayer: egmData.rentPayer,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Creates the tip vault of a Gaugemeister.
+   * Tips are paid in SOL if no mint is provided.
+   * @returns
+   */
+  async createTipVault({
+    gaugemeister,
+    mint,
+    tokenAccount,
+    foreman = this.provider.wallet.publicKey,
+    payer = this.provider.wallet.publicKey,
+  }: {
+    gaugemeister: PublicKey;
+    /**
+     * Mint of the tips. Defaults to SOL.
+     */
+    mint?: PublicKey;
+    /**
+     * Token account owned by the tip vault. Required if a mint is provided.
+     */
+    tokenAccount?: PublicKey;
+    foreman?: PublicKey;
+    payer?: PublicKey;
+  }): Promise<{ tipVault: PublicKey; tx: TransactionEnvelope }> {
+    const [tipVault] = await findTipVaultAddress(gaugemeister);
+    if (mint && !tokenAccount) {
+      throw new Error("token account required for SPL tips");
+    }
+    return {
+      tipVault,
+      tx: this.provider.newTX([
+        this.program.instruction.createTipVault(
+          mint ?? SystemProgram.programId,
+          {
+            accounts: {
+              gaugemeister,
+              foreman,
+              tipVault,
+              tokenAccount: tokenAccount ?? SystemProgram.programId,
+              payer,
+              systemProgram: SystemProgram.programId,
+            },
+          }
+        ),
+      ]),
+    };
+  }
+
+  /**
+   * Sets the tip paid per crank and the maximum number of tips per epoch
+   * for each kind of crank.
+   * @returns
+   */
+  async setTipParams({
+    gaugemeister,
+    tipAmount,
+    maxTipsPerEpoch,
+    foreman = this.provider.wallet.publicKey,
+  }: {
+    gaugemeister: PublicKey;
+    tipAmount: u64;
+    maxTipsPerEpoch: number;
+    foreman?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const [tipVault] = await findTipVaultAddress(gaugemeister);
+    return this.provider.newTX([
+      this.program.instruction.setTipParams(tipAmount, maxTipsPerEpoch, {
+        accounts: {
+          gaugemeister,
+          tipVault,
+          foreman,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Withdraws funds from the tip vault.
+   * @returns
+   */
+  async withdrawTips({
+    gaugemeister,
+    amount,
+    destination,
+    foreman = this.provider.wallet.publicKey,
+  }: {
+    gaugemeister: PublicKey;
+    amount: u64;
+    /**
+     * A system account for SOL tips, or a token account for SPL tips.
+     */
+    destination: PublicKey;
+    foreman?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const [tipVault] = await findTipVaultAddress(gaugemeister);
+    const tipVaultData = await this.fetchTipVault(tipVault);
+    if (!tipVaultData) {
+      throw new Error("tip vault not found");
+    }
+    return this.provider.newTX([
+      this.program.instruction.withdrawTips(amount, {
+        accounts: {
+          gaugemeister,
+          tipVault,
+          foreman,
+          tokenAccount: tipVaultData.tokenAccount,
+          destination,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Gets the remaining accounts which pay a crank tip to the cranker.
+   * Returns no accounts if the Gaugemeister has no tip vault.
+   * @returns
+   */
+  async getCrankTipAccounts({
+    gaugemeister,
+    cranker = this.provider.wallet.publicKey,
+    crankerTokenAccount,
+  }: {
+    gaugemeister: PublicKey;
+    cranker?: PublicKey;
+    /**
+     * Token account receiving SPL tips.
+     */
+    crankerTokenAccount?: PublicKey;
+  }): Promise<AccountMeta[]> {
+    const [tipVault] = await findTipVaultAddress(gaugemeister);
+    const tipVaultData = await this.fetchTipVault(tipVault);
+    if (!tipVaultData) {
+      return [];
+    }
+    const accounts: AccountMeta[] = [
+      { pubkey: tipVault, isSigner: false, isWritable: true },
+      { pubkey: cranker, isSigner: true, isWritable: true },
+    ];
+    if (tipVaultData.mint.equals(SystemProgram.programId)) {
+      return accounts;
+    }
+    if (!crankerTokenAccount) {
+      throw new Error("cranker token account required for SPL tips");
+    }
+    return [
+      ...accounts,
+      {
+        pubkey: tipVaultData.tokenAccount,
+        isSigner: false,
+        isWritable: true,
+      },
+      { pubkey: crankerTokenAccount, isSigner: false, isWritable: true },
+      { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    ];
+  }
+
+  /**
+   * Creates an incentive rewarding the voters of a gauge in a voting epoch.
+   * The token account must be owned by the incentive.
+   * @returns
+   */
+  async createEpochGaugeIncentive({
+    gauge,
+    votingEpoch,
+    mint,
+    tokenAccount,
+    depositor = this.provider.wallet.publicKey,
+    payer = this.provider.wallet.publicKey,
+  }: {
+    gauge: PublicKey;
+    votingEpoch: number;
+    mint: PublicKey;
+    tokenAccount: PublicKey;
+    depositor?: PublicKey;
+    payer?: PublicKey;
+  }): Promise<{ epochGaugeIncentive: PublicKey; tx: TransactionEnvelope }> {
+    const gaugeData = await this.fetchGauge(gauge);
+    if (!gaugeData) {
+      throw new Error("gauge not found");
+    }
+    const [epochGauge] = await findEpochGaugeAddress(gauge, votingEpoch);
+    const [epochGaugeIncentive] = await findEpochGaugeIncentiveAddress(
+      gauge,
+      votingEpoch,
+      depositor,
+      mint
+    );
+    return {
+      epochGaugeIncentive,
+      tx: this.provider.newTX([
+        this.program.instruction.createEpochGaugeIncentive({
+          accounts: {
+            gaugemeister: gaugeData.gaugemeister,
+            gauge,
+            epochGauge,
+            epochGaugeIncentive,
+            depositor,
+            mint,
+            tokenAccount,
+            payer,
+            systemProgram: SystemProgram.programId,
+          },
+        }),
+      ]),
+    };
+  }
+
+  /**
+   * Deposits tokens into an incentive.
+   * @returns
+   */
+  async depositIncentive({
+    epochGaugeIncentive,
+    amount,
+    source,
+  }: {
+    epochGaugeIncentive: PublicKey;
+    amount: u64;
+    /**
+     * Token account of the depositor.
+     */
+    source: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const incentiveData = await this.fetchEpochGaugeIncentive(
+      epochGaugeIncentive
+    );
+    if (!incentiveData) {
+      throw new Error("incentive not found");
+    }
+    const gaugeData = await this.fetchGauge(incentiveData.gauge);
+    if (!gaugeData) {
+      throw new Error("gauge not found");
+    }
+    return this.provider.newTX([
+      this.program.instruction.depositIncentive(amount, {
+        accounts: {
+          gaugemeister: gaugeData.gaugemeister,
+          gauge: incentiveData.gauge,
+          epochGaugeIncentive,
+          tokenAccount: incentiveData.tokenAccount,
+          depositor: incentiveData.depositor,
+          source,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Claims the share of an incentive owed to a voter.
+   * @returns
+   */
+  async claimIncentive({
+    epochGaugeIncentive,
+    destination,
+    owner = this.provider.wallet.publicKey,
+    payer = this.provider.wallet.publicKey,
+  }: {
+    epochGaugeIncentive: PublicKey;
+    /**
+     * Token account receiving the claimed tokens.
+     */
+    destination: PublicKey;
+    owner?: PublicKey;
+    payer?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const incentiveData = await this.fetchEpochGaugeIncentive(
+      epochGaugeIncentive
+    );
+    if (!incentiveData) {
+      throw new Error("incentive not found");
+    }
+    const { gauge, votingEpoch } = incentiveData;
+    const gaugeData = await this.fetchGauge(gauge);
+    if (!gaugeData) {
+      throw new Error("gauge not found");
+    }
+    const gmData = await this.fetchGaugemeister(gaugeData.gaugemeister);
+    if (!gmData) {
+      throw new Error("gaugemeister not found");
+    }
+    const [escrow] = await findEscrowAddress(gmData.locker, owner);
+    const [gaugeVoter] = await findGaugeVoterAddress(
+      gaugeData.gaugemeister,
+      escrow
+    );
+    const [gaugeVote] = await findGaugeVoteAddress(gaugeVoter, gauge);
+    const [epochGauge] = await findEpochGaugeAddress(gauge, votingEpoch);
+    const [epochGaugeVote] = await findEpochGaugeVoteAddress(
+      gaugeVote,
+      votingEpoch
+    );
+    const [incentiveClaim] = await findIncentiveClaimAddress(
+      epochGaugeIncentive,
+      gaugeVoter
+    );
+    return this.provider.newTX([
+      this.program.instruction.claimIncentive({
+        accounts: {
+          gaugemeister: gaugeData.gaugemeister,
+          gauge,
+          epochGauge,
+          epochGaugeIncentive,
+          tokenAccount: incentiveData.tokenAccount,
+          gaugeVoter,
+          gaugeVote,
+          epochGaugeVote,
+          incentiveClaim,
+          owner,
+          destination,
+          payer,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Returns the unclaimed tokens of an incentive to its depositor
+   * after the claim window has ended.
+   * @returns
+   */
+  async reclaimIncentive({
+    epochGaugeIncentive,
+    destination,
+  }: {
+    epochGaugeIncentive: PublicKey;
+    /**
+     * Token account receiving the unclaimed tokens.
+     */
+    destination: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const incentiveData = await this.fetchEpochGaugeIncentive(
+      epochGaugeIncentive
+    );
+    if (!incentiveData) {
+      throw new Error("incentive not found");
+    }
+    const gaugeData = await this.fetchGauge(incentiveData.gauge);
+    if (!gaugeData) {
+      throw new Error("gauge not found");
+    }
+    return this.provider.newTX([
+      this.program.instruction.reclaimIncentive({
+        accounts: {
+          gaugemeister: gaugeData.gaugemeister,
+          gauge: incentiveData.gauge,
+          epochGaugeIncentive,
+          tokenAccount: incentiveData.tokenAccount,
+          depositor: incentiveData.depositor,
+          destination,
+          tokenProgram: TOKEN_PROGRAM_ID,
         },
       }),
     ]);
@@ -124,6 +124,40 @@ export const findEpochGaugeVoteAddress = async (
   );
 };
 
+/**
+ * Finds the address of an epoch gaugemeister.
+ */
+export const findEpochGaugemeisterAddress = async (
+  gaugemeister: PublicKey,
+  votingEpoch: number
+): Promise<[PublicKey, number]> => {
+  return await PublicKey.findProgramAddress(
+    [
+      utils.bytes.utf8.encode("EpochGaugemeister"),
+      gaugemeister.toBuffer(),
+      encodeU32(votingEpoch),
+    ],
+    GAUGE_ADDRESSES.Gauge
+  );
+};
+
+/**
+ * Finds the address of an epoch gauge allocation.
+ */
+export const findEpochGaugeAllocationAddress = async (
+  gauge: PublicKey,
+  votingEpoch: number
+): Promise<[PublicKey, number]> => {
+  return await PublicKey.findProgramAddress(
+    [
+      utils.bytes.utf8.encode("EpochGaugeAllocation"),
+      gauge.toBuffer(),
+      encodeU32(votingEpoch),
+    ],
+    GAUGE_ADDRESSES.Gauge
+  );
+};
+
 /**
  * Finds the address of a GaugeVoter.
  */
@@ -235,3 +269,87 @@ export const getEpochGaugeVoteAddress = (
     GAUGE_ADDRESSES.Gauge
   );
 };
+
+/**
+ * Finds the address of an epoch gaugemeister.
+ */
+export const getEpochGaugemeisterAddress = (
+  gaugemeister: PublicKey,
+  votingEpoch: number
+): PublicKey => {
+  return getProgramAddress(
+    [
+      utils.bytes.utf8.encode("EpochGaugemeister"),
+      gaugemeister.toBuffer(),
+      encodeU32(votingEpoch),
+    ],
+    GAUGE_ADDRESSES.Gauge
+  );
+};
+
+/**
+ * Finds the address of an epoch gauge allocation.
+ */
+export const getEpochGaugeAllocationAddress = (
+  gauge: PublicKey,
+  votingEpoch: number
+): PublicKey => {
+  return getProgramAddress(
+    [
+      utils.bytes.utf8.encode("EpochGaugeAllocation"),
+      gauge.toBuffer(),
+      encodeU32(votingEpoch),
+    ],
+    GAUGE_ADDRESSES.Gauge
+  );
+};
+
+/**
+ * Finds the address of a tip vault.
+ */
+export const findTipVaultAddress = async (
+  gaugemeister: PublicKey
+): Promise<[PublicKey, number]> => {
+  return await PublicKey.findProgramAddress(
+    [utils.bytes.utf8.encode("TipVault"), gaugemeister.toBuffer()],
+    GAUGE_ADDRESSES.Gauge
+  );
+};
+
+/**
+ * Finds the address of an epoch gauge incentive.
+ */
+export const findEpochGaugeIncentiveAddress = async (
+  gauge: PublicKey,
+  votingEpoch: number,
+  depositor: PublicKey,
+  mint: PublicKey
+): Promise<[PublicKey, number]> => {
+  return await PublicKey.findProgramAddress(
+    [
+      utils.bytes.utf8.encode("EpochGaugeIncentive"),
+      gauge.toBuffer(),
+      encodeU32(votingEpoch),
+      depositor.toBuffer(),
+      mint.toBuffer(),
+    ],
+    GAUGE_ADDRESSES.Gauge
+  );
+};
+
+/**
+ * Finds the address of an incentive claim.
+ */
+export const findIncentiveClaimAddress = async (
+  epochGaugeIncentive: PublicKey,
+  gaugeVoter: PublicKey
+): Promise<[PublicKey, number]> => {
+  return await PublicKey.findProgramAddress(
+    [
+      utils.bytes.utf8.encode("IncentiveClaim"),
+      epochGaugeIncentive.toBuffer(),
+      gaugeVoter.toBuffer(),
+    ],
+    GAUGE_ADDRESSES.Gauge
+  );
+};
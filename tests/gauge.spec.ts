@@ -1,6 +1,6 @@
 import { LangErrorCode } from "@project-serum/anchor";
 import type { Operator } from "@quarryprotocol/quarry-sdk";
-import { QUARRY_CODERS } from "@quarryprotocol/quarry-sdk";
+import { QUARRY_ADDRESSES, QUARRY_CODERS } from "@quarryprotocol/quarry-sdk";
 import { matchError } from "@saberhq/anchor-contrib";
 import {
   assertTXSuccess,
@@ -9,18 +9,30 @@ import {
   expectTXTable,
 } from "@saberhq/chai-solana";
 import type { TokenAmount } from "@saberhq/token-utils";
-import { createMint, sleep, u64 } from "@saberhq/token-utils";
-import type { PublicKey } from "@solana/web3.js";
-import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import {
+  createMint,
+  createMintAndVault,
+  getOrCreateATA,
+  getTokenAccount,
+  sleep,
+  u64,
+} from "@saberhq/token-utils";
+import type { AccountMeta, PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL, SystemProgram } from "@solana/web3.js";
 import type { LockerWrapper } from "@tribecahq/tribeca-sdk";
 import BN from "bn.js";
 import { expect } from "chai";
 import invariant from "tiny-invariant";
 
 import {
+  findEpochGaugeAddress,
+  findEpochGaugeAllocationAddress,
+  findEpochGaugeIncentiveAddress,
+  findEpochGaugemeisterAddress,
   findEpochGaugeVoteAddress,
   findEpochGaugeVoterAddress,
   findGaugeVoteAddress,
+  findTipVaultAddress,
   GaugeErrors,
 } from "../src";
 import { GaugeSDK } from "../src/sdk";
@@ -82,6 +94,7 @@ describe("Gauge", () => {
         operator: operatorW.key,
         locker: lockerW.locker,
         epochDurationSeconds: TEST_EPOCH_SECONDS,
+        paramsChangeDelaySeconds: 0,
       });
     gaugemeister = theGaugemeister;
 
@@ -430,10 +443,10 @@ describe("Gauge", () => {
       invariant(gmData2);
       expect(gmData2.currentRewardsEpoch).to.eq(2);
 
-      const recipientKP = Keypair.generate();
-
-      expect(await voterSDK.provider.getAccountInfo(recipientKP.publicKey)).to
-        .be.null;
+      const payerBalance = (
+        await voterSDK.provider.getAccountInfo(voterSDK.provider.walletKey)
+      )?.accountInfo.lamports;
+      invariant(payerBalance);
 
       await assertTXSuccess(
         await voterSDK.gauge.closeEpochGaugeVote({
@@ -441,15 +454,14 @@ describe("Gauge", () => {
           gaugemeister,
           escrow: voterEscrow,
           votingEpoch: 2,
-          recipient: recipientKP.publicKey,
         }),
         "close old epoch gauge vote"
       );
 
-      const recipientBalance = (
-        await voterSDK.provider.getAccountInfo(recipientKP.publicKey)
+      const payerBalance2 = (
+        await voterSDK.provider.getAccountInfo(voterSDK.provider.walletKey)
       )?.accountInfo.lamports;
-      expect(recipientBalance).to.be.gt(0);
+      expect(payerBalance2).to.be.gt(payerBalance);
 
       await expectTX(
         await voterSDK.gauge.closeEpochGaugeVote({
@@ -460,11 +472,6 @@ describe("Gauge", () => {
         }),
         "cannot close an epoch gauge vote multiple times"
       ).to.be.rejectedWith(LangErrorCode.AccountNotInitialized.toString(16));
-
-      const recipientBalance2 = (
-        await voterSDK.provider.getAccountInfo(recipientKP.publicKey)
-      )?.accountInfo.lamports;
-      expect(recipientBalance2).to.eq(recipientBalance);
     });
   });
 
@@ -589,4 +596,2178 @@ describe("Gauge", () => {
       expect(evoteAfterRevert).to.be.null;
     });
   });
+
+  const fetchVotingEpoch = async (): Promise<number> => {
+    const gmData = await voterSDK.gauge.fetchGaugemeister(gaugemeister);
+    invariant(gmData);
+    return gmData.currentRewardsEpoch + 1;
+  };
+
+  const getLamports = async (account: PublicKey): Promise<number> => {
+    const info = await adminSDK.provider.getAccountInfo(account);
+    invariant(info, "account must exist");
+    return info.accountInfo.lamports;
+  };
+
+  const fetchRewardsShare = async (theQuarry: PublicKey): Promise<BN> => {
+    const quarryData = await QUARRY_CODERS.Mine.getProgram(
+      voterSDK.provider
+    ).account.quarry.fetch(theQuarry);
+    return quarryData.rewardsShare;
+  };
+
+  const createSecondGauge = async (): Promise<{
+    gauge2: PublicKey;
+    quarry2: PublicKey;
+  }> => {
+    const farmToken2Mint = await createMint(adminSDK.provider);
+    const { quarry: quarry2, tx: createQuarry2TX } =
+      await operatorW.delegateCreateQuarry({
+        tokenMint: farmToken2Mint,
+      });
+    await assertTXSuccess(createQuarry2TX, "create quarry 2");
+
+    const { gauge: gauge2, tx: createGauge2TX } =
+      await voterSDK.gauge.createGauge({
+        gaugemeister,
+        quarry: quarry2,
+      });
+    await assertTXSuccess(createGauge2TX, "create gauge 2");
+
+    const { tx: createGaugeVote2TX } = await voterSDK.gauge.createGaugeVote({
+      gaugeVoter,
+      gauge: gauge2,
+    });
+    await assertTXSuccess(createGaugeVote2TX, "create gauge vote 2");
+    return { gauge2, quarry2 };
+  };
+
+  /**
+   * Finds the vote accounts of the voter, sorted by GaugeVote address.
+   */
+  const findVoteAccounts = async (
+    gauges: PublicKey[],
+    votingEpoch: number
+  ): Promise<
+    {
+      gauge: PublicKey;
+      gaugeVote: PublicKey;
+      epochGauge: PublicKey;
+      epochGaugeVote: PublicKey;
+    }[]
+  > => {
+    const votes = await Promise.all(
+      gauges.map(async (theGauge) => {
+        const [gaugeVote] = await findGaugeVoteAddress(gaugeVoter, theGauge);
+        const [epochGauge] = await findEpochGaugeAddress(theGauge, votingEpoch);
+        const [epochGaugeVote] = await findEpochGaugeVoteAddress(
+          gaugeVote,
+          votingEpoch
+        );
+        return { gauge: theGauge, gaugeVote, epochGauge, epochGaugeVote };
+      })
+    );
+    return votes.sort((a, b) =>
+      a.gaugeVote.toBuffer().compare(b.gaugeVote.toBuffer())
+    );
+  };
+
+  const waitForNextEpoch = async (): Promise<void> => {
+    await sleep(TEST_EPOCH_SECONDS * 1_000 + 500);
+    await assertTXSuccess(
+      voterSDK.gauge.triggerNextEpoch({ gaugemeister }),
+      "trigger next epoch"
+    );
+  };
+
+
+  describe("incentives", () => {
+    beforeEach("commit votes for the gauge", async () => {
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge }),
+        "enable gauge 1"
+      );
+      await assertTXSuccess(
+        voterSDK.gauge.triggerNextEpoch({ gaugemeister }),
+        "trigger epoch step"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge, weight: 50 }),
+        "vote gauge 1"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.prepareEpochGaugeVoter({ gaugemeister }),
+        "prepare epoch gauge voter"
+      );
+      const commitTXs = await voterSDK.gauge.commitVotes({
+        gaugemeister,
+        gauges: [gauge],
+      });
+      for (const [i, commitTX] of commitTXs.entries()) {
+        await assertTXSuccess(commitTX, `commit gauge ${i + 1}`);
+      }
+    });
+
+    const createIncentive = async (
+      votingEpoch: number,
+      amount: u64
+    ): Promise<{
+      epochGaugeIncentive: PublicKey;
+      mint: PublicKey;
+      source: PublicKey;
+    }> => {
+      const [mint, source] = await createMintAndVault(
+        adminSDK.provider,
+        amount
+      );
+      const [epochGaugeIncentive] = await findEpochGaugeIncentiveAddress(
+        gauge,
+        votingEpoch,
+        adminSDK.provider.wallet.publicKey,
+        mint
+      );
+      const { address: tokenAccount, instruction: createATAIX } =
+        await getOrCreateATA({
+          provider: adminSDK.provider,
+          mint,
+          owner: epochGaugeIncentive,
+        });
+      const { tx: createIncentiveTX } =
+        await adminSDK.gauge.createEpochGaugeIncentive({
+          gauge,
+          votingEpoch,
+          mint,
+          tokenAccount,
+        });
+      await assertTXSuccess(
+        adminSDK.provider.newTX([createATAIX]).combine(createIncentiveTX),
+        "create incentive"
+      );
+      return { epochGaugeIncentive, mint, source };
+    };
+
+    const createVoterTokenAccount = async (
+      mint: PublicKey
+    ): Promise<PublicKey> => {
+      const { address, instruction } = await getOrCreateATA({
+        provider: voterSDK.provider,
+        mint,
+      });
+      await assertTXSuccess(
+        voterSDK.provider.newTX([instruction]),
+        "create voter token account"
+      );
+      return address;
+    };
+
+    it("pays voters once and returns unclaimed tokens to the depositor", async function () {
+      this.timeout(120_000);
+
+      const votingEpoch = await fetchVotingEpoch();
+      const amount = new u64(1_000_000);
+      const claimed = await createIncentive(votingEpoch, amount);
+      const unclaimed = await createIncentive(votingEpoch, amount);
+      for (const incentive of [claimed, unclaimed]) {
+        await assertTXSuccess(
+          await adminSDK.gauge.depositIncentive({
+            epochGaugeIncentive: incentive.epochGaugeIncentive,
+            amount,
+            source: incentive.source,
+          }),
+          "deposit incentive"
+        );
+      }
+      const voterTokens = await createVoterTokenAccount(claimed.mint);
+
+      await assertTXThrows(
+        await voterSDK.gauge.claimIncentive({
+          epochGaugeIncentive: claimed.epochGaugeIncentive,
+          destination: voterTokens,
+        }),
+        GaugeErrors.IncentiveClaimsNotOpen,
+        "cannot claim while voting"
+      );
+
+      await waitForNextEpoch();
+
+      await assertTXThrows(
+        await adminSDK.gauge.depositIncentive({
+          epochGaugeIncentive: claimed.epochGaugeIncentive,
+          amount,
+          source: claimed.source,
+        }),
+        GaugeErrors.IncentiveDepositsClosed,
+        "cannot deposit once voting has ended"
+      );
+
+      // the voter committed all of the power of the gauge
+      await assertTXSuccess(
+        await voterSDK.gauge.claimIncentive({
+          epochGaugeIncentive: claimed.epochGaugeIncentive,
+          destination: voterTokens,
+        }),
+        "claim incentive"
+      );
+      const voterTokensData = await getTokenAccount(
+        voterSDK.provider,
+        voterTokens
+      );
+      expect(voterTokensData.amount).to.bignumber.eq(amount);
+
+      await expectTX(
+        await voterSDK.gauge.claimIncentive({
+          epochGaugeIncentive: claimed.epochGaugeIncentive,
+          destination: voterTokens,
+        }),
+        "cannot claim twice"
+      ).to.be.rejected;
+
+      await assertTXThrows(
+        await adminSDK.gauge.reclaimIncentive({
+          epochGaugeIncentive: unclaimed.epochGaugeIncentive,
+          destination: unclaimed.source,
+        }),
+        GaugeErrors.IncentiveClaimsOpen,
+        "cannot reclaim while claims are open"
+      );
+
+      const claimedData = await voterSDK.gauge.fetchEpochGaugeIncentive(
+        claimed.epochGaugeIncentive
+      );
+      invariant(claimedData);
+      expect(claimedData.claimedAmount).to.bignumber.eq(amount);
+
+      // wait for the claim window to end
+      while (
+        (await voterSDK.gauge.fetchGaugemeister(gaugemeister))
+          ?.currentRewardsEpoch !==
+        votingEpoch + 4
+      ) {
+        await waitForNextEpoch();
+      }
+
+      const unclaimedTokens = await createVoterTokenAccount(unclaimed.mint);
+      await assertTXThrows(
+        await voterSDK.gauge.claimIncentive({
+          epochGaugeIncentive: unclaimed.epochGaugeIncentive,
+          destination: unclaimedTokens,
+        }),
+        GaugeErrors.IncentiveClaimsNotOpen,
+        "cannot claim after the claim window"
+      );
+
+      await assertTXSuccess(
+        await adminSDK.gauge.reclaimIncentive({
+          epochGaugeIncentive: unclaimed.epochGaugeIncentive,
+          destination: unclaimed.source,
+        }),
+        "reclaim incentive"
+      );
+      const sourceData = await getTokenAccount(
+        adminSDK.provider,
+        unclaimed.source
+      );
+      expect(sourceData.amount).to.bignumber.eq(amount);
+    });
+  });
+
+  describe("fixed epoch schedule", () => {
+    beforeEach("enable the fixed epoch schedule", async () => {
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge }),
+        "enable gauge"
+      );
+      await assertTXSuccess(
+        voterSDK.gauge.triggerNextEpoch({ gaugemeister }),
+        "trigger epoch step"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.setFixedEpochSchedule({
+          gaugemeister,
+          fixedEpochSchedule: true,
+        }),
+        "propose fixed epoch schedule"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.acceptGaugemeisterParams({ gaugemeister }),
+        "accept fixed epoch schedule"
+      );
+    });
+
+    it("applies the voted epoch after a missed epoch", async () => {
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge, weight: 50 }),
+        "vote gauge"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.prepareEpochGaugeVoter({ gaugemeister }),
+        "prepare epoch gauge voter"
+      );
+      const commitTXs = await voterSDK.gauge.commitVotes({
+        gaugemeister,
+        gauges: [gauge],
+      });
+      for (const [i, commitTX] of commitTXs.entries()) {
+        await assertTXSuccess(commitTX, `commit gauge ${i + 1}`);
+      }
+
+      const gmBefore = await voterSDK.gauge.fetchGaugemeister(gaugemeister);
+      invariant(gmBefore);
+      expect(gmBefore.fixedEpochSchedule).to.be.true;
+
+      // miss the start of the voting epoch entirely
+      await sleep(TEST_EPOCH_SECONDS * 2 * 1_000 + 500);
+      await assertTXSuccess(
+        voterSDK.gauge.triggerNextEpoch({ gaugemeister }),
+        "trigger late epoch"
+      );
+
+      const gmAfter = await voterSDK.gauge.fetchGaugemeister(gaugemeister);
+      invariant(gmAfter);
+      expect(gmAfter.currentRewardsEpoch).to.eq(
+        gmBefore.currentRewardsEpoch + 1
+      );
+      // the schedule is re-anchored past the missed start
+      expect(gmAfter.nextEpochStartsAt).to.bignumber.eq(
+        gmBefore.nextEpochStartsAt.add(new BN(TEST_EPOCH_SECONDS * 2))
+      );
+
+      // the votes of the voted epoch are synced
+      await assertTXSuccess(
+        await voterSDK.gauge.syncGauge({ gauge }),
+        "sync gauge"
+      );
+      const [epochGauge] = await findEpochGaugeAddress(
+        gauge,
+        gmAfter.currentRewardsEpoch
+      );
+      const epochGaugeData = await voterSDK.gauge.fetchEpochGauge(epochGauge);
+      invariant(epochGaugeData);
+      expect(epochGaugeData.totalPower).to.bignumber.not.eq("0");
+      expect(await fetchRewardsShare(quarry)).to.bignumber.eq(
+        epochGaugeData.totalPower
+      );
+    });
+  });
+
+  describe("gaugemeister params", () => {
+    it("timelocks parameter changes", async () => {
+      await assertTXSuccess(
+        await adminSDK.gauge.setGaugemeisterParams({
+          gaugemeister,
+          newParamsChangeDelaySeconds: 60,
+        }),
+        "propose params change delay"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.acceptGaugemeisterParams({ gaugemeister }),
+        "accept params change delay"
+      );
+
+      await assertTXSuccess(
+        await adminSDK.gauge.setFixedEpochSchedule({
+          gaugemeister,
+          fixedEpochSchedule: true,
+        }),
+        "propose fixed epoch schedule"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.setGaugemeisterParams({
+          gaugemeister,
+          newForeman: voterSDK.provider.wallet.publicKey,
+        }),
+        "propose new foreman"
+      );
+
+      const gmData = await voterSDK.gauge.fetchGaugemeister(gaugemeister);
+      invariant(gmData);
+      expect(gmData.paramsChangeDelaySeconds).to.eq(60);
+      expect(gmData.pendingForeman).to.eqAddress(
+        voterSDK.provider.wallet.publicKey
+      );
+      // the pending schedule change is kept
+      expect(gmData.pendingFixedEpochSchedule).to.be.true;
+
+      await assertTXThrows(
+        await voterSDK.gauge.acceptGaugemeisterParams({ gaugemeister }),
+        GaugeErrors.ParamsChangeNotExecutable,
+        "cannot accept before the delay"
+      );
+
+      await assertTXSuccess(
+        await adminSDK.gauge.cancelGaugemeisterParams({ gaugemeister }),
+        "cancel params"
+      );
+      const canceled = await voterSDK.gauge.fetchGaugemeister(gaugemeister);
+      invariant(canceled);
+      expect(canceled.foreman).to.eqAddress(adminSDK.provider.wallet.publicKey);
+      expect(canceled.fixedEpochSchedule).to.be.false;
+      expect(canceled.pendingParamsExecutableAt).to.bignumber.eq("0");
+    });
+  });
+
+  describe("curator and guardian", () => {
+    it("lets the curator and the guardian disable gauges", async () => {
+      await assertTXSuccess(
+        await adminSDK.gauge.setCurator({
+          gaugemeister,
+          newCurator: voterSDK.provider.wallet.publicKey,
+        }),
+        "set curator"
+      );
+      const gmData = await voterSDK.gauge.fetchGaugemeister(gaugemeister);
+      invariant(gmData);
+      expect(gmData.curator).to.eqAddress(voterSDK.provider.wallet.publicKey);
+      expect(gmData.guardian).to.eqAddress(adminSDK.provider.wallet.publicKey);
+
+      // only the curator may enable
+      await assertTXThrows(
+        adminSDK.provider.newTX([
+          adminSDK.gauge.program.instruction.gaugeEnable({
+            accounts: {
+              gaugemeister,
+              gauge,
+              curator: adminSDK.provider.wallet.publicKey,
+            },
+          }),
+        ]),
+        GaugeErrors.UnauthorizedNotCurator,
+        "guardian cannot enable"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.enableGauge({ gauge }),
+        "curator enables"
+      );
+
+      // the guardian may disable
+      await assertTXSuccess(
+        await adminSDK.gauge.disableGauge({
+          gauge,
+          authority: adminSDK.provider.wallet.publicKey,
+        }),
+        "guardian disables"
+      );
+      const gaugeData = await voterSDK.gauge.fetchGauge(gauge);
+      invariant(gaugeData);
+      expect(gaugeData.isDisabled).to.be.true;
+
+      await assertTXSuccess(
+        await adminSDK.gauge.setGuardian({
+          gaugemeister,
+          newGuardian: voterSDK.provider.wallet.publicKey,
+        }),
+        "set guardian"
+      );
+      await assertTXThrows(
+        await adminSDK.gauge.disableGauge({
+          gauge,
+          authority: adminSDK.provider.wallet.publicKey,
+        }),
+        GaugeErrors.UnauthorizedNotCuratorOrGuardian,
+        "the foreman cannot disable"
+      );
+    });
+  });
+
+  describe("pause", () => {
+    it("blocks votes, syncs and epoch triggers while paused", async () => {
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge }),
+        "enable gauge"
+      );
+      await assertTXSuccess(
+        voterSDK.gauge.triggerNextEpoch({ gaugemeister }),
+        "trigger epoch step"
+      );
+
+      await assertTXThrows(
+        voterSDK.provider.newTX([
+          voterSDK.gauge.program.instruction.pauseGaugemeister({
+            accounts: {
+              gaugemeister,
+              guardian: voterSDK.provider.wallet.publicKey,
+            },
+          }),
+        ]),
+        GaugeErrors.UnauthorizedNotGuardian,
+        "only the guardian can pause"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.pauseGaugemeister({ gaugemeister }),
+        "pause"
+      );
+
+      await assertTXThrows(
+        await voterSDK.gauge.setVote({ gauge, weight: 50 }),
+        GaugeErrors.GaugemeisterPaused,
+        "cannot vote while paused"
+      );
+      await assertTXThrows(
+        await voterSDK.gauge.syncGauge({ gauge }),
+        GaugeErrors.GaugemeisterPaused,
+        "cannot sync while paused"
+      );
+      await sleep(TEST_EPOCH_SECONDS * 1_000 + 500);
+      await assertTXThrows(
+        voterSDK.gauge.triggerNextEpoch({ gaugemeister }),
+        GaugeErrors.GaugemeisterPaused,
+        "cannot trigger while paused"
+      );
+
+      await assertTXSuccess(
+        await adminSDK.gauge.unpauseGaugemeister({ gaugemeister }),
+        "unpause"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge, weight: 50 }),
+        "vote after unpause"
+      );
+      await assertTXSuccess(
+        voterSDK.gauge.triggerNextEpoch({ gaugemeister }),
+        "trigger after unpause"
+      );
+    });
+  });
+
+  describe("sync gauges", () => {
+    let gauge2: PublicKey;
+    let quarry2: PublicKey;
+
+    beforeEach("commit votes to two gauges", async () => {
+      ({ gauge2, quarry2 } = await createSecondGauge());
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge }),
+        "enable gauge 1"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge: gauge2 }),
+        "enable gauge 2"
+      );
+      await assertTXSuccess(
+        voterSDK.gauge.triggerNextEpoch({ gaugemeister }),
+        "trigger epoch step"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge, weight: 50 }),
+        "vote gauge 1"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge: gauge2, weight: 25 }),
+        "vote gauge 2"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.prepareEpochGaugeVoter({ gaugemeister }),
+        "prepare epoch gauge voter"
+      );
+      const commitTXs = await voterSDK.gauge.commitVotes({
+        gaugemeister,
+        gauges: [gauge, gauge2],
+      });
+      for (const [i, commitTX] of commitTXs.entries()) {
+        await assertTXSuccess(commitTX, `commit gauge ${i + 1}`);
+      }
+    });
+
+    const syncAllGauges = async (remainingAccounts: AccountMeta[]) => {
+      const gmData = await voterSDK.gauge.fetchGaugemeister(gaugemeister);
+      invariant(gmData);
+      const [epochGaugemeister] = await findEpochGaugemeisterAddress(
+        gaugemeister,
+        gmData.currentRewardsEpoch
+      );
+      return voterSDK.provider.newTX([
+        voterSDK.gauge.program.instruction.syncGauges({
+          accounts: {
+            gaugemeister,
+            epochGaugemeister,
+            operator: gmData.operator,
+            rewarder: gmData.rewarder,
+            quarryMineProgram: QUARRY_ADDRESSES.Mine,
+            quarryOperatorProgram: QUARRY_ADDRESSES.Operator,
+          },
+          remainingAccounts,
+        }),
+      ]);
+    };
+
+    const findSyncAccounts = async (
+      gauges: { gauge: PublicKey; quarry: PublicKey }[]
+    ): Promise<AccountMeta[]> => {
+      const gmData = await voterSDK.gauge.fetchGaugemeister(gaugemeister);
+      invariant(gmData);
+      const accounts = await Promise.all(
+        gauges.map(async ({ gauge: theGauge, quarry: theQuarry }) => {
+          const [epochGauge] = await findEpochGaugeAddress(
+            theGauge,
+            gmData.currentRewardsEpoch
+          );
+          const [epochGaugeAllocation] = await findEpochGaugeAllocationAddress(
+            theGauge,
+            gmData.currentRewardsEpoch
+          );
+          return [
+            { pubkey: theGauge, isSigner: false, isWritable: false },
+            { pubkey: epochGauge, isSigner: false, isWritable: false },
+            {
+              pubkey: epochGaugeAllocation,
+              isSigner: false,
+              isWritable: false,
+            },
+            { pubkey: theQuarry, isSigner: false, isWritable: true },
+          ];
+        })
+      );
+      return accounts.flat();
+    };
+
+    it("syncs all gauges at once", async () => {
+      await waitForNextEpoch();
+
+      const syncAccounts = await findSyncAccounts([
+        { gauge, quarry },
+        { gauge: gauge2, quarry: quarry2 },
+      ]);
+      await assertTXThrows(
+        await syncAllGauges(syncAccounts.slice(0, -1)),
+        GaugeErrors.InvalidRemainingAccounts,
+        "gauge accounts must be complete"
+      );
+      await assertTXSuccess(
+        await syncAllGauges(syncAccounts),
+        "sync all gauges"
+      );
+
+      const share1 = await fetchRewardsShare(quarry);
+      const share2 = await fetchRewardsShare(quarry2);
+      expect(share1).to.bignumber.not.eq("0");
+      expect(
+        share2.mul(new BN(2)).sub(share1).abs(),
+        "quarry 1 should have approx 2x quarry 2"
+      ).to.bignumber.lt(new BN(2));
+    });
+  });
+
+  describe("commit votes", () => {
+    let gauge2: PublicKey;
+
+    beforeEach("vote for two gauges", async () => {
+      ({ gauge2 } = await createSecondGauge());
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge }),
+        "enable gauge 1"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge: gauge2 }),
+        "enable gauge 2"
+      );
+      await assertTXSuccess(
+        voterSDK.gauge.triggerNextEpoch({ gaugemeister }),
+        "trigger epoch step"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge, weight: 50 }),
+        "vote gauge 1"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge: gauge2, weight: 25 }),
+        "vote gauge 2"
+      );
+    });
+
+    const commitAllVotes = async (
+      votes: {
+        gauge: PublicKey;
+        gaugeVote: PublicKey;
+        epochGauge: PublicKey;
+        epochGaugeVote: PublicKey;
+      }[]
+    ) => {
+      const votingEpoch = await fetchVotingEpoch();
+      const [epochGaugeVoter] = await findEpochGaugeVoterAddress(
+        gaugeVoter,
+        votingEpoch
+      );
+      const [epochGaugemeister] = await findEpochGaugemeisterAddress(
+        gaugemeister,
+        votingEpoch
+      );
+      return voterSDK.provider.newTX([
+        voterSDK.gauge.program.instruction.gaugeCommitVotes({
+          accounts: {
+            gaugemeister,
+            gaugeVoter,
+            epochGaugeVoter,
+            epochGaugemeister,
+            payer: voterSDK.provider.wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          },
+          remainingAccounts: votes.flatMap((vote) => [
+            { pubkey: vote.gauge, isSigner: false, isWritable: false },
+            { pubkey: vote.gaugeVote, isSigner: false, isWritable: true },
+            { pubkey: vote.epochGauge, isSigner: false, isWritable: true },
+            { pubkey: vote.epochGaugeVote, isSigner: false, isWritable: true },
+          ]),
+        }),
+      ]);
+    };
+
+    it("commits all votes at once", async () => {
+      const votingEpoch = await fetchVotingEpoch();
+      const votes = await findVoteAccounts([gauge, gauge2], votingEpoch);
+      await assertTXSuccess(
+        await voterSDK.gauge.prepareEpochGaugeVoter({ gaugemeister }),
+        "prepare epoch gauge voter"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.createEpochGaugemeister({ gaugemeister }),
+        "create epoch gaugemeister"
+      );
+      await assertTXSuccess(
+        (await voterSDK.gauge.createEpochGauge({ gauge })).combine(
+          await voterSDK.gauge.createEpochGauge({ gauge: gauge2 })
+        ),
+        "create epoch gauges"
+      );
+
+      await assertTXThrows(
+        await commitAllVotes(votes.slice(0, 1)),
+        GaugeErrors.GaugeVotesIncomplete,
+        "votes must be complete"
+      );
+      await assertTXThrows(
+        await commitAllVotes([...votes].reverse()),
+        GaugeErrors.GaugeVotesNotSorted,
+        "votes must be sorted"
+      );
+      await assertTXSuccess(await commitAllVotes(votes), "commit all votes");
+      // committed votes are skipped, so a commit can be retried
+      await assertTXSuccess(
+        await commitAllVotes(votes),
+        "commit all votes again"
+      );
+
+      const [epochGaugeVoter] = await findEpochGaugeVoterAddress(
+        gaugeVoter,
+        votingEpoch
+      );
+      const epochVoterData = await voterSDK.gauge.fetchEpochGaugeVoter(
+        epochGaugeVoter
+      );
+      invariant(epochVoterData);
+      expect(epochVoterData.allocatedPower).to.bignumber.gt(
+        lockAmount.toU64().mul(new u64(9_999)).div(new u64(10_000))
+      );
+      const [epochGaugemeister] = await findEpochGaugemeisterAddress(
+        gaugemeister,
+        votingEpoch
+      );
+      const egmData = await voterSDK.gauge.fetchEpochGaugemeister(
+        epochGaugemeister
+      );
+      invariant(egmData);
+      expect(egmData.totalPower).to.bignumber.eq(epochVoterData.allocatedPower);
+      expect(egmData.numGaugesVoted).to.eq(2);
+
+      // the epoch gauge voter and both epoch gauge votes must be closed first
+      const gvData = await voterSDK.gauge.fetchGaugeVoter(gaugeVoter);
+      invariant(gvData);
+      expect(gvData.openEpochAccounts).to.eq(3);
+    });
+  });
+
+  describe("set votes", () => {
+    let gauge2: PublicKey;
+
+    beforeEach("setup second gauge", async () => {
+      ({ gauge2 } = await createSecondGauge());
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge }),
+        "enable gauge 1"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge: gauge2 }),
+        "enable gauge 2"
+      );
+      await assertTXSuccess(
+        voterSDK.gauge.triggerNextEpoch({ gaugemeister }),
+        "trigger epoch step"
+      );
+    });
+
+    const setAllVotes = (
+      votes: { gauge: PublicKey; gaugeVote: PublicKey; weight: number }[]
+    ) =>
+      voterSDK.provider.newTX([
+        voterSDK.gauge.program.instruction.gaugeSetVotes(
+          votes.map((vote) => vote.weight),
+          {
+            accounts: {
+              gaugemeister,
+              gaugeVoter,
+              escrow: voterEscrow,
+              voteDelegate: voterSDK.provider.wallet.publicKey,
+            },
+            remainingAccounts: votes.flatMap((vote) => [
+              { pubkey: vote.gauge, isSigner: false, isWritable: false },
+              { pubkey: vote.gaugeVote, isSigner: false, isWritable: true },
+            ]),
+          }
+        ),
+      ]);
+
+    it("sets all votes at once", async () => {
+      const votingEpoch = await fetchVotingEpoch();
+      const votes = (await findVoteAccounts([gauge, gauge2], votingEpoch)).map(
+        (vote) => ({
+          ...vote,
+          weight: vote.gauge.equals(gauge) ? 50 : 25,
+        })
+      );
+
+      await assertTXThrows(
+        setAllVotes([...votes].reverse()),
+        GaugeErrors.GaugeVotesNotSorted,
+        "votes must be sorted"
+      );
+
+      const gvBefore = await voterSDK.gauge.fetchGaugeVoter(gaugeVoter);
+      invariant(gvBefore);
+      await assertTXSuccess(setAllVotes(votes), "set all votes");
+
+      const gvAfter = await voterSDK.gauge.fetchGaugeVoter(gaugeVoter);
+      invariant(gvAfter);
+      expect(gvAfter.totalWeight).to.eq(75);
+      expect(gvAfter.weightChangeSeqno).to.bignumber.eq(
+        gvBefore.weightChangeSeqno.add(new BN(1))
+      );
+
+      // setting the same weights does not change the seqno
+      await assertTXSuccess(setAllVotes(votes), "set the same votes");
+      const gvUnchanged = await voterSDK.gauge.fetchGaugeVoter(gaugeVoter);
+      invariant(gvUnchanged);
+      expect(gvUnchanged.weightChangeSeqno).to.bignumber.eq(
+        gvAfter.weightChangeSeqno
+      );
+
+      // every vote with weight must be passed
+      await assertTXThrows(
+        setAllVotes(
+          votes
+            .filter((vote) => vote.gauge.equals(gauge))
+            .map((vote) => ({ ...vote, weight: 100 }))
+        ),
+        GaugeErrors.GaugeVotesIncomplete,
+        "votes must be complete"
+      );
+
+      await assertTXSuccess(
+        await adminSDK.gauge.disableGauge({ gauge: gauge2 }),
+        "disable gauge 2"
+      );
+      await assertTXThrows(
+        setAllVotes(votes.map((vote) => ({ ...vote, weight: vote.weight + 1 }))),
+        GaugeErrors.CannotVoteGaugeDisabled,
+        "cannot vote for a disabled gauge"
+      );
+    });
+  });
+
+  describe("normalized rewards shares", () => {
+    beforeEach("normalize rewards shares", async () => {
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge }),
+        "enable gauge"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.setNormalizeRewardsShares({
+          gaugemeister,
+          normalizeRewardsShares: true,
+        }),
+        "normalize rewards shares"
+      );
+    });
+
+    it("gives a sole voted gauge all of the shares", async () => {
+      await assertTXSuccess(
+        voterSDK.gauge.triggerNextEpoch({ gaugemeister }),
+        "trigger epoch step"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge, weight: 50 }),
+        "vote gauge"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.prepareEpochGaugeVoter({ gaugemeister }),
+        "prepare epoch gauge voter"
+      );
+      const commitTXs = await voterSDK.gauge.commitVotes({
+        gaugemeister,
+        gauges: [gauge],
+      });
+      for (const [i, commitTX] of commitTXs.entries()) {
+        await assertTXSuccess(commitTX, `commit gauge ${i + 1}`);
+      }
+
+      await waitForNextEpoch();
+      await assertTXSuccess(
+        await voterSDK.gauge.syncGauge({ gauge }),
+        "sync gauge"
+      );
+      expect(await fetchRewardsShare(quarry)).to.bignumber.eq("1000000000");
+    });
+
+    it("syncs an epoch without an epoch gaugemeister to zero", async () => {
+      // the epoch gauge exists, but nobody committed votes
+      await assertTXSuccess(
+        await voterSDK.gauge.createEpochGauge({ gauge }),
+        "create epoch gauge"
+      );
+      await assertTXSuccess(
+        voterSDK.gauge.triggerNextEpoch({ gaugemeister }),
+        "trigger epoch step"
+      );
+      const gmData = await voterSDK.gauge.fetchGaugemeister(gaugemeister);
+      invariant(gmData);
+      const [epochGaugemeister] = await findEpochGaugemeisterAddress(
+        gaugemeister,
+        gmData.currentRewardsEpoch
+      );
+      expect(await voterSDK.gauge.fetchEpochGaugemeister(epochGaugemeister)).to
+        .be.null;
+
+      await assertTXSuccess(
+        await voterSDK.gauge.syncGauge({ gauge }),
+        "sync gauge"
+      );
+      expect(await fetchRewardsShare(quarry)).to.bignumber.eq("0");
+    });
+  });
+
+  describe("share caps", () => {
+    let gauge2: PublicKey;
+    let quarry2: PublicKey;
+
+    beforeEach("commit votes to two gauges", async () => {
+      ({ gauge2, quarry2 } = await createSecondGauge());
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge }),
+        "enable gauge 1"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge: gauge2 }),
+        "enable gauge 2"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.setNormalizeRewardsShares({
+          gaugemeister,
+          normalizeRewardsShares: true,
+        }),
+        "normalize rewards shares"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.setMaxGaugeShareBps({
+          gaugemeister,
+          maxGaugeShareBps: 5_000,
+        }),
+        "set max gauge share"
+      );
+      await assertTXSuccess(
+        voterSDK.gauge.triggerNextEpoch({ gaugemeister }),
+        "trigger epoch step"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge, weight: 50 }),
+        "vote gauge 1"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge: gauge2, weight: 25 }),
+        "vote gauge 2"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.prepareEpochGaugeVoter({ gaugemeister }),
+        "prepare epoch gauge voter"
+      );
+      const commitTXs = await voterSDK.gauge.commitVotes({
+        gaugemeister,
+        gauges: [gauge, gauge2],
+      });
+      for (const [i, commitTX] of commitTXs.entries()) {
+        await assertTXSuccess(commitTX, `commit gauge ${i + 1}`);
+      }
+      await waitForNextEpoch();
+    });
+
+    it("requires gauges over the cap to be capped before syncing", async () => {
+      await assertTXThrows(
+        await voterSDK.gauge.syncGauge({ gauge }),
+        GaugeErrors.GaugeCapNotApplied,
+        "gauge 1 is over its cap"
+      );
+      await assertTXThrows(
+        await voterSDK.gauge.capEpochGauge({ gauge: gauge2 }),
+        GaugeErrors.EpochGaugeNotOverCap,
+        "gauge 2 is under its cap"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.capEpochGauge({ gauge }),
+        "cap gauge 1"
+      );
+      await assertTXThrows(
+        await voterSDK.gauge.capEpochGauge({ gauge }),
+        GaugeErrors.EpochGaugeAlreadyCapped,
+        "gauge 1 is already capped"
+      );
+
+      await assertTXSuccess(
+        await voterSDK.gauge.syncGauge({ gauge }),
+        "sync gauge 1"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.syncGauge({ gauge: gauge2 }),
+        "sync gauge 2"
+      );
+
+      // the excess of gauge 1 is redistributed, so both get half
+      const share1 = await fetchRewardsShare(quarry);
+      const share2 = await fetchRewardsShare(quarry2);
+      expect(share1).to.bignumber.not.eq("0");
+      expect(share1.sub(share2).abs()).to.bignumber.lte(new BN(1));
+    });
+  });
+
+  describe("epoch gaugemeister", () => {
+    let gauge2: PublicKey;
+    let quarry2: PublicKey;
+
+    beforeEach("enable two gauges", async () => {
+      ({ gauge2, quarry2 } = await createSecondGauge());
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge }),
+        "enable gauge 1"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge: gauge2 }),
+        "enable gauge 2"
+      );
+      await assertTXSuccess(
+        voterSDK.gauge.triggerNextEpoch({ gaugemeister }),
+        "trigger epoch step"
+      );
+    });
+
+    const commitBothVotes = async (): Promise<void> => {
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge, weight: 50 }),
+        "vote gauge 1"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge: gauge2, weight: 25 }),
+        "vote gauge 2"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.prepareEpochGaugeVoter({ gaugemeister }),
+        "prepare epoch gauge voter"
+      );
+      const commitTXs = await voterSDK.gauge.commitVotes({
+        gaugemeister,
+        gauges: [gauge, gauge2],
+      });
+      for (const [i, commitTX] of commitTXs.entries()) {
+        await assertTXSuccess(commitTX, `commit gauge ${i + 1}`);
+      }
+    };
+
+    it("records the totals of committed and reverted votes", async () => {
+      await commitBothVotes();
+      const votingEpoch = await fetchVotingEpoch();
+      const [epochGaugemeister] = await findEpochGaugemeisterAddress(
+        gaugemeister,
+        votingEpoch
+      );
+      const [epochGauge1] = await findEpochGaugeAddress(gauge, votingEpoch);
+      const [epochGauge2] = await findEpochGaugeAddress(gauge2, votingEpoch);
+      const eg1 = await voterSDK.gauge.fetchEpochGauge(epochGauge1);
+      const eg2 = await voterSDK.gauge.fetchEpochGauge(epochGauge2);
+      invariant(eg1 && eg2);
+
+      const egm = await voterSDK.gauge.fetchEpochGaugemeister(
+        epochGaugemeister
+      );
+      invariant(egm);
+      expect(egm.votingEpoch).to.eq(votingEpoch);
+      expect(egm.totalPower).to.bignumber.eq(
+        eg1.totalPower.add(eg2.totalPower)
+      );
+      expect(egm.numGaugesVoted).to.eq(2);
+      expect(egm.numVoters).to.eq(1);
+
+      const revertTXs = await voterSDK.gauge.revertVotes({
+        gaugemeister,
+        gauges: [gauge2],
+      });
+      for (const [i, revertTX] of revertTXs.entries()) {
+        await assertTXSuccess(revertTX, `revert gauge ${i + 1}`);
+      }
+      const egmAfterRevert = await voterSDK.gauge.fetchEpochGaugemeister(
+        epochGaugemeister
+      );
+      invariant(egmAfterRevert);
+      expect(egmAfterRevert.totalPower).to.bignumber.eq(eg1.totalPower);
+      expect(egmAfterRevert.numGaugesVoted).to.eq(1);
+      // the voter still has power on gauge 1
+      expect(egmAfterRevert.numVoters).to.eq(1);
+    });
+
+    it("syncs an epoch without an epoch gaugemeister", async () => {
+      // nobody commits a vote, so only the EpochGauge exists
+      await assertTXSuccess(
+        await voterSDK.gauge.createEpochGauge({ gauge }),
+        "create epoch gauge"
+      );
+      const votingEpoch = await fetchVotingEpoch();
+      const [epochGaugemeister] = await findEpochGaugemeisterAddress(
+        gaugemeister,
+        votingEpoch
+      );
+      await waitForNextEpoch();
+      expect(await voterSDK.gauge.fetchEpochGaugemeister(epochGaugemeister)).to
+        .be.null;
+
+      await assertTXSuccess(
+        await voterSDK.gauge.syncGauge({ gauge }),
+        "sync gauge without epoch gaugemeister"
+      );
+      expect(await fetchRewardsShare(quarry)).to.bignumber.eq("0");
+      expect(await fetchRewardsShare(quarry2)).to.bignumber.eq("0");
+    });
+
+    it(
+      "closes epoch gaugemeisters once their rewards epoch has passed",
+      async () => {
+        await commitBothVotes();
+        const votingEpoch = await fetchVotingEpoch();
+        const [epochGaugemeister] = await findEpochGaugemeisterAddress(
+          gaugemeister,
+          votingEpoch
+        );
+
+        await waitForNextEpoch();
+        // still needed to sync the current rewards epoch
+        await assertTXThrows(
+          await voterSDK.gauge.closeEpochGaugemeister({
+            gaugemeister,
+            votingEpoch,
+          }),
+          GaugeErrors.CloseEpochNotElapsed,
+          "close epoch gaugemeister of current rewards epoch"
+        );
+
+        await waitForNextEpoch();
+        await assertTXSuccess(
+          await voterSDK.gauge.closeEpochGaugemeister({
+            gaugemeister,
+            votingEpoch,
+          }),
+          "close epoch gaugemeister"
+        );
+        const egmAfterClose = await voterSDK.gauge.fetchEpochGaugemeister(
+          epochGaugemeister
+        );
+        expect(egmAfterClose).to.be.null;
+      }
+    );
+  });
+
+  describe("unvoted gauges", () => {
+    beforeEach("enable the gauge", async () => {
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge }),
+        "enable gauge"
+      );
+      await assertTXSuccess(
+        voterSDK.gauge.triggerNextEpoch({ gaugemeister }),
+        "trigger epoch step"
+      );
+    });
+
+    it("zeroes the share of a gauge without votes in the epoch", async () => {
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge, weight: 50 }),
+        "vote gauge"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.prepareEpochGaugeVoter({ gaugemeister }),
+        "prepare epoch gauge voter"
+      );
+      const commitTXs = await voterSDK.gauge.commitVotes({
+        gaugemeister,
+        gauges: [gauge],
+      });
+      for (const [i, commitTX] of commitTXs.entries()) {
+        await assertTXSuccess(commitTX, `commit gauge ${i + 1}`);
+      }
+      await waitForNextEpoch();
+      await assertTXSuccess(
+        await voterSDK.gauge.syncGauge({ gauge }),
+        "sync voted gauge"
+      );
+      expect(await fetchRewardsShare(quarry)).to.bignumber.not.eq("0");
+
+      // nobody votes in the next epoch, so no EpochGauge is created
+      await waitForNextEpoch();
+      const gm = await voterSDK.gauge.fetchGaugemeister(gaugemeister);
+      invariant(gm);
+      const [epochGauge] = await findEpochGaugeAddress(
+        gauge,
+        gm.currentRewardsEpoch
+      );
+      expect(await voterSDK.gauge.fetchEpochGauge(epochGauge)).to.be.null;
+
+      await assertTXSuccess(
+        await voterSDK.gauge.syncGauge({ gauge }),
+        "sync unvoted gauge"
+      );
+      expect(await fetchRewardsShare(quarry)).to.bignumber.eq("0");
+    });
+  });
+
+  describe("minimum gauge power", () => {
+    let gauge2: PublicKey;
+    let quarry2: PublicKey;
+
+    beforeEach("commit a dust vote", async () => {
+      ({ gauge2, quarry2 } = await createSecondGauge());
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge }),
+        "enable gauge 1"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge: gauge2 }),
+        "enable gauge 2"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.setMinGaugePower({
+          gaugemeister,
+          minGaugePower: new u64(0),
+          minGaugeShareBps: 1_000,
+        }),
+        "set min gauge power"
+      );
+      await assertTXSuccess(
+        voterSDK.gauge.triggerNextEpoch({ gaugemeister }),
+        "trigger epoch step"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge, weight: 99 }),
+        "vote gauge 1"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge: gauge2, weight: 1 }),
+        "vote gauge 2"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.prepareEpochGaugeVoter({ gaugemeister }),
+        "prepare epoch gauge voter"
+      );
+      const commitTXs = await voterSDK.gauge.commitVotes({
+        gaugemeister,
+        gauges: [gauge, gauge2],
+      });
+      for (const [i, commitTX] of commitTXs.entries()) {
+        await assertTXSuccess(commitTX, `commit gauge ${i + 1}`);
+      }
+      await waitForNextEpoch();
+    });
+
+    it("drops gauges below the threshold before syncing", async () => {
+      await assertTXThrows(
+        await voterSDK.gauge.syncGauge({ gauge: gauge2 }),
+        GaugeErrors.GaugeThresholdNotApplied,
+        "gauge 2 is below the threshold"
+      );
+      await assertTXThrows(
+        await voterSDK.gauge.dropEpochGauge({ gauge }),
+        GaugeErrors.EpochGaugeNotBelowThreshold,
+        "gauge 1 is above the threshold"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.dropEpochGauge({ gauge: gauge2 }),
+        "drop gauge 2"
+      );
+      await assertTXThrows(
+        await voterSDK.gauge.dropEpochGauge({ gauge: gauge2 }),
+        GaugeErrors.EpochGaugeAlreadyDropped,
+        "gauge 2 is already dropped"
+      );
+
+      await assertTXSuccess(
+        await voterSDK.gauge.syncGauge({ gauge }),
+        "sync gauge 1"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.syncGauge({ gauge: gauge2 }),
+        "sync gauge 2"
+      );
+      expect(await fetchRewardsShare(quarry)).to.bignumber.not.eq("0");
+      expect(await fetchRewardsShare(quarry2)).to.bignumber.eq("0");
+    });
+  });
+
+  describe("gauge floors", () => {
+    let gauge2: PublicKey;
+    let quarry2: PublicKey;
+
+    beforeEach("set a floor on the second gauge", async () => {
+      ({ gauge2, quarry2 } = await createSecondGauge());
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge }),
+        "enable gauge 1"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge: gauge2 }),
+        "enable gauge 2"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.setNormalizeRewardsShares({
+          gaugemeister,
+          normalizeRewardsShares: true,
+        }),
+        "normalize rewards shares"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.setGaugeFloorBps({
+          gauge: gauge2,
+          floorBps: 2_000,
+        }),
+        "set gauge 2 floor"
+      );
+    });
+
+    it("does not reserve more than the whole epoch", async () => {
+      await assertTXThrows(
+        await adminSDK.gauge.setGaugeFloorBps({ gauge, floorBps: 8_001 }),
+        GaugeErrors.InvalidBps,
+        "floors over 100%"
+      );
+      const gmData = await voterSDK.gauge.fetchGaugemeister(gaugemeister);
+      invariant(gmData);
+      expect(gmData.totalFloorBps).to.eq(2_000);
+    });
+
+    it("gives floored gauges their floor without votes", async () => {
+      await assertTXSuccess(
+        voterSDK.gauge.triggerNextEpoch({ gaugemeister }),
+        "trigger epoch step"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge, weight: 50 }),
+        "vote gauge 1"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.prepareEpochGaugeVoter({ gaugemeister }),
+        "prepare epoch gauge voter"
+      );
+      const commitTXs = await voterSDK.gauge.commitVotes({
+        gaugemeister,
+        gauges: [gauge],
+      });
+      for (const [i, commitTX] of commitTXs.entries()) {
+        await assertTXSuccess(commitTX, `commit gauge ${i + 1}`);
+      }
+      await waitForNextEpoch();
+
+      await assertTXSuccess(
+        await voterSDK.gauge.syncGauge({ gauge }),
+        "sync gauge 1"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.syncGauge({ gauge: gauge2 }),
+        "sync gauge 2"
+      );
+      // the floor is taken out of the vote-driven shares
+      expect(await fetchRewardsShare(quarry)).to.bignumber.eq("800000000");
+      expect(await fetchRewardsShare(quarry2)).to.bignumber.eq("200000000");
+    });
+  });
+
+  describe("foreman slice", () => {
+    let gauge2: PublicKey;
+    let quarry2: PublicKey;
+
+    beforeEach("reserve a foreman slice", async () => {
+      ({ gauge2, quarry2 } = await createSecondGauge());
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge }),
+        "enable gauge 1"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge: gauge2 }),
+        "enable gauge 2"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.setNormalizeRewardsShares({
+          gaugemeister,
+          normalizeRewardsShares: true,
+        }),
+        "normalize rewards shares"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.setForemanSliceBps({
+          gaugemeister,
+          foremanSliceBps: 2_000,
+        }),
+        "set foreman slice"
+      );
+      await assertTXSuccess(
+        voterSDK.gauge.triggerNextEpoch({ gaugemeister }),
+        "trigger epoch step"
+      );
+    });
+
+    it(
+      "only allows allocations by the foreman in the voting epoch",
+      async () => {
+        await assertTXThrows(
+          await voterSDK.gauge.setEpochGaugeAllocation({
+            gauge: gauge2,
+            weight: 1,
+          }),
+          GaugeErrors.UnauthorizedNotForemanOrGovernor,
+          "voter allocates"
+        );
+
+        const votingEpoch = await fetchVotingEpoch();
+        const [epochGaugeAllocation] = await findEpochGaugeAllocationAddress(
+          gauge2,
+          votingEpoch + 1
+        );
+        await assertTXThrows(
+          adminSDK.provider.newTX([
+            adminSDK.gauge.program.instruction.createEpochGaugeAllocation(
+              votingEpoch + 1,
+              {
+                accounts: {
+                  gaugemeister,
+                  gauge: gauge2,
+                  epochGaugeAllocation,
+                  payer: adminSDK.provider.wallet.publicKey,
+                  systemProgram: SystemProgram.programId,
+                },
+              }
+            ),
+          ]),
+          GaugeErrors.EpochGaugeNotVoting,
+          "allocation for a future epoch"
+        );
+      }
+    );
+
+    it("combines the foreman slice with the votes", async () => {
+      await assertTXSuccess(
+        await adminSDK.gauge.setEpochGaugeAllocation({
+          gauge: gauge2,
+          weight: 1,
+        }),
+        "allocate gauge 2"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge, weight: 50 }),
+        "vote gauge 1"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.prepareEpochGaugeVoter({ gaugemeister }),
+        "prepare epoch gauge voter"
+      );
+      const commitTXs = await voterSDK.gauge.commitVotes({
+        gaugemeister,
+        gauges: [gauge],
+      });
+      for (const [i, commitTX] of commitTXs.entries()) {
+        await assertTXSuccess(commitTX, `commit gauge ${i + 1}`);
+      }
+      await waitForNextEpoch();
+
+      await assertTXSuccess(
+        await voterSDK.gauge.syncGauge({ gauge }),
+        "sync gauge 1"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.syncGauge({ gauge: gauge2 }),
+        "sync gauge 2"
+      );
+      expect(await fetchRewardsShare(quarry)).to.bignumber.eq("800000000");
+      expect(await fetchRewardsShare(quarry2)).to.bignumber.eq("200000000");
+    });
+  });
+
+  describe("gauge groups", () => {
+    let gauge2: PublicKey;
+    let quarry2: PublicKey;
+
+    beforeEach("split two gauges into equal groups", async () => {
+      ({ gauge2, quarry2 } = await createSecondGauge());
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge }),
+        "enable gauge 1"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge: gauge2 }),
+        "enable gauge 2"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.setNormalizeRewardsShares({
+          gaugemeister,
+          normalizeRewardsShares: true,
+        }),
+        "normalize rewards shares"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.setGaugeGroup({ gauge: gauge2, group: 1 }),
+        "set gauge 2 group"
+      );
+      for (const group of [0, 1]) {
+        await assertTXSuccess(
+          await adminSDK.gauge.setGaugeGroupBudget({
+            gaugemeister,
+            group,
+            budgetWeight: 1,
+          }),
+          `set group ${group} budget`
+        );
+      }
+      await assertTXSuccess(
+        voterSDK.gauge.triggerNextEpoch({ gaugemeister }),
+        "trigger epoch step"
+      );
+    });
+
+    it("applies budget changes from the next voting epoch", async () => {
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge, weight: 50 }),
+        "vote gauge 1"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge: gauge2, weight: 25 }),
+        "vote gauge 2"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.prepareEpochGaugeVoter({ gaugemeister }),
+        "prepare epoch gauge voter"
+      );
+      const commitTXs = await voterSDK.gauge.commitVotes({
+        gaugemeister,
+        gauges: [gauge, gauge2],
+      });
+      for (const [i, commitTX] of commitTXs.entries()) {
+        await assertTXSuccess(commitTX, `commit gauge ${i + 1}`);
+      }
+
+      // change the budgets while the epoch is being voted on
+      await assertTXSuccess(
+        await adminSDK.gauge.setGaugeGroupBudget({
+          gaugemeister,
+          group: 1,
+          budgetWeight: 3,
+        }),
+        "change group 1 budget"
+      );
+      const [epochGaugemeister] = await findEpochGaugemeisterAddress(
+        gaugemeister,
+        await fetchVotingEpoch()
+      );
+      const egm = await voterSDK.gauge.fetchEpochGaugemeister(
+        epochGaugemeister
+      );
+      invariant(egm);
+      expect(egm.groupBudgetWeights.slice(0, 2)).to.deep.eq([1, 1]);
+
+      await waitForNextEpoch();
+      await assertTXSuccess(
+        await voterSDK.gauge.syncGauge({ gauge }),
+        "sync gauge 1"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.syncGauge({ gauge: gauge2 }),
+        "sync gauge 2"
+      );
+
+      // each group gets half of the epoch regardless of its votes
+      const share1 = await fetchRewardsShare(quarry);
+      const share2 = await fetchRewardsShare(quarry2);
+      expect(share1.sub(new BN(500_000_000)).abs()).to.bignumber.lte(
+        new BN(1)
+      );
+      expect(share2.sub(new BN(500_000_000)).abs()).to.bignumber.lte(
+        new BN(1)
+      );
+    });
+  });
+
+  describe("gauge schedule", () => {
+    beforeEach("enable the gauge", async () => {
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge }),
+        "enable gauge"
+      );
+      await assertTXSuccess(
+        voterSDK.gauge.triggerNextEpoch({ gaugemeister }),
+        "trigger epoch step"
+      );
+    });
+
+    it("rejects votes before the activation epoch", async () => {
+      const votingEpoch = await fetchVotingEpoch();
+      await assertTXThrows(
+        await adminSDK.gauge.setGaugeSchedule({
+          gauge,
+          activationEpoch: votingEpoch + 2,
+          sunsetEpoch: votingEpoch + 2,
+        }),
+        GaugeErrors.InvalidGaugeSchedule,
+        "sunset at activation"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.setGaugeSchedule({
+          gauge,
+          activationEpoch: votingEpoch + 1,
+          sunsetEpoch: 0,
+        }),
+        "set activation epoch"
+      );
+      await assertTXThrows(
+        await voterSDK.gauge.setVote({ gauge, weight: 50 }),
+        GaugeErrors.GaugeNotActive,
+        "vote before activation"
+      );
+    });
+
+    it("sunsets the gauge at its sunset epoch", async () => {
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge, weight: 50 }),
+        "vote gauge"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.prepareEpochGaugeVoter({ gaugemeister }),
+        "prepare epoch gauge voter"
+      );
+      const commitTXs = await voterSDK.gauge.commitVotes({
+        gaugemeister,
+        gauges: [gauge],
+      });
+      for (const [i, commitTX] of commitTXs.entries()) {
+        await assertTXSuccess(commitTX, `commit gauge ${i + 1}`);
+      }
+      const votingEpoch = await fetchVotingEpoch();
+      await assertTXSuccess(
+        await adminSDK.gauge.setGaugeSchedule({
+          gauge,
+          activationEpoch: 0,
+          sunsetEpoch: votingEpoch + 1,
+        }),
+        "set sunset epoch"
+      );
+
+      // the last active epoch is still synced from its votes
+      await waitForNextEpoch();
+      await assertTXSuccess(
+        await voterSDK.gauge.syncGauge({ gauge }),
+        "sync last active epoch"
+      );
+      expect(await fetchRewardsShare(quarry)).to.bignumber.not.eq("0");
+      await assertTXThrows(
+        await voterSDK.gauge.setVote({ gauge, weight: 60 }),
+        GaugeErrors.GaugeNotActive,
+        "vote in the sunset epoch"
+      );
+
+      // syncing in the sunset epoch disables the gauge
+      await waitForNextEpoch();
+      await assertTXSuccess(
+        await voterSDK.gauge.syncGauge({ gauge }),
+        "sunset gauge"
+      );
+      expect(await fetchRewardsShare(quarry)).to.bignumber.eq("0");
+      const gaugeData = await voterSDK.gauge.fetchGauge(gauge);
+      invariant(gaugeData);
+      expect(gaugeData.isDisabled).to.be.true;
+    });
+  });
+
+  describe("release", () => {
+    let gauge2: PublicKey;
+
+    beforeEach("commit votes to two gauges", async () => {
+      ({ gauge2 } = await createSecondGauge());
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge }),
+        "enable gauge 1"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge: gauge2 }),
+        "enable gauge 2"
+      );
+      await assertTXSuccess(
+        voterSDK.gauge.triggerNextEpoch({ gaugemeister }),
+        "trigger epoch step"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge, weight: 50 }),
+        "vote gauge 1"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge: gauge2, weight: 50 }),
+        "vote gauge 2"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.prepareEpochGaugeVoter({ gaugemeister }),
+        "prepare epoch gauge voter"
+      );
+      const commitTXs = await voterSDK.gauge.commitVotes({
+        gaugemeister,
+        gauges: [gauge, gauge2],
+      });
+      for (const [i, commitTX] of commitTXs.entries()) {
+        await assertTXSuccess(commitTX, `commit gauge ${i + 1}`);
+      }
+    });
+
+    it("releases power from a disabled gauge to be recommitted", async () => {
+      const votingEpoch = await fetchVotingEpoch();
+      const [vote1] = await findVoteAccounts([gauge], votingEpoch);
+      const [vote2] = await findVoteAccounts([gauge2], votingEpoch);
+      invariant(vote1 && vote2);
+      const [epochGaugeVoter] = await findEpochGaugeVoterAddress(
+        gaugeVoter,
+        votingEpoch
+      );
+
+      await assertTXThrows(
+        await adminSDK.gauge.releaseGaugeVote({
+          gauge: gauge2,
+          owner: voterSDK.provider.wallet.publicKey,
+        }),
+        GaugeErrors.GaugeNotDisabled,
+        "only votes for disabled gauges can be released"
+      );
+
+      await assertTXSuccess(
+        await adminSDK.gauge.disableGauge({ gauge: gauge2 }),
+        "disable gauge 2"
+      );
+      // anyone can release the vote
+      await assertTXSuccess(
+        await adminSDK.gauge.releaseGaugeVote({
+          gauge: gauge2,
+          owner: voterSDK.provider.wallet.publicKey,
+        }),
+        "release gauge vote"
+      );
+      expect(await voterSDK.gauge.fetchEpochGaugeVote(vote2.epochGaugeVote)).to
+        .be.null;
+
+      const released = await voterSDK.gauge.fetchEpochGaugeVoter(
+        epochGaugeVoter
+      );
+      invariant(released);
+      expect(
+        released.votingPower.sub(released.allocatedPower.mul(new BN(2))).abs()
+      ).to.bignumber.lt(new BN(2));
+
+      // zeroing the weight of the disabled gauge lets its power be recommitted
+      const revertTXs = await voterSDK.gauge.revertVotes({
+        gaugemeister,
+        gauges: [gauge],
+      });
+      for (const [i, revertTX] of revertTXs.entries()) {
+        await assertTXSuccess(revertTX, `revert gauge ${i + 1}`);
+      }
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge: gauge2, weight: 0 }),
+        "remove vote for gauge 2"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.resetEpochGaugeVoter({ gaugemeister }),
+        "reset epoch gauge voter"
+      );
+      const commitTXs = await voterSDK.gauge.commitVotes({
+        gaugemeister,
+        gauges: [gauge],
+      });
+      for (const [i, commitTX] of commitTXs.entries()) {
+        await assertTXSuccess(commitTX, `recommit gauge ${i + 1}`);
+      }
+
+      const recommitted = await voterSDK.gauge.fetchEpochGaugeVoter(
+        epochGaugeVoter
+      );
+      invariant(recommitted);
+      expect(recommitted.allocatedPower).to.bignumber.eq(
+        recommitted.votingPower
+      );
+      const evote1 = await voterSDK.gauge.fetchEpochGaugeVote(
+        vote1.epochGaugeVote
+      );
+      invariant(evote1);
+      expect(evote1.allocatedPower).to.bignumber.eq(recommitted.votingPower);
+    });
+  });
+
+  describe("persistent votes", () => {
+    let gauge2: PublicKey;
+
+    beforeEach("setup second gauge", async () => {
+      ({ gauge2 } = await createSecondGauge());
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge }),
+        "enable gauge 1"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge: gauge2 }),
+        "enable gauge 2"
+      );
+      await assertTXSuccess(
+        voterSDK.gauge.triggerNextEpoch({ gaugemeister }),
+        "trigger epoch step"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge, weight: 50 }),
+        "vote gauge 1"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge: gauge2, weight: 50 }),
+        "vote gauge 2"
+      );
+    });
+
+    it("rolls votes forward and skips disabled gauges", async () => {
+      await assertTXSuccess(
+        await adminSDK.gauge.createEpochGaugemeister({ gaugemeister }),
+        "create epoch gaugemeister"
+      );
+      await assertTXSuccess(
+        (await adminSDK.gauge.createEpochGauge({ gauge })).combine(
+          await adminSDK.gauge.createEpochGauge({ gauge: gauge2 })
+        ),
+        "create epoch gauges"
+      );
+
+      await assertTXThrows(
+        await adminSDK.gauge.rollForwardVotes({
+          gaugemeister,
+          gauges: [gauge, gauge2],
+          owner: voterSDK.provider.wallet.publicKey,
+        }),
+        GaugeErrors.GaugeVoterNotPersistent,
+        "only persistent votes roll forward"
+      );
+
+      await assertTXSuccess(
+        await voterSDK.gauge.setPersistentVotes({
+          gaugemeister,
+          isPersistent: true,
+        }),
+        "set persistent votes"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.disableGauge({ gauge: gauge2 }),
+        "disable gauge 2"
+      );
+
+      // anyone can roll the votes forward
+      await assertTXSuccess(
+        await adminSDK.gauge.rollForwardVotes({
+          gaugemeister,
+          gauges: [gauge, gauge2],
+          owner: voterSDK.provider.wallet.publicKey,
+        }),
+        "roll forward votes"
+      );
+
+      const votingEpoch = await fetchVotingEpoch();
+      const [vote1] = await findVoteAccounts([gauge], votingEpoch);
+      const [vote2] = await findVoteAccounts([gauge2], votingEpoch);
+      invariant(vote1 && vote2);
+
+      const [epochGaugeVoter] = await findEpochGaugeVoterAddress(
+        gaugeVoter,
+        votingEpoch
+      );
+      const epochVoterData = await voterSDK.gauge.fetchEpochGaugeVoter(
+        epochGaugeVoter
+      );
+      invariant(epochVoterData);
+      expect(epochVoterData.rentPayer).to.eqAddress(
+        adminSDK.provider.wallet.publicKey
+      );
+
+      // the disabled gauge keeps its half of the power out of the epoch
+      const evote1 = await voterSDK.gauge.fetchEpochGaugeVote(
+        vote1.epochGaugeVote
+      );
+      invariant(evote1);
+      expect(evote1.allocatedPower).to.bignumber.eq(
+        epochVoterData.allocatedPower
+      );
+      expect(
+        epochVoterData.votingPower
+          .sub(epochVoterData.allocatedPower.mul(new BN(2)))
+          .abs()
+      ).to.bignumber.lt(new BN(2));
+      expect(await voterSDK.gauge.fetchEpochGaugeVote(vote2.epochGaugeVote)).to
+        .be.null;
+    });
+  });
+
+  describe("tips", () => {
+    const TIP_AMOUNT = 10_000;
+
+    let tipVault: PublicKey;
+    let gauge2: PublicKey;
+
+    beforeEach("setup tip vault", async () => {
+      ({ gauge2 } = await createSecondGauge());
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge }),
+        "enable gauge 1"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge: gauge2 }),
+        "enable gauge 2"
+      );
+
+      const { tipVault: theTipVault, tx: createTipVaultTX } =
+        await adminSDK.gauge.createTipVault({ gaugemeister });
+      tipVault = theTipVault;
+      await assertTXSuccess(
+        createTipVaultTX.combine(
+          adminSDK.provider.newTX([
+            SystemProgram.transfer({
+              fromPubkey: adminSDK.provider.wallet.publicKey,
+              toPubkey: tipVault,
+              lamports: LAMPORTS_PER_SOL,
+            }),
+          ])
+        ),
+        "create and fund tip vault"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.setTipParams({
+          gaugemeister,
+          tipAmount: new u64(TIP_AMOUNT),
+          maxTipsPerEpoch: 1,
+        }),
+        "set tip params"
+      );
+    });
+
+    it("pays crank tips up to the cap of each crank kind", async () => {
+      const [foundTipVault] = await findTipVaultAddress(gaugemeister);
+      expect(foundTipVault).to.eqAddress(tipVault);
+      await assertTXThrows(
+        await voterSDK.gauge.setTipParams({
+          gaugemeister,
+          tipAmount: new u64(TIP_AMOUNT * 2),
+          maxTipsPerEpoch: 10,
+        }),
+        GaugeErrors.UnauthorizedNotForeman,
+        "only the foreman can set tip params"
+      );
+
+      const tipAccounts = await voterSDK.gauge.getCrankTipAccounts({
+        gaugemeister,
+      });
+      const vaultBalance = await getLamports(tipVault);
+      await assertTXSuccess(
+        voterSDK.provider.newTX([
+          voterSDK.gauge.program.instruction.triggerNextEpoch({
+            accounts: { gaugemeister },
+            remainingAccounts: tipAccounts,
+          }),
+        ]),
+        "trigger next epoch with tip"
+      );
+      expect(await getLamports(tipVault)).to.eq(vaultBalance - TIP_AMOUNT);
+
+      // only one epoch gauge creation is tipped in the epoch
+      const votingEpoch = await fetchVotingEpoch();
+      const createEpochGaugeIXs = await Promise.all(
+        [gauge, gauge2].map(async (theGauge) => {
+          const [epochGauge, bump] = await findEpochGaugeAddress(
+            theGauge,
+            votingEpoch
+          );
+          return voterSDK.gauge.program.instruction.createEpochGauge(
+            bump,
+            votingEpoch,
+            {
+              accounts: {
+                epochGauge,
+                gauge: theGauge,
+                payer: voterSDK.provider.wallet.publicKey,
+                systemProgram: SystemProgram.programId,
+              },
+              remainingAccounts: [
+                { pubkey: gaugemeister, isSigner: false, isWritable: false },
+                ...tipAccounts,
+              ],
+            }
+          );
+        })
+      );
+      for (const [i, ix] of createEpochGaugeIXs.entries()) {
+        await assertTXSuccess(
+          voterSDK.provider.newTX([ix]),
+          `create epoch gauge ${i + 1} with tip`
+        );
+      }
+      expect(await getLamports(tipVault)).to.eq(
+        vaultBalance - 2 * TIP_AMOUNT
+      );
+
+      // only one vote commit is tipped in the epoch
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge, weight: 50 }),
+        "vote gauge 1"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge: gauge2, weight: 50 }),
+        "vote gauge 2"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.prepareEpochGaugeVoter({ gaugemeister }),
+        "prepare epoch gauge voter"
+      );
+      for (const [i, theGauge] of [gauge, gauge2].entries()) {
+        await assertTXSuccess(
+          await voterSDK.gauge.commitVote({ gauge: theGauge, tipAccounts }),
+          `commit gauge ${i + 1} with tip`
+        );
+      }
+      expect(await getLamports(tipVault)).to.eq(
+        vaultBalance - 3 * TIP_AMOUNT
+      );
+
+      const tipVaultData = await voterSDK.gauge.fetchTipVault(tipVault);
+      invariant(tipVaultData);
+      expect(tipVaultData.tipsPaidInEpoch).to.deep.eq([1, 1, 0]);
+      expect(tipVaultData.commitVoteTipsPaidInEpoch).to.eq(1);
+      expect(tipVaultData.totalTipsPaid).to.bignumber.eq(
+        new u64(3 * TIP_AMOUNT)
+      );
+    });
+
+    it("lets only the foreman withdraw tips", async () => {
+      await assertTXThrows(
+        await voterSDK.gauge.withdrawTips({
+          gaugemeister,
+          amount: new u64(TIP_AMOUNT),
+          destination: voterSDK.provider.wallet.publicKey,
+        }),
+        GaugeErrors.UnauthorizedNotForeman,
+        "only the foreman can withdraw tips"
+      );
+
+      const vaultBalance = await getLamports(tipVault);
+      await assertTXSuccess(
+        await adminSDK.gauge.withdrawTips({
+          gaugemeister,
+          amount: new u64(LAMPORTS_PER_SOL / 2),
+          destination: adminSDK.provider.wallet.publicKey,
+        }),
+        "withdraw tips"
+      );
+      expect(await getLamports(tipVault)).to.eq(
+        vaultBalance - LAMPORTS_PER_SOL / 2
+      );
+    });
+  });
+
+  describe("refresh", () => {
+    let gauge2: PublicKey;
+
+    beforeEach("commit votes to two gauges", async () => {
+      ({ gauge2 } = await createSecondGauge());
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge }),
+        "enable gauge 1"
+      );
+      await assertTXSuccess(
+        await adminSDK.gauge.enableGauge({ gauge: gauge2 }),
+        "enable gauge 2"
+      );
+      await assertTXSuccess(
+        voterSDK.gauge.triggerNextEpoch({ gaugemeister }),
+        "trigger epoch step"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge, weight: 50 }),
+        "vote gauge 1"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge: gauge2, weight: 50 }),
+        "vote gauge 2"
+      );
+      await assertTXSuccess(
+        await voterSDK.gauge.prepareEpochGaugeVoter({ gaugemeister }),
+        "prepare epoch gauge voter"
+      );
+      const commitTXs = await voterSDK.gauge.commitVotes({
+        gaugemeister,
+        gauges: [gauge, gauge2],
+      });
+      for (const [i, commitTX] of commitTXs.entries()) {
+        await assertTXSuccess(commitTX, `commit gauge ${i + 1}`);
+      }
+    });
+
+    it("refreshes committed votes without reverting them", async () => {
+      const votingEpoch = await fetchVotingEpoch();
+      const [epochGaugeVoter] = await findEpochGaugeVoterAddress(
+        gaugeVoter,
+        votingEpoch
+      );
+      const before = await voterSDK.gauge.fetchEpochGaugeVoter(
+        epochGaugeVoter
+      );
+      invariant(before);
+
+      await assertTXSuccess(
+        await voterSDK.gauge.refreshEpochGaugeVoter({
+          gaugemeister,
+          gauges: [gauge, gauge2],
+        }),
+        "refresh epoch gauge voter"
+      );
+
+      const after = await voterSDK.gauge.fetchEpochGaugeVoter(epochGaugeVoter);
+      invariant(after);
+      expect(after.allocatedPower).to.bignumber.eq(after.votingPower);
+      expect(after.weightChangeSeqno).to.bignumber.eq(before.weightChangeSeqno);
+      const [epochGaugemeister] = await findEpochGaugemeisterAddress(
+        gaugemeister,
+        votingEpoch
+      );
+      const egmData = await voterSDK.gauge.fetchEpochGaugemeister(
+        epochGaugemeister
+      );
+      invariant(egmData);
+      expect(egmData.totalPower).to.bignumber.eq(after.allocatedPower);
+      expect(egmData.numVoters).to.eq(1);
+    });
+
+    it("cannot refresh after the vote weights change", async () => {
+      await assertTXSuccess(
+        await voterSDK.gauge.setVote({ gauge: gauge2, weight: 0 }),
+        "remove vote for gauge 2"
+      );
+      await assertTXThrows(
+        await voterSDK.gauge.refreshEpochGaugeVoter({
+          gaugemeister,
+          gauges: [gauge, gauge2],
+        }),
+        GaugeErrors.WeightSeqnoChanged,
+        "weights changed since the epoch gauge voter was prepared"
+      );
+    });
+  });
 });